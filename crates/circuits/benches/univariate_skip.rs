@@ -0,0 +1,131 @@
+// Copyright 2025 Irreducible Inc.
+
+#![feature(array_try_from_fn)]
+
+use std::array;
+
+use binius_circuits::{
+	builder::{types::U, ConstraintSystemBuilder},
+	keccakf::{keccakf, KeccakfState},
+	sha256::sha256,
+	unconstrained::unconstrained,
+	vision::vision_permutation,
+};
+use binius_core::{
+	constraint_system::{self, ProveOptions, UnivariateSkipRounds},
+	fiat_shamir::HasherChallenger,
+	oracle::OracleId,
+	tower::CanonicalTowerFamily,
+};
+use binius_field::{BinaryField1b, BinaryField32b};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::DefaultEvaluationDomainFactory;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+const LOG_INV_RATE: usize = 1;
+const SECURITY_BITS: usize = 100;
+const SKIP_ROUNDS: [(&str, UnivariateSkipRounds); 4] = [
+	("disabled", UnivariateSkipRounds::AtMost(0)),
+	("standard", UnivariateSkipRounds::Standard),
+	("extended_10", UnivariateSkipRounds::AtMost(10)),
+	("extended_12", UnivariateSkipRounds::AtMost(12)),
+];
+
+type BuildCircuit = fn(&mut ConstraintSystemBuilder) -> Result<(), anyhow::Error>;
+
+fn sha256_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+	// 2^5 rows per compression, 2^5 compressions.
+	let log_size = 10;
+	let input: [OracleId; 16] =
+		array::try_from_fn(|i| unconstrained::<BinaryField1b>(builder, i, log_size))?;
+	sha256(builder, input, log_size)?;
+	Ok(())
+}
+
+fn keccakf_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+	let log_size = 5;
+	let mut rng = StdRng::seed_from_u64(0);
+	let input_states = (0..1 << log_size)
+		.map(|_| KeccakfState(rng.gen()))
+		.collect::<Vec<_>>();
+	keccakf(builder, &Some(input_states), log_size)?;
+	Ok(())
+}
+
+fn vision32b_circuit(builder: &mut ConstraintSystemBuilder) -> Result<(), anyhow::Error> {
+	let log_size = 8;
+	let state_in: [OracleId; 24] = array::try_from_fn(|i| {
+		unconstrained::<BinaryField32b>(builder, format!("p_in[{i}]"), log_size)
+	})?;
+	vision_permutation(builder, log_size, state_in)?;
+	Ok(())
+}
+
+/// Benchmarks proving the hash circuits with different numbers of univariate skip rounds.
+///
+/// Proof sizes are printed once per configuration, as criterion only reports timings.
+fn bench_univariate_skip(c: &mut Criterion) {
+	let circuits: [(&str, BuildCircuit); 3] = [
+		("sha256", sha256_circuit),
+		("keccakf", keccakf_circuit),
+		("vision32b", vision32b_circuit),
+	];
+
+	let mut group = c.benchmark_group("univariate_skip");
+	group.sample_size(10);
+
+	let domain_factory = DefaultEvaluationDomainFactory::default();
+	let backend = make_portable_backend();
+
+	for (circuit_name, build_circuit) in circuits {
+		for (skip_rounds_name, univariate_skip_rounds) in SKIP_ROUNDS {
+			let prove = || {
+				let allocator = bumpalo::Bump::new();
+				let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+				build_circuit(&mut builder).unwrap();
+				let witness = builder.take_witness().unwrap();
+				let constraint_system = builder.build().unwrap();
+
+				constraint_system::prove_with_options::<
+					U,
+					CanonicalTowerFamily,
+					_,
+					Groestl256,
+					Groestl256ByteCompression,
+					HasherChallenger<Groestl256>,
+					_,
+				>(
+					&constraint_system,
+					LOG_INV_RATE,
+					SECURITY_BITS,
+					&[],
+					witness,
+					&domain_factory,
+					ProveOptions {
+						univariate_skip_rounds,
+						..Default::default()
+					},
+					&backend,
+				)
+				.unwrap()
+				.0
+			};
+
+			println!(
+				"{circuit_name}/{skip_rounds_name}: proof size {} bytes",
+				prove().get_proof_size()
+			);
+
+			group.bench_function(BenchmarkId::new(circuit_name, skip_rounds_name), |b| {
+				b.iter(prove)
+			});
+		}
+	}
+	group.finish();
+}
+
+criterion_group!(univariate_skip, bench_univariate_skip);
+criterion_main!(univariate_skip);
@@ -12,37 +12,64 @@ use binius_core::{
 		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
 		ProjectionVariant, ShiftVariant,
 	},
-	polynomial::MultivariatePoly,
+	polynomial::{CircuitEvaluator, MultivariatePoly},
 	transparent::step_down::StepDown,
 	witness::MultilinearExtensionIndex,
 };
-use binius_field::{as_packed_field::PackScalar, BinaryField1b};
+use binius_field::{as_packed_field::PackScalar, BinaryField1b, TowerField};
 use binius_math::ArithExpr;
 use binius_utils::bail;
 
 use crate::builder::{
-	types::{F, U},
-	witness,
+	types::U,
+	witness::{self, WitnessAllocator},
 };
 
+/// Builder of a constraint system over the tower field `F`.
+///
+/// The gadgets in this crate work with the canonical tower, which is the default. Constraint
+/// systems over other tower families, e.g. the AES tower, are built with
+/// [`Self::new_in_field`] and [`Self::new_in_field_with_witness`].
 #[derive(Default)]
-pub struct ConstraintSystemBuilder<'arena> {
+pub struct ConstraintSystemBuilder<'arena, F: TowerField = super::types::F>
+where
+	U: PackScalar<F>,
+{
 	oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 	constraints: ConstraintSetBuilder<F>,
 	non_zero_oracle_ids: Vec<OracleId>,
 	flushes: Vec<Flush>,
 	step_down_dedup: HashMap<(usize, usize), OracleId>,
-	witness: Option<witness::Builder<'arena>>,
+	witness: Option<witness::Builder<'arena, F>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
 }
 
 impl<'arena> ConstraintSystemBuilder<'arena> {
 	pub fn new() -> Self {
+		Self::new_in_field()
+	}
+
+	pub fn new_with_witness(allocator: impl Into<WitnessAllocator<'arena>>) -> Self {
+		Self::new_in_field_with_witness(allocator)
+	}
+}
+
+impl<'arena, F: TowerField> ConstraintSystemBuilder<'arena, F>
+where
+	U: PackScalar<F>,
+{
+	/// Creates a verifier-side builder over the tower field `F`.
+	pub fn new_in_field() -> Self {
 		Self::default()
 	}
 
-	pub fn new_with_witness(allocator: &'arena bumpalo::Bump) -> Self {
+	/// Creates a prover-side builder over the tower field `F`, allocating the witness in
+	/// `allocator`.
+	///
+	/// With the `mmap` feature enabled, passing an `MmapArena` stores large witness columns in
+	/// memory-mapped files.
+	pub fn new_in_field_with_witness(allocator: impl Into<WitnessAllocator<'arena>>) -> Self {
 		let oracles = Rc::new(RefCell::new(MultilinearOracleSet::new()));
 		Self {
 			witness: Some(witness::Builder::new(allocator, oracles.clone())),
@@ -72,7 +99,7 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 		})
 	}
 
-	pub const fn witness(&mut self) -> Option<&mut witness::Builder<'arena>> {
+	pub const fn witness(&mut self) -> Option<&mut witness::Builder<'arena, F>> {
 		self.witness.as_mut()
 	}
 
@@ -194,6 +221,12 @@ impl<'arena> ConstraintSystemBuilder<'arena> {
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	/// Sets the evaluator the prover uses for the compositions of constraints asserted after
+	/// this call.
+	pub fn set_circuit_evaluator(&mut self, evaluator: CircuitEvaluator) {
+		self.constraints.set_evaluator(evaluator);
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
@@ -12,40 +12,86 @@ use binius_field::{
 	underlier::WithUnderlier,
 	ExtensionField, PackedField, TowerField,
 };
+#[cfg(feature = "mmap")]
+use binius_hal::mmap_vec::MmapArena;
 use binius_math::MultilinearExtension;
 use binius_utils::bail;
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 
-use super::types::{F, U};
+use super::types::U;
 
-pub struct Builder<'arena> {
-	bump: &'arena bumpalo::Bump,
+/// The arena that witness columns are allocated in.
+#[derive(Debug, Clone, Copy)]
+pub enum WitnessAllocator<'arena> {
+	/// Columns are allocated on the heap.
+	Bump(&'arena bumpalo::Bump),
+	/// Columns larger than the arena's spill threshold are stored in memory-mapped files, which
+	/// lets a witness exceed the available memory.
+	#[cfg(feature = "mmap")]
+	Mmap(&'arena MmapArena<U>),
+}
+
+impl<'arena> WitnessAllocator<'arena> {
+	fn alloc_filled(self, len: usize, value: U) -> &'arena mut [U] {
+		match self {
+			Self::Bump(bump) => bumpalo::vec![in bump; value; len].into_bump_slice_mut(),
+			#[cfg(feature = "mmap")]
+			Self::Mmap(arena) => arena.alloc_filled(len, value),
+		}
+	}
+}
+
+impl<'arena> From<&'arena bumpalo::Bump> for WitnessAllocator<'arena> {
+	fn from(bump: &'arena bumpalo::Bump) -> Self {
+		Self::Bump(bump)
+	}
+}
+
+#[cfg(feature = "mmap")]
+impl<'arena> From<&'arena MmapArena<U>> for WitnessAllocator<'arena> {
+	fn from(arena: &'arena MmapArena<U>) -> Self {
+		Self::Mmap(arena)
+	}
+}
+
+/// Builder of the witness for a constraint system over the tower field `F`.
+pub struct Builder<'arena, F: TowerField = super::types::F>
+where
+	U: PackScalar<F>,
+{
+	allocator: WitnessAllocator<'arena>,
 
 	oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 
 	#[allow(clippy::type_complexity)]
-	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
+	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena, F>>>>>,
 }
 
-struct WitnessBuilderEntry<'arena> {
+struct WitnessBuilderEntry<'arena, F: TowerField>
+where
+	U: PackScalar<F>,
+{
 	witness: Result<MultilinearWitness<'arena, PackedType<U, F>>, binius_math::Error>,
 	tower_level: usize,
 	data: &'arena [U],
 }
 
-impl<'arena> Builder<'arena> {
+impl<'arena, F: TowerField> Builder<'arena, F>
+where
+	U: PackScalar<F>,
+{
 	pub fn new(
-		allocator: &'arena bumpalo::Bump,
+		allocator: impl Into<WitnessAllocator<'arena>>,
 		oracles: Rc<RefCell<MultilinearOracleSet<F>>>,
 	) -> Self {
 		Self {
-			bump: allocator,
+			allocator: allocator.into(),
 			oracles,
 			entries: Rc::new(RefCell::new(Vec::new())),
 		}
 	}
 
-	pub fn new_column<FS: TowerField>(&self, id: OracleId) -> EntryBuilder<'arena, FS>
+	pub fn new_column<FS: TowerField>(&self, id: OracleId) -> EntryBuilder<'arena, FS, F>
 	where
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
@@ -53,7 +99,7 @@ impl<'arena> Builder<'arena> {
 		let oracles = self.oracles.borrow();
 		let log_rows = oracles.n_vars(id);
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
-		let data = bumpalo::vec![in self.bump; U::default(); len].into_bump_slice_mut();
+		let data = self.allocator.alloc_filled(len, U::default());
 		EntryBuilder {
 			_marker: PhantomData,
 			log_rows,
@@ -67,7 +113,7 @@ impl<'arena> Builder<'arena> {
 		&self,
 		id: OracleId,
 		default: FS,
-	) -> EntryBuilder<'arena, FS>
+	) -> EntryBuilder<'arena, FS, F>
 	where
 		U: PackScalar<FS>,
 		F: ExtensionField<FS>,
@@ -76,7 +122,7 @@ impl<'arena> Builder<'arena> {
 		let log_rows = oracles.n_vars(id);
 		let len = 1 << log_rows.saturating_sub(<PackedType<U, FS>>::LOG_WIDTH);
 		let default = WithUnderlier::to_underlier(PackedType::<U, FS>::broadcast(default));
-		let data = bumpalo::vec![in self.bump; default; len].into_bump_slice_mut();
+		let data = self.allocator.alloc_filled(len, default);
 		EntryBuilder {
 			_marker: PhantomData,
 			log_rows,
@@ -199,25 +245,25 @@ where
 	}
 }
 
-pub struct EntryBuilder<'arena, FS>
+pub struct EntryBuilder<'arena, FS, F = super::types::F>
 where
 	FS: TowerField,
-	U: PackScalar<FS>,
-	F: ExtensionField<FS>,
+	F: TowerField + ExtensionField<FS>,
+	U: PackScalar<FS> + PackScalar<F>,
 {
 	_marker: PhantomData<FS>,
 	#[allow(clippy::type_complexity)]
-	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena>>>>>,
+	entries: Rc<RefCell<Vec<Option<WitnessBuilderEntry<'arena, F>>>>>,
 	id: OracleId,
 	log_rows: usize,
 	data: Option<&'arena mut [U]>,
 }
 
-impl<FS> EntryBuilder<'_, FS>
+impl<FS, F> EntryBuilder<'_, FS, F>
 where
 	FS: TowerField,
-	U: PackScalar<FS>,
-	F: ExtensionField<FS>,
+	F: TowerField + ExtensionField<FS>,
+	U: PackScalar<FS> + PackScalar<F>,
 {
 	#[inline]
 	pub fn packed(&mut self) -> &mut [PackedType<U, FS>] {
@@ -237,11 +283,11 @@ where
 	}
 }
 
-impl<FS> Drop for EntryBuilder<'_, FS>
+impl<FS, F> Drop for EntryBuilder<'_, FS, F>
 where
 	FS: TowerField,
-	U: PackScalar<FS>,
-	F: ExtensionField<FS>,
+	F: TowerField + ExtensionField<FS>,
+	U: PackScalar<FS> + PackScalar<F>,
 {
 	fn drop(&mut self) {
 		let data = Option::take(&mut self.data).expect("data is always Some until this point");
@@ -26,38 +26,86 @@ pub mod vision;
 
 #[cfg(test)]
 mod tests {
+	use std::iter::repeat_with;
+
 	use binius_core::{
 		constraint_system::{
 			self,
 			channel::{validate_witness, Boundary, FlushDirection},
+			error::VerificationError,
+			ProveOptions, UnivariateSkipRounds, VerifyOptions,
 		},
-		fiat_shamir::HasherChallenger,
-		oracle::ShiftVariant,
-		polynomial::ArithCircuitPoly,
-		tower::CanonicalTowerFamily,
+		fiat_shamir::{Challenger, HasherChallenger, Vision32bDuplexChallenger},
+		oracle::{OracleEvalQuery, ShiftVariant},
+		polynomial::{ArithCircuitPoly, CircuitEvaluator},
+		tower::{AESTowerFamily, CanonicalTowerFamily},
 	};
 	use binius_field::{
 		arch::OptimalUnderlier, as_packed_field::PackedType, underlier::WithUnderlier,
-		BinaryField128b, BinaryField64b, BinaryField8b, Field,
+		AESTowerField128b, AESTowerField8b, BinaryField128b, BinaryField1b, BinaryField64b,
+		BinaryField8b, Field, TowerField,
+	};
+	#[cfg(feature = "mmap")]
+	use binius_hal::MmapBackend;
+	use binius_hal::{make_portable_backend, BackendOperation, ProfilingBackend};
+	use binius_hash::{
+		compress::Groestl256ByteCompression, PseudoCompressionFunction, Vision32Compression,
+		VisionHasherDigest,
 	};
-	use binius_hal::make_portable_backend;
-	use binius_hash::compress::Groestl256ByteCompression;
 	use binius_macros::arith_expr;
 	use binius_math::{
 		CompositionPoly, DefaultEvaluationDomainFactory, IsomorphicEvaluationDomainFactory,
+		MultilinearPoly, MultilinearQuery,
 	};
+	use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 	use groestl_crypto::Groestl256;
+	use rand::{rngs::StdRng, SeedableRng};
 
 	type B128 = BinaryField128b;
 	type B64 = BinaryField64b;
 
-	use crate::builder::{
-		types::{F, U},
-		ConstraintSystemBuilder,
+	use crate::{
+		arithmetic,
+		builder::{
+			types::{F, U},
+			ConstraintSystemBuilder,
+		},
+		unconstrained::unconstrained,
 	};
 
 	#[test]
 	fn test_boundaries() {
+		test_boundaries_with_hash::<
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>();
+	}
+
+	#[test]
+	fn test_boundaries_vision_hash() {
+		test_boundaries_with_hash::<
+			VisionHasherDigest,
+			Vision32Compression,
+			HasherChallenger<VisionHasherDigest>,
+		>();
+	}
+
+	#[test]
+	fn test_boundaries_vision_duplex_challenger() {
+		test_boundaries_with_hash::<
+			VisionHasherDigest,
+			Vision32Compression,
+			Vision32bDuplexChallenger,
+		>();
+	}
+
+	fn test_boundaries_with_hash<Hash, Compress, Challenger_>()
+	where
+		Hash: Digest + BlockSizeUser + FixedOutputReset,
+		Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+		Challenger_: Challenger + Default,
+	{
 		// Proving Collatz Orbits
 		let allocator = bumpalo::Bump::new();
 		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
@@ -148,27 +196,392 @@ mod tests {
 		let constraint_system = builder.build().unwrap();
 
 		let domain_factory = DefaultEvaluationDomainFactory::default();
-		let backend = make_portable_backend();
+		let backend = ProfilingBackend::new(make_portable_backend());
 
 		let proof = constraint_system::prove::<
 			U,
 			CanonicalTowerFamily,
 			_,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
+			Hash,
+			Compress,
+			Challenger_,
 			_,
 		>(&constraint_system, 1, 10, &boundaries, witness, &domain_factory, &backend)
 		.unwrap();
 
-		constraint_system::verify::<
+		let summary = backend.summary();
+		assert!(summary
+			.stats
+			.keys()
+			.any(|(operation, _)| *operation == BackendOperation::SumcheckComputeRoundEvals));
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system,
+			1,
+			10,
+			&boundaries,
+			proof,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_aes_tower_prove_verify() {
+		type Hash = Groestl256;
+		type Compress = Groestl256ByteCompression;
+		type Challenger_ = HasherChallenger<Groestl256>;
+
+		let log_size = 10;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder =
+			ConstraintSystemBuilder::<AESTowerField128b>::new_in_field_with_witness(&allocator);
+
+		let in_a = builder.add_committed("in_a", log_size, AESTowerField8b::TOWER_LEVEL);
+		let in_b = builder.add_committed("in_b", log_size, AESTowerField8b::TOWER_LEVEL);
+		let out = builder.add_committed("out", log_size, AESTowerField8b::TOWER_LEVEL);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut in_a_witness = witness.new_column::<AESTowerField8b>(in_a);
+			let mut in_b_witness = witness.new_column::<AESTowerField8b>(in_b);
+			let mut out_witness = witness.new_column::<AESTowerField8b>(out);
+			for ((a, b), out) in in_a_witness
+				.as_mut_slice::<AESTowerField8b>()
+				.iter_mut()
+				.zip(in_b_witness.as_mut_slice::<AESTowerField8b>())
+				.zip(out_witness.as_mut_slice::<AESTowerField8b>())
+			{
+				*a = AESTowerField8b::random(&mut rng);
+				*b = AESTowerField8b::random(&mut rng);
+				*out = *a * *b;
+			}
+		}
+
+		builder.assert_zero(
+			"mul",
+			[in_a, in_b, out],
+			arith_expr!([a, b, out] = a * b - out).convert_field(),
+		);
+
+		let channel_id = builder.add_channel();
+		builder
+			.send(channel_id, 1 << log_size, [in_a, out])
+			.unwrap();
+		builder
+			.receive(channel_id, 1 << log_size, [in_a, out])
+			.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof =
+			constraint_system::prove::<U, AESTowerFamily, _, Hash, Compress, Challenger_, _>(
+				&constraint_system,
+				1,
+				10,
+				&[],
+				witness,
+				&domain_factory,
+				&backend,
+			)
+			.unwrap();
+
+		constraint_system::verify::<U, AESTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system,
+			1,
+			10,
+			&[],
+			proof,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_univariate_skip_rounds() {
+		type Hash = Groestl256;
+		type Compress = Groestl256ByteCompression;
+		type Challenger_ = HasherChallenger<Groestl256>;
+
+		let log_size = 11;
+		let log_inv_rate = 1;
+		let security_bits = 30;
+
+		let verifier_constraint_system = {
+			let mut builder = ConstraintSystemBuilder::new();
+			let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+			let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+			arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked)
+				.unwrap();
+			builder.build().unwrap()
+		};
+
+		// No skipped rounds, the rounds supported by the 8-bit domain, and enough rounds to
+		// require the extended domain.
+		for univariate_skip_rounds in [
+			UnivariateSkipRounds::AtMost(0),
+			UnivariateSkipRounds::Standard,
+			UnivariateSkipRounds::AtMost(10),
+		] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+			let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+			let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+			arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked)
+				.unwrap();
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+
+			let domain_factory = DefaultEvaluationDomainFactory::default();
+			let backend = make_portable_backend();
+			let proof = constraint_system::prove_with_options::<
+				U,
+				CanonicalTowerFamily,
+				_,
+				Hash,
+				Compress,
+				Challenger_,
+				_,
+			>(
+				&constraint_system,
+				log_inv_rate,
+				security_bits,
+				&[],
+				witness,
+				&domain_factory,
+				ProveOptions {
+					univariate_skip_rounds,
+					..Default::default()
+				},
+				&backend,
+			)
+			.unwrap()
+			.0;
+
+			let verify = |univariate_skip_rounds| {
+				constraint_system::verify_with_options::<
+					U,
+					CanonicalTowerFamily,
+					Hash,
+					Compress,
+					Challenger_,
+				>(
+					&verifier_constraint_system,
+					log_inv_rate,
+					security_bits,
+					&[],
+					proof.clone(),
+					VerifyOptions {
+						univariate_skip_rounds,
+						..Default::default()
+					},
+				)
+			};
+			verify(univariate_skip_rounds).unwrap();
+
+			// A verifier configured with a different number of skipped rounds rejects the proof.
+			let mismatched = match univariate_skip_rounds {
+				UnivariateSkipRounds::AtMost(0) => UnivariateSkipRounds::Standard,
+				_ => UnivariateSkipRounds::AtMost(0),
+			};
+			assert!(matches!(
+				verify(mismatched),
+				Err(constraint_system::error::Error::Verification(
+					VerificationError::UnivariateSkipRoundsMismatch { .. }
+				))
+			));
+		}
+	}
+
+	#[test]
+	fn test_compiled_circuit_evaluator() {
+		type Hash = Groestl256;
+		type Compress = Groestl256ByteCompression;
+		type Challenger_ = HasherChallenger<Groestl256>;
+
+		let log_size = 9;
+		let log_inv_rate = 1;
+		let security_bits = 30;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		builder.set_circuit_evaluator(CircuitEvaluator::Compiled);
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		assert!(constraint_system
+			.table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.all(|constraint| constraint.evaluator == CircuitEvaluator::Compiled));
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let proof =
+			constraint_system::prove::<U, CanonicalTowerFamily, _, Hash, Compress, Challenger_, _>(
+				&constraint_system,
+				log_inv_rate,
+				security_bits,
+				&[],
+				witness,
+				&domain_factory,
+				&backend,
+			)
+			.unwrap();
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system,
+			log_inv_rate,
+			security_bits,
+			&[],
+			proof,
+		)
+		.unwrap();
+	}
+
+	#[cfg(feature = "mmap")]
+	#[test]
+	fn test_prove_verify_out_of_core() {
+		type Hash = Groestl256;
+		type Compress = Groestl256ByteCompression;
+		type Challenger_ = HasherChallenger<Groestl256>;
+
+		let log_size = 12;
+		let log_inv_rate = 1;
+		let security_bits = 30;
+
+		// Store the witness columns and every backend buffer above 1 KiB in memory-mapped files.
+		let backend = MmapBackend::with_spill_threshold(1 << 10);
+		let arena = backend.arena();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&arena);
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked).unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let proof =
+			constraint_system::prove::<U, CanonicalTowerFamily, _, Hash, Compress, Challenger_, _>(
+				&constraint_system,
+				log_inv_rate,
+				security_bits,
+				&[],
+				witness,
+				&domain_factory,
+				&backend,
+			)
+			.unwrap();
+
+		constraint_system::verify::<U, CanonicalTowerFamily, Hash, Compress, Challenger_>(
+			&constraint_system,
+			log_inv_rate,
+			security_bits,
+			&[],
+			proof,
+		)
+		.unwrap();
+	}
+
+	#[test]
+	fn test_eval_queries() {
+		type Hash = Groestl256;
+		type Compress = Groestl256ByteCompression;
+		type Challenger_ = HasherChallenger<Groestl256>;
+
+		let log_size = 7;
+		let log_inv_rate = 1;
+		let security_bits = 30;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::new_with_witness(&allocator);
+		let a = unconstrained::<BinaryField1b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<BinaryField1b>(&mut builder, "b", log_size).unwrap();
+		let c = arithmetic::u32::add(&mut builder, "u32add", a, b, arithmetic::Flags::Unchecked)
+			.unwrap();
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let eval_queries = [a, c]
+			.into_iter()
+			.map(|id| OracleEvalQuery {
+				id,
+				eval_point: repeat_with(|| <F as Field>::random(&mut rng))
+					.take(constraint_system.oracles.n_vars(id))
+					.collect(),
+			})
+			.collect::<Vec<_>>();
+		let expected_evals = eval_queries
+			.iter()
+			.map(|query| {
+				let multilin_query =
+					MultilinearQuery::<PackedType<U, F>>::expand(&query.eval_point);
+				witness
+					.get_multilin_poly(query.id)
+					.unwrap()
+					.evaluate(multilin_query.to_ref())
+					.unwrap()
+			})
+			.collect::<Vec<_>>();
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+		let (proof, evals) = constraint_system::prove_with_options::<
 			U,
 			CanonicalTowerFamily,
-			Groestl256,
-			Groestl256ByteCompression,
-			HasherChallenger<Groestl256>,
-		>(&constraint_system, 1, 10, &boundaries, proof)
+			_,
+			Hash,
+			Compress,
+			Challenger_,
+			_,
+		>(
+			&constraint_system,
+			log_inv_rate,
+			security_bits,
+			&[],
+			witness,
+			&domain_factory,
+			ProveOptions {
+				eval_queries: &eval_queries,
+				..Default::default()
+			},
+			&backend,
+		)
 		.unwrap();
+		assert_eq!(evals, expected_evals);
+
+		let verify = |evals: &[F]| {
+			constraint_system::verify_with_options::<
+				U,
+				CanonicalTowerFamily,
+				Hash,
+				Compress,
+				Challenger_,
+			>(
+				&constraint_system,
+				log_inv_rate,
+				security_bits,
+				&[],
+				proof.clone(),
+				VerifyOptions {
+					eval_queries: &eval_queries,
+					evals,
+					..Default::default()
+				},
+			)
+		};
+		verify(&evals).unwrap();
+
+		let mut wrong_evals = evals;
+		wrong_evals[1] += F::ONE;
+		assert!(verify(&wrong_evals).is_err());
 	}
 
 	#[test]
@@ -12,15 +12,14 @@ use std::array;
 use anyhow::Result;
 use binius_core::{oracle::OracleId, transparent::constant::Constant};
 use binius_field::{
-	linear_transformation::Transformation, make_aes_to_binary_packed_transformer,
-	packed::get_packed_slice, AESTowerField32b, BinaryField1b, BinaryField32b, ExtensionField,
-	Field, PackedAESBinaryField8x32b, PackedBinaryField8x32b, PackedExtension, PackedField,
-	TowerField,
+	batch_invert_or_zero_par, linear_transformation::Transformation,
+	make_aes_to_binary_packed_transformer, packed::get_packed_slice, AESTowerField32b,
+	BinaryField1b, BinaryField32b, ExtensionField, Field, PackedAESBinaryField8x32b,
+	PackedBinaryField8x32b, PackedExtension, PackedField, TowerField,
 };
 use binius_hash::{Vision32MDSTransform, INV_PACKED_TRANS_AES};
 use binius_macros::arith_expr;
 use binius_math::ArithExpr;
-use bytemuck::must_cast_slice;
 
 use crate::builder::{types::F, ConstraintSystemBuilder};
 
@@ -339,7 +338,6 @@ where {
 	if let Some(witness) = builder.witness() {
 		let perm_in_data_owned: [_; STATE_SIZE] =
 			array::try_from_fn(|i| witness.get::<B32>(perm_in[i]))?;
-		let perm_in_data: [_; STATE_SIZE] = perm_in_data_owned.map(|elem| elem.as_slice::<B32>());
 
 		let mut even_round_consts = even_round_consts.map(|id| witness.new_column::<B32>(id));
 		let mut inv_0 = inv_0.map(|id| witness.new_column::<B32>(id));
@@ -352,8 +350,14 @@ where {
 		let mut mds_out_1 = mds_out_1.map(|id| witness.new_column::<B32>(id));
 		let mut perm_out = perm_out.map(|id| witness.new_column::<B32>(id));
 
-		let inv_0_slice = inv_0.each_mut().map(|elem| elem.as_mut_slice());
-		let s_box_out_0_slice = s_box_out_0.each_mut().map(|elem| elem.as_mut_slice());
+		// The inputs of the first S-box layer are known upfront, so they are inverted in batches.
+		for (inv_0, perm_in) in inv_0.iter_mut().zip(&perm_in_data_owned) {
+			inv_0.packed().copy_from_slice(perm_in.packed());
+			batch_invert_or_zero_par(inv_0.packed());
+		}
+
+		let inv_0_slice = inv_0.each_mut().map(|elem| elem.as_mut_slice::<B32>());
+		let mut s_box_out_0_slice = s_box_out_0.each_mut().map(|elem| elem.as_mut_slice());
 		let mds_out_0_slice = mds_out_0.each_mut().map(|elem| elem.as_mut_slice());
 		let round_out_0_slice = round_out_0
 			.each_mut()
@@ -381,9 +385,7 @@ where {
 
 		for z in 0..1 << log_size {
 			// Even rounds
-			let input: [_; STATE_SIZE] =
-				array::from_fn(|row| must_cast_slice::<_, B32>(perm_in_data[row])[z]);
-			let inverse_0 = input.map(B32::invert_or_zero);
+			let inverse_0: [_; STATE_SIZE] = array::from_fn(|row| inv_0_slice[row][z]);
 
 			let sbox_out_packed: [PackedBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
 				let inp = PackedAESBinaryField8x32b::from_fn(|pack_idx| {
@@ -395,10 +397,8 @@ where {
 				) + inv_const_packed
 			});
 
-			for i in 0..STATE_SIZE {
-				let sbox_out = get_packed_slice(&sbox_out_packed, i);
-				inv_0_slice[i][z] = inverse_0[i];
-				s_box_out_0_slice[i][z] = sbox_out;
+			for (i, s_box_out_0) in s_box_out_0_slice.iter_mut().enumerate() {
+				s_box_out_0[z] = get_packed_slice(&sbox_out_packed, i);
 			}
 			let mut inp_as_packed_aes: [PackedAESBinaryField8x32b; 3] = array::from_fn(|arr_idx| {
 				PackedAESBinaryField8x32b::from_fn(|pack_idx| {
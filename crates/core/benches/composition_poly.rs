@@ -2,10 +2,10 @@
 
 use std::iter::repeat_with;
 
-use binius_core::polynomial::ArithCircuitPoly;
+use binius_core::polynomial::{ArithCircuitPoly, CircuitEvaluator};
 use binius_field::{
-	BinaryField1b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField1x128b,
-	PackedField,
+	BinaryField1b, BinaryField8b, Field, PackedBinaryField128x1b, PackedBinaryField16x8b,
+	PackedBinaryField1x128b, PackedField,
 };
 use binius_macros::{arith_circuit_poly, composition_poly};
 use binius_math::{ArithExpr as Expr, CompositionPoly};
@@ -63,6 +63,9 @@ fn benchmark_evaluate(c: &mut Criterion) {
 			+ (Expr::Const(BinaryField1b::ONE) - Expr::Var(0)) * Expr::Var(2)
 			- Expr::Var(3),
 	);
+	let arith_circuit_poly_compiled = arith_circuit_poly
+		.clone()
+		.with_evaluator(CircuitEvaluator::Compiled);
 	let arith_circuit_poly_cached =
 		arith_circuit_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch, BinaryField1b);
 
@@ -78,6 +81,11 @@ fn benchmark_evaluate(c: &mut Criterion) {
 			evaluate_arith_circuit_poly(&query128x1b, &arith_circuit_poly_cached);
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_128x1b", |bench| {
+		bench.iter(|| {
+			evaluate_arith_circuit_poly(&query128x1b, &arith_circuit_poly_compiled);
+		});
+	});
 	group.bench_function("composition_poly_128x1b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -95,6 +103,11 @@ fn benchmark_evaluate(c: &mut Criterion) {
 			evaluate_arith_circuit_poly(&query16x8b, &arith_circuit_poly_cached);
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_16x8b", |bench| {
+		bench.iter(|| {
+			evaluate_arith_circuit_poly(&query16x8b, &arith_circuit_poly_compiled);
+		});
+	});
 	group.bench_function("composition_poly_16x8b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -112,6 +125,11 @@ fn benchmark_evaluate(c: &mut Criterion) {
 			evaluate_arith_circuit_poly(&query1x128b, &arith_circuit_poly_cached);
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_1x128b", |bench| {
+		bench.iter(|| {
+			evaluate_arith_circuit_poly(&query1x128b, &arith_circuit_poly_compiled);
+		});
+	});
 	group.bench_function("composition_poly_1x128b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -136,6 +154,13 @@ fn benchmark_evaluate(c: &mut Criterion) {
 				.unwrap();
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_128x1b", |bench| {
+		bench.iter(|| {
+			arith_circuit_poly_compiled
+				.batch_evaluate(&query128x1b, &mut results128x1b)
+				.unwrap();
+		});
+	});
 	group.bench_function("composition_poly_128x1b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -158,6 +183,13 @@ fn benchmark_evaluate(c: &mut Criterion) {
 				.unwrap();
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_16x8b", |bench| {
+		bench.iter(|| {
+			arith_circuit_poly_compiled
+				.batch_evaluate(&query16x8b, &mut results16x8b)
+				.unwrap();
+		});
+	});
 	group.bench_function("composition_poly_16x8b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -179,6 +211,13 @@ fn benchmark_evaluate(c: &mut Criterion) {
 				.unwrap();
 		});
 	});
+	group.bench_function("arith_circuit_poly_compiled_1x128b", |bench| {
+		bench.iter(|| {
+			arith_circuit_poly_compiled
+				.batch_evaluate(&query1x128b, &mut results1x128b)
+				.unwrap();
+		});
+	});
 	group.bench_function("composition_poly_1x128b", |bench| {
 		let poly = composition_poly!([h4, h5, h6, ch] = (h4 * h5 + (1 - h4) * h6) - ch);
 		bench.iter(|| {
@@ -189,5 +228,58 @@ fn benchmark_evaluate(c: &mut Criterion) {
 	group.finish();
 }
 
+/// Benchmarks a composition with many repeated subexpressions, where the compiled evaluator
+/// computes every shared term once.
+fn benchmark_large_composition(c: &mut Criterion) {
+	let mut rng = thread_rng();
+
+	let query = generate_input_data::<PackedBinaryField16x8b>(&mut rng);
+	let query = query.iter().map(|q| q.as_slice()).collect::<Vec<_>>();
+	let mut results = vec![PackedBinaryField16x8b::zero(); BATCH_SIZE];
+
+	// Sum over all pairs of the products of the pairwise sums, each raised to a power.
+	let terms = (0..4)
+		.flat_map(|i| (i + 1..4).map(move |j| Expr::Var(i) + Expr::Var(j)))
+		.collect::<Vec<Expr<BinaryField8b>>>();
+	let expr = terms
+		.iter()
+		.flat_map(|lhs| {
+			terms
+				.iter()
+				.map(move |rhs| (lhs.clone() * rhs.clone()).pow(3))
+		})
+		.reduce(|acc, term| acc + term)
+		.unwrap();
+
+	let interpreted = ArithCircuitPoly::new(expr);
+	let compiled = interpreted
+		.clone()
+		.with_evaluator(CircuitEvaluator::Compiled);
+
+	let mut group = c.benchmark_group("large_composition");
+	group.throughput(Throughput::Elements(BATCH_SIZE as _));
+	group.bench_function("evaluate_interpreted_16x8b", |bench| {
+		bench.iter(|| {
+			evaluate_arith_circuit_poly(&query, &interpreted);
+		});
+	});
+	group.bench_function("evaluate_compiled_16x8b", |bench| {
+		bench.iter(|| {
+			evaluate_arith_circuit_poly(&query, &compiled);
+		});
+	});
+	group.bench_function("batch_evaluate_interpreted_16x8b", |bench| {
+		bench.iter(|| {
+			interpreted.batch_evaluate(&query, &mut results).unwrap();
+		});
+	});
+	group.bench_function("batch_evaluate_compiled_16x8b", |bench| {
+		bench.iter(|| {
+			compiled.batch_evaluate(&query, &mut results).unwrap();
+		});
+	});
+	group.finish();
+}
+
 criterion_main!(composition_poly);
-criterion_group!(composition_poly, benchmark_evaluate);
+criterion_group!(composition_poly, benchmark_evaluate, benchmark_large_composition);
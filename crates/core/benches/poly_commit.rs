@@ -13,6 +13,7 @@ use binius_field::{
 	arch::OptimalUnderlier, as_packed_field::PackedType, packed::set_packed_slice, BinaryField128b,
 	BinaryField32b, PackedField,
 };
+use binius_hal::make_portable_backend;
 use binius_hash::compress::Groestl256ByteCompression;
 use binius_math::{MLEDirectAdapter, MultilinearExtension, MultilinearPoly};
 use binius_ntt::{NTTOptions, ThreadingSettings};
@@ -68,18 +69,24 @@ fn bench_poly_commit(c: &mut Criterion) {
 		&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		},
 	)
 	.unwrap();
+	let backend = make_portable_backend();
 	let mut group = c.benchmark_group("Polynomial Commitment");
 	group.throughput(Throughput::Bytes(
 		((1 << LOG_SIZE) * committed_multilins.len() * std::mem::size_of::<F>()) as u64,
 	));
 	group.bench_function(BenchmarkId::new("log_size", LOG_SIZE), |b| {
 		b.iter(|| {
-			fri::commit_interleaved_with(&rs_code, &fri_params, &merkle_prover, |message_buffer| {
-				merge_multilins(&committed_multilins, message_buffer)
-			})
+			fri::commit_interleaved_with(
+				&rs_code,
+				&fri_params,
+				&merkle_prover,
+				|message_buffer| merge_multilins(&committed_multilins, message_buffer),
+				&backend,
+			)
 			.unwrap();
 		});
 	});
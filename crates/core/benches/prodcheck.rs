@@ -12,8 +12,9 @@ use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::{PackedTransformationFactory, Transformation},
 	AESTowerField8b, BinaryField, BinaryField128b, BinaryField128bPolyval, BinaryField8b,
-	ByteSlicedAES16x128b, ByteSlicedAES32x128b, ByteSlicedAES64x128b, PackedExtension, PackedField,
-	PackedFieldIndexable, TowerField, BINARY_TO_POLYVAL_TRANSFORMATION,
+	ByteSliced16x128b, ByteSlicedAES16x128b, ByteSlicedAES32x128b, ByteSlicedAES64x128b,
+	PackedExtension, PackedField, PackedFieldIndexable, TowerField,
+	BINARY_TO_POLYVAL_TRANSFORMATION,
 };
 use binius_hal::{make_portable_backend, CpuBackend};
 use binius_math::{
@@ -230,6 +231,15 @@ fn bench_byte_sliced_aes_512b(c: &mut Criterion) {
 	);
 }
 
+fn bench_byte_sliced_128b(c: &mut Criterion) {
+	// TODO: this benchmarks should account for the byte sliced transposition time
+	bench_gpa::<ByteSliced16x128b, BinaryField8b>(
+		"gpa_byte_sliced_128b",
+		EvaluationOrder::HighToLow,
+		c,
+	);
+}
+
 fn bench_binary_128b_isomorphic(c: &mut Criterion) {
 	bench_gpa_polyval_with_isomorphism::<OptimalUnderlier>(
 		"gpa_binary_128b_isomorphic",
@@ -247,5 +257,6 @@ criterion_group!(
 	bench_byte_sliced_aes_128b,
 	bench_byte_sliced_aes_256b,
 	bench_byte_sliced_aes_512b,
+	bench_byte_sliced_128b,
 	bench_binary_128b_isomorphic
 );
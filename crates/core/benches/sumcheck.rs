@@ -0,0 +1,117 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_core::{
+	fiat_shamir::HasherChallenger,
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{
+		batch_prove, prove::RegularSumcheckProver, CompositeSumClaim, SwitchoverPolicy,
+	},
+	transcript::ProverTranscript,
+};
+use binius_field::{
+	arch::OptimalUnderlier,
+	as_packed_field::{PackScalar, PackedType},
+	BinaryField128b, BinaryField1b, BinaryField32b, BinaryField8b, ExtensionField, PackedField,
+	TowerField,
+};
+use binius_hal::make_portable_backend;
+use binius_math::{
+	ArithExpr, EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEEmbeddingAdapter,
+	MultilinearExtension, MultilinearPoly,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+type U = OptimalUnderlier;
+type FExt = BinaryField128b;
+type PExt = PackedType<U, FExt>;
+
+const N_VARS: usize = 16;
+const DEGREES: [usize; 5] = [2, 3, 4, 6, 8];
+const POLICIES: [(&str, SwitchoverPolicy); 2] = [
+	("standard", SwitchoverPolicy::Standard(-2)),
+	("immediate", SwitchoverPolicy::Immediate),
+];
+
+fn product_composition(degree: usize) -> ArithCircuitPoly<FExt> {
+	let expr = (0..degree)
+		.map(ArithExpr::Var)
+		.reduce(|acc, var| acc * var)
+		.expect("degree is at least one");
+	ArithCircuitPoly::new(expr)
+}
+
+/// Benchmarks a sumcheck over the product of `degree` multilinears over `F`, which is the
+/// composition of degree `degree` that is cheapest to evaluate, so the timings are dominated by
+/// the multilinear evaluations that the switchover policy affects.
+fn bench_sumcheck_field<F>(c: &mut Criterion, field_name: &str)
+where
+	F: TowerField,
+	FExt: ExtensionField<F>,
+	U: PackScalar<F>,
+{
+	let mut group = c.benchmark_group(format!("sumcheck/{field_name}"));
+	group.throughput(Throughput::Elements(1 << N_VARS));
+	group.sample_size(10);
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+
+	for degree in DEGREES {
+		let multilinears = repeat_with(|| {
+			let values = repeat_with(|| PackedType::<U, F>::random(&mut rng))
+				.take(1 << N_VARS.saturating_sub(PackedType::<U, F>::LOG_WIDTH))
+				.collect::<Vec<_>>();
+			let mle = MultilinearExtension::new(N_VARS, values).unwrap();
+			MLEEmbeddingAdapter::<_, PExt, _>::from(mle)
+		})
+		.take(degree)
+		.collect::<Vec<_>>();
+		let composition = product_composition(degree);
+		let sum = (0..1 << N_VARS)
+			.map(|index| {
+				multilinears
+					.iter()
+					.map(|multilinear| multilinear.evaluate_on_hypercube(index).unwrap())
+					.product::<FExt>()
+			})
+			.sum::<FExt>();
+
+		for (policy_name, policy) in POLICIES {
+			let switchover_fn = policy.switchover_fn();
+			group.bench_function(BenchmarkId::new(policy_name, format!("degree={degree}")), |b| {
+				b.iter(|| {
+					let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+						EvaluationOrder::LowToHigh,
+						multilinears.iter().collect(),
+						[CompositeSumClaim {
+							composition: &composition,
+							sum,
+						}],
+						&domain_factory,
+						switchover_fn,
+						&backend,
+					)
+					.unwrap();
+					let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+					batch_prove(vec![prover], &mut transcript).unwrap()
+				});
+			});
+		}
+	}
+
+	group.finish()
+}
+
+fn bench_sumcheck(c: &mut Criterion) {
+	bench_sumcheck_field::<BinaryField1b>(c, "B1");
+	bench_sumcheck_field::<BinaryField8b>(c, "B8");
+	bench_sumcheck_field::<BinaryField32b>(c, "B32");
+}
+
+criterion_main!(sumcheck);
+criterion_group!(sumcheck, bench_sumcheck);
@@ -15,8 +15,31 @@ pub type FFastExt<Tower> = <Tower as ProverTowerFamily>::FastB128;
 /// technique.
 pub type FDomain<Tower> = <Tower as TowerFamily>::B8;
 
+/// The evaluation domain used by the univariate skip when more rounds are skipped than [`FDomain`]
+/// supports.
+///
+/// The zerocheck univariate round is evaluated over a domain of size $d \cdot 2^k$ for $k$ skipped
+/// rounds and composition degree $d$. The 16-bit domain accommodates up to 16 skipped rounds, at
+/// the cost of lifting all witness data in the univariate round to at least 16-bit fields.
+pub type FExtendedDomain<Tower> = <Tower as TowerFamily>::B16;
+
 /// The Reed–Solomon alphabet used for FRI encoding.
 ///
 /// This is fixed to be 32-bits, which is large enough to handle trace sizes up to 512 GiB
 /// of committed data.
 pub type FEncode<Tower> = <Tower as TowerFamily>::B32;
+
+/// Number of zerocheck rounds that are skipped with the univariate skip technique.
+///
+/// Both prover and verifier must be configured with the same value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum UnivariateSkipRounds {
+	/// Skip as many rounds as the [`FDomain`] evaluation domain supports.
+	#[default]
+	Standard,
+	/// Skip at most the given number of rounds.
+	///
+	/// Zero disables the univariate skip. Counts larger than what [`FDomain`] supports are served
+	/// by the [`FExtendedDomain`] evaluation domain.
+	AtMost(usize),
+}
@@ -105,4 +105,8 @@ pub enum VerificationError {
 		"Channel with id={id} is not balanced. Pushes and pulls do not contain the same elements"
 	)]
 	ChannelUnbalanced { id: ChannelId },
+	#[error(
+		"the proof skips {got} zerocheck rounds with the univariate skip, expected {expected}"
+	)]
+	UnivariateSkipRoundsMismatch { expected: usize, got: usize },
 }
@@ -11,8 +11,9 @@ use binius_field::{BinaryField128b, TowerField};
 use binius_macros::SerializeBytes;
 use binius_utils::{DeserializeBytes, SerializationError, SerializationMode};
 use channel::{ChannelId, Flush};
-pub use prove::prove;
-pub use verify::verify;
+pub use common::UnivariateSkipRounds;
+pub use prove::{prove, prove_with_options, ProveOptions};
+pub use verify::{verify, verify_with_options, VerifyOptions};
 
 use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
 
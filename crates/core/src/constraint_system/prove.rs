@@ -6,10 +6,10 @@ use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
 	linear_transformation::{PackedTransformationFactory, Transformation},
 	underlier::WithUnderlier,
-	BinaryField, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
-	RepackedExtension, TowerField,
+	ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable, RepackedExtension,
+	TowerField,
 };
-use binius_hal::ComputationBackend;
+use binius_hal::{ComputationBackend, ComputationBackendExt};
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{
 	EvaluationDomainFactory, EvaluationOrder, IsomorphicEvaluationDomainFactory, MLEDirectAdapter,
@@ -26,19 +26,19 @@ use super::{
 	channel::Boundary,
 	error::Error,
 	verify::{
-		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles,
-		max_n_vars_and_skip_rounds, reorder_for_flushing_by_n_vars,
+		get_post_flush_sumcheck_eval_claims_without_eq, make_flush_oracles, observe_eval_queries,
+		reorder_for_flushing_by_n_vars, zerocheck_skip_rounds,
 	},
 	ConstraintSystem, Proof,
 };
 use crate::{
 	constraint_system::{
-		common::{FDomain, FEncode, FExt, FFastExt},
+		common::{FDomain, FEncode, FExt, FExtendedDomain, FFastExt, UnivariateSkipRounds},
 		verify::{get_flush_dedup_sumcheck_metas, FlushSumcheckMeta},
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeProver,
-	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleId},
+	oracle::{Constraint, MultilinearOracleSet, MultilinearPolyVariant, OracleEvalQuery, OracleId},
 	piop,
 	protocols::{
 		fri::CommitOutput,
@@ -50,23 +50,24 @@ use crate::{
 		sumcheck::{
 			self, constraint_set_zerocheck_claim,
 			prove::{SumcheckProver, UnivariateZerocheckProver},
-			standard_switchover_heuristic, zerocheck,
+			zerocheck, SwitchoverPolicy,
 		},
 	},
 	ring_switch,
 	tower::{PackedTop, ProverTowerFamily, ProverTowerUnderlier},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
 /// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS.
-#[instrument("constraint_system::prove", skip_all, level = "debug")]
+///
+/// The prover runs with the default [`ProveOptions`]; see [`prove_with_options`] to override them.
 pub fn prove<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
 	security_bits: usize,
 	boundaries: &[Boundary<FExt<Tower>>],
-	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	witness: MultilinearExtensionIndex<U, FExt<Tower>>,
 	domain_factory: DomainFactory,
 	backend: &Backend,
 ) -> Result<Proof, Error>
@@ -95,6 +96,95 @@ where
 	PackedType<U, Tower::B32>: PackedFieldIndexable,
 	PackedType<U, Tower::B64>: PackedFieldIndexable,
 {
+	let (proof, _) = prove_with_options::<_, _, _, Hash, Compress, Challenger_, _>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		witness,
+		domain_factory,
+		ProveOptions::default(),
+		backend,
+	)?;
+	Ok(proof)
+}
+
+/// Parameters of [`prove_with_options`].
+#[derive(Debug, Clone, Copy)]
+pub struct ProveOptions<'a, F: Field> {
+	/// Policy choosing the switchover rounds of the zerocheck provers.
+	pub switchover_policy: SwitchoverPolicy,
+	/// Number of zerocheck rounds skipped with the univariate skip technique.
+	///
+	/// The resolved number of skipped rounds is written to the proof, and the verifier rejects
+	/// proofs that disagree with its [`VerifyOptions`](super::VerifyOptions).
+	pub univariate_skip_rounds: UnivariateSkipRounds,
+	/// Oracles to open at points chosen by the caller, in addition to the evaluation claims of the
+	/// constraint system itself.
+	///
+	/// The verifier must be given the same queries, along with the evaluations returned by
+	/// [`prove_with_options`].
+	pub eval_queries: &'a [OracleEvalQuery<F>],
+}
+
+impl<F: Field> Default for ProveOptions<'_, F> {
+	fn default() -> Self {
+		Self {
+			switchover_policy: SwitchoverPolicy::default(),
+			univariate_skip_rounds: UnivariateSkipRounds::default(),
+			eval_queries: &[],
+		}
+	}
+}
+
+/// Generates a proof that a witness satisfies a constraint system with the standard FRI PCS,
+/// using the given prover options.
+///
+/// Returns the proof along with the evaluations of the oracles queried by
+/// [`ProveOptions::eval_queries`], in query order.
+#[allow(clippy::too_many_arguments)]
+#[instrument("constraint_system::prove", skip_all, level = "debug")]
+pub fn prove_with_options<U, Tower, DomainFactory, Hash, Compress, Challenger_, Backend>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	mut witness: MultilinearExtensionIndex<U, FExt<Tower>>,
+	domain_factory: DomainFactory,
+	options: ProveOptions<FExt<Tower>>,
+	backend: &Backend,
+) -> Result<(Proof, Vec<FExt<Tower>>), Error>
+where
+	U: ProverTowerUnderlier<Tower>,
+	Tower: ProverTowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	Hash: Digest + BlockSizeUser + FixedOutputReset,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+	Backend: ComputationBackend,
+	// REVIEW: Consider changing TowerFamily and associated traits to shorten/remove these bounds
+	PackedType<U, Tower::B128>: PackedTop<Tower>
+		+ PackedFieldIndexable
+		+ RepackedExtension<PackedType<U, Tower::B8>>
+		+ RepackedExtension<PackedType<U, Tower::B16>>
+		+ RepackedExtension<PackedType<U, Tower::B32>>
+		+ RepackedExtension<PackedType<U, Tower::B64>>
+		+ RepackedExtension<PackedType<U, Tower::B128>>
+		+ PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
+	PackedType<U, Tower::FastB128>:
+		PackedFieldIndexable + PackedTransformationFactory<PackedType<U, Tower::B128>>,
+	PackedType<U, Tower::B8>: PackedFieldIndexable,
+	PackedType<U, Tower::B16>: PackedFieldIndexable,
+	PackedType<U, Tower::B32>: PackedFieldIndexable,
+	PackedType<U, Tower::B64>: PackedFieldIndexable,
+{
+	let ProveOptions {
+		switchover_policy,
+		univariate_skip_rounds,
+		eval_queries,
+	} = options;
+
 	tracing::debug!(
 		arch = env::consts::ARCH,
 		rayon_threads = binius_maybe_rayon::current_num_threads(),
@@ -104,6 +194,7 @@ where
 	let fast_domain_factory = IsomorphicEvaluationDomainFactory::<FFastExt<Tower>>::default();
 
 	let mut transcript = ProverTranscript::<Challenger_>::new();
+	transcript.domain_separator(labels::CONSTRAINT_SYSTEM);
 	transcript.observe().write_slice(boundaries);
 
 	let ConstraintSystem {
@@ -114,11 +205,26 @@ where
 		max_channel_id,
 	} = constraint_system.clone();
 
+	// Evaluate the queried oracles and bind the openings to the transcript
+	let eval_query_evals = eval_queries
+		.iter()
+		.map(|query| {
+			oracles.check_eval_query(query)?;
+			let multilin = witness.get_multilin_poly(query.id)?;
+			let multilin_query =
+				backend.multilinear_query::<PackedType<U, FExt<Tower>>>(&query.eval_point)?;
+			Ok(multilin.evaluate(multilin_query.to_ref())?)
+		})
+		.collect::<Result<Vec<_>, Error>>()?;
+	let eval_query_claims = oracles.eval_claims(eval_queries, &eval_query_evals)?;
+	observe_eval_queries(&mut transcript.observe(), eval_queries, &eval_query_evals);
+
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
 	// Commit polynomials
-	let merkle_prover = BinaryMerkleTreeProver::<_, Hash, _>::new(Compress::default());
+	let merkle_prover =
+		BinaryMerkleTreeProver::<_, Hash, _, _>::with_backend(Compress::default(), backend);
 	let merkle_scheme = merkle_prover.scheme();
 
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
@@ -139,7 +245,7 @@ where
 		commitment,
 		committed,
 		codeword,
-	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins)?;
+	} = piop::commit(&fri_params, &merkle_prover, &committed_multilins, backend)?;
 
 	// Observe polynomial commitment
 	let mut writer = transcript.message();
@@ -148,7 +254,7 @@ where
 	// Grand product arguments
 	// Grand products for non-zero checking
 	let non_zero_fast_witnesses =
-		make_fast_masked_flush_witnesses(&oracles, &witness, &non_zero_oracle_ids, None)?;
+		make_fast_masked_flush_witnesses(&oracles, &witness, &non_zero_oracle_ids, None, backend)?;
 	let non_zero_prodcheck_witnesses = non_zero_fast_witnesses
 		.into_par_iter()
 		.map(GrandProductWitness::new)
@@ -183,13 +289,14 @@ where
 		.map(|flush| flush.selector)
 		.collect::<Vec<_>>();
 
-	make_unmasked_flush_witnesses(&oracles, &mut witness, &flush_oracle_ids)?;
+	make_unmasked_flush_witnesses(&oracles, &mut witness, &flush_oracle_ids, backend)?;
 	// there are no oracle ids associated with these flush_witnesses
 	let flush_witnesses = make_fast_masked_flush_witnesses(
 		&oracles,
 		&witness,
 		&flush_oracle_ids,
 		Some(&flush_selectors),
+		backend,
 	)?;
 
 	// This is important to do in parallel.
@@ -274,13 +381,14 @@ where
 		.into_iter()
 		.unzip::<_, _, Vec<_>, Vec<_>>();
 
-	let (max_n_vars, skip_rounds) =
-		max_n_vars_and_skip_rounds(&zerocheck_claims, FDomain::<Tower>::N_BITS);
+	let (max_n_vars, skip_rounds, use_extended_domain) =
+		zerocheck_skip_rounds::<Tower, _, _>(&zerocheck_claims, univariate_skip_rounds);
+	let extended_domain_factory =
+		IsomorphicEvaluationDomainFactory::<FExtendedDomain<Tower>>::default();
 
+	transcript.message().write(&skip_rounds);
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
-	let switchover_fn = standard_switchover_heuristic(-2);
-
 	let mut univariate_provers = Vec::new();
 	let mut tail_regular_zerocheck_provers = Vec::new();
 	let mut univariatized_multilinears = Vec::new();
@@ -303,26 +411,56 @@ where
 		.max()
 		.unwrap_or(0);
 
+		let switchover_fn = switchover_policy.switchover_fn();
+
 		univariatized_multilinears.push(multilinears.clone());
 
-		let constructor =
-			ZerocheckProverConstructor::<PackedType<U, FExt<Tower>>, FDomain<Tower>, _, _, _> {
+		let either_prover = if use_extended_domain {
+			let constructor = ZerocheckProverConstructor::<
+				PackedType<U, FExt<Tower>>,
+				FExtendedDomain<Tower>,
+				_,
+				_,
+				_,
+			> {
 				constraints,
 				multilinears,
-				domain_factory: &domain_factory,
+				domain_factory: &extended_domain_factory,
 				switchover_fn,
 				zerocheck_challenges: &zerocheck_challenges[skip_challenges..],
 				backend,
 				_fdomain_marker: PhantomData,
 			};
 
-		let either_prover = match base_tower_level {
-			0..=3 => constructor.create::<Tower::B8>(univariate_decider)?,
-			4 => constructor.create::<Tower::B16>(univariate_decider)?,
-			5 => constructor.create::<Tower::B32>(univariate_decider)?,
-			6 => constructor.create::<Tower::B64>(univariate_decider)?,
-			7 => constructor.create::<Tower::B128>(univariate_decider)?,
-			_ => unreachable!(),
+			// The extended domain requires the univariate round to be evaluated over at least
+			// 16-bit fields.
+			match base_tower_level {
+				0..=4 => constructor.create::<Tower::B16>(univariate_decider)?,
+				5 => constructor.create::<Tower::B32>(univariate_decider)?,
+				6 => constructor.create::<Tower::B64>(univariate_decider)?,
+				7 => constructor.create::<Tower::B128>(univariate_decider)?,
+				_ => unreachable!(),
+			}
+		} else {
+			let constructor =
+				ZerocheckProverConstructor::<PackedType<U, FExt<Tower>>, FDomain<Tower>, _, _, _> {
+					constraints,
+					multilinears,
+					domain_factory: &domain_factory,
+					switchover_fn,
+					zerocheck_challenges: &zerocheck_challenges[skip_challenges..],
+					backend,
+					_fdomain_marker: PhantomData,
+				};
+
+			match base_tower_level {
+				0..=3 => constructor.create::<Tower::B8>(univariate_decider)?,
+				4 => constructor.create::<Tower::B16>(univariate_decider)?,
+				5 => constructor.create::<Tower::B32>(univariate_decider)?,
+				6 => constructor.create::<Tower::B64>(univariate_decider)?,
+				7 => constructor.create::<Tower::B128>(univariate_decider)?,
+				_ => unreachable!(),
+			}
 		};
 
 		match either_prover {
@@ -376,14 +514,28 @@ where
 			univariatized_multilinear_evals,
 		)?;
 
-		let reduction_prover =
-			sumcheck::prove::univariatizing_reduction_prover::<_, FDomain<Tower>, _, _>(
+		let reduction_prover = if use_extended_domain {
+			Box::new(sumcheck::prove::univariatizing_reduction_prover::<
+				_,
+				FExtendedDomain<Tower>,
+				_,
+				_,
+			>(
+				reduced_multilinears,
+				univariatized_multilinear_evals,
+				univariate_challenge,
+				&extended_domain_factory,
+				backend,
+			)?) as TypeErasedSumcheck<'_, _>
+		} else {
+			Box::new(sumcheck::prove::univariatizing_reduction_prover::<_, FDomain<Tower>, _, _>(
 				reduced_multilinears,
 				univariatized_multilinear_evals,
 				univariate_challenge,
 				&domain_factory,
 				backend,
-			)?;
+			)?) as TypeErasedSumcheck<'_, _>
+		};
 
 		reduction_claims.push(reduction_claim);
 		reduction_provers.push(reduction_prover);
@@ -408,8 +560,9 @@ where
 		[non_zero_prodcheck_eval_claims, flush_eval_claims]
 			.concat()
 			.into_iter()
-			.chain(zerocheck_eval_claims),
-		switchover_fn,
+			.chain(zerocheck_eval_claims)
+			.chain(eval_query_claims),
+		switchover_policy.switchover_fn(),
 		&mut transcript,
 		&domain_factory,
 		backend,
@@ -448,9 +601,10 @@ where
 		&backend,
 	)?;
 
-	Ok(Proof {
+	let proof = Proof {
 		transcript: transcript.finalize(),
-	})
+	};
+	Ok((proof, eval_query_evals))
 }
 
 type TypeErasedUnivariateZerocheck<'a, F> = Box<dyn UnivariateZerocheckProver<'a, F> + 'a>;
@@ -520,14 +674,16 @@ where
 }
 
 #[instrument(skip_all, level = "debug")]
-fn make_unmasked_flush_witnesses<'a, U, Tower>(
+fn make_unmasked_flush_witnesses<'a, U, Tower, Backend>(
 	oracles: &MultilinearOracleSet<FExt<Tower>>,
 	witness: &mut MultilinearExtensionIndex<'a, U, FExt<Tower>>,
 	flush_oracle_ids: &[OracleId],
+	backend: &Backend,
 ) -> Result<(), Error>
 where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
+	Backend: ComputationBackend,
 {
 	// The function is on the critical path, parallelize.
 	let flush_witnesses: Result<Vec<MultilinearWitness<'a, _>>, Error> = flush_oracle_ids
@@ -547,22 +703,20 @@ where
 				<< lincom
 					.n_vars()
 					.saturating_sub(<PackedType<U, FExt<Tower>>>::LOG_WIDTH);
-			let data = (0..packed_len)
-				.into_par_iter()
-				.map(|i| {
-					<PackedType<U, FExt<Tower>>>::from_fn(|j| {
-						let index = i << <PackedType<U, FExt<Tower>>>::LOG_WIDTH | j;
-						polys.iter().zip(lincom.coefficients()).fold(
-							lincom.offset(),
-							|sum, (poly, coeff)| {
-								sum + poly
-									.evaluate_on_hypercube_and_scale(index, coeff)
-									.unwrap_or(<FExt<Tower>>::ZERO)
-							},
-						)
-					})
-				})
-				.collect::<Vec<_>>();
+			let mut data = backend.zeroed_hal_slice(packed_len);
+			data.par_iter_mut().enumerate().for_each(|(i, packed)| {
+				*packed = <PackedType<U, FExt<Tower>>>::from_fn(|j| {
+					let index = i << <PackedType<U, FExt<Tower>>>::LOG_WIDTH | j;
+					polys.iter().zip(lincom.coefficients()).fold(
+						lincom.offset(),
+						|sum, (poly, coeff)| {
+							sum + poly
+								.evaluate_on_hypercube_and_scale(index, coeff)
+								.unwrap_or(<FExt<Tower>>::ZERO)
+						},
+					)
+				});
+			});
 			let lincom_poly = MultilinearExtension::new(lincom.n_vars(), data)
 				.expect("data is constructed with the correct length with respect to n_vars");
 
@@ -576,15 +730,17 @@ where
 
 #[allow(clippy::type_complexity)]
 #[instrument(skip_all, level = "debug")]
-fn make_fast_masked_flush_witnesses<'a, U, Tower>(
+fn make_fast_masked_flush_witnesses<'a, U, Tower, Backend>(
 	oracles: &MultilinearOracleSet<FExt<Tower>>,
 	witness: &MultilinearExtensionIndex<'a, U, FExt<Tower>>,
 	flush_oracles: &[OracleId],
 	flush_selectors: Option<&[OracleId]>,
+	backend: &Backend,
 ) -> Result<Vec<MultilinearWitness<'a, PackedType<U, FFastExt<Tower>>>>, Error>
 where
 	U: ProverTowerUnderlier<Tower>,
 	Tower: ProverTowerFamily,
+	Backend: ComputationBackend,
 	PackedType<U, Tower::B128>: PackedTransformationFactory<PackedType<U, Tower::FastB128>>,
 {
 	let to_fast = Tower::packed_transformation_to_fast();
@@ -600,7 +756,8 @@ where
 			let width = 1 << log_width;
 
 			let packed_len = 1 << n_vars.saturating_sub(log_width);
-			let mut fast_ext_result = vec![PackedType::<U, FFastExt<Tower>>::one(); packed_len];
+			let mut fast_ext_result =
+				backend.filled_hal_slice(packed_len, PackedType::<U, FFastExt<Tower>>::one());
 
 			let poly = witness.get_multilin_poly(flush_oracle_id)?;
 			let selector = flush_selectors
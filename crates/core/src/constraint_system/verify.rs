@@ -2,7 +2,7 @@
 
 use std::{cmp::Reverse, iter};
 
-use binius_field::{BinaryField, PackedField, TowerField};
+use binius_field::{BinaryField, Field, PackedField, TowerField};
 use binius_hash::PseudoCompressionFunction;
 use binius_math::{ArithExpr, CompositionPoly, EvaluationOrder};
 use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
@@ -19,11 +19,11 @@ use crate::{
 	composition::IndexComposition,
 	constraint_system::{
 		channel::{Flush, FlushDirection},
-		common::{FDomain, FEncode, FExt},
+		common::{FDomain, FEncode, FExt, FExtendedDomain, UnivariateSkipRounds},
 	},
 	fiat_shamir::{CanSample, Challenger},
 	merkle_tree::BinaryMerkleTreeScheme,
-	oracle::{MultilinearOracleSet, OracleId},
+	oracle::{MultilinearOracleSet, OracleEvalQuery, OracleId},
 	piop,
 	polynomial::MultivariatePoly,
 	protocols::{
@@ -39,12 +39,14 @@ use crate::{
 	},
 	ring_switch,
 	tower::{PackedTop, TowerFamily, TowerUnderlier},
-	transcript::VerifierTranscript,
+	transcript::{labels, ScalarBufMut, TranscriptWriter, VerifierTranscript},
 	transparent::eq_ind::EqIndPartialEval,
 };
 
 /// Verifies a proof against a constraint system.
-#[instrument("constraint_system::verify", skip_all, level = "debug")]
+///
+/// The verifier runs with the default [`VerifyOptions`]; see [`verify_with_options`] to override
+/// them.
 pub fn verify<U, Tower, Hash, Compress, Challenger_>(
 	constraint_system: &ConstraintSystem<FExt<Tower>>,
 	log_inv_rate: usize,
@@ -60,6 +62,65 @@ where
 	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
 	Challenger_: Challenger + Default,
 {
+	verify_with_options::<U, Tower, Hash, Compress, Challenger_>(
+		constraint_system,
+		log_inv_rate,
+		security_bits,
+		boundaries,
+		proof,
+		VerifyOptions::default(),
+	)
+}
+
+/// Parameters of [`verify_with_options`], mirroring
+/// [`ProveOptions`](super::ProveOptions).
+#[derive(Debug, Clone, Copy)]
+pub struct VerifyOptions<'a, F: Field> {
+	/// Number of zerocheck rounds skipped with the univariate skip technique.
+	///
+	/// Must match the value the proof was generated with, otherwise verification fails with
+	/// [`VerificationError::UnivariateSkipRoundsMismatch`].
+	pub univariate_skip_rounds: UnivariateSkipRounds,
+	/// Oracles opened at points chosen by the caller, as given to the prover.
+	pub eval_queries: &'a [OracleEvalQuery<F>],
+	/// Claimed evaluations of the queried oracles, in query order.
+	pub evals: &'a [F],
+}
+
+impl<F: Field> Default for VerifyOptions<'_, F> {
+	fn default() -> Self {
+		Self {
+			univariate_skip_rounds: UnivariateSkipRounds::default(),
+			eval_queries: &[],
+			evals: &[],
+		}
+	}
+}
+
+/// Verifies a proof against a constraint system, using the given verifier options.
+#[instrument("constraint_system::verify", skip_all, level = "debug")]
+pub fn verify_with_options<U, Tower, Hash, Compress, Challenger_>(
+	constraint_system: &ConstraintSystem<FExt<Tower>>,
+	log_inv_rate: usize,
+	security_bits: usize,
+	boundaries: &[Boundary<FExt<Tower>>],
+	proof: Proof,
+	options: VerifyOptions<FExt<Tower>>,
+) -> Result<(), Error>
+where
+	U: TowerUnderlier<Tower>,
+	Tower: TowerFamily,
+	Tower::B128: PackedTop<Tower>,
+	Hash: Digest + BlockSizeUser,
+	Compress: PseudoCompressionFunction<Output<Hash>, 2> + Default + Sync,
+	Challenger_: Challenger + Default,
+{
+	let VerifyOptions {
+		univariate_skip_rounds,
+		eval_queries,
+		evals,
+	} = options;
+
 	let ConstraintSystem {
 		mut oracles,
 		mut table_constraints,
@@ -72,10 +133,14 @@ where
 	// Stable sort constraint sets in descending order by number of variables.
 	table_constraints.sort_by_key(|constraint_set| Reverse(constraint_set.n_vars));
 
+	let eval_query_claims = oracles.eval_claims(eval_queries, evals)?;
+
 	let Proof { transcript } = proof;
 
 	let mut transcript = VerifierTranscript::<Challenger_>::new(transcript);
+	transcript.domain_separator(labels::CONSTRAINT_SYSTEM)?;
 	transcript.observe().write_slice(boundaries);
+	observe_eval_queries(&mut transcript.observe(), eval_queries, evals);
 
 	let merkle_scheme = BinaryMerkleTreeScheme::<_, Hash, _>::new(Compress::default());
 	let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
@@ -208,9 +273,16 @@ where
 		.into_iter()
 		.unzip::<_, _, Vec<_>, Vec<_>>();
 
-	let (max_n_vars, skip_rounds) =
-		max_n_vars_and_skip_rounds(&zerocheck_claims, <FDomain<Tower>>::N_BITS);
+	let (max_n_vars, skip_rounds, _) =
+		zerocheck_skip_rounds::<Tower, _, _>(&zerocheck_claims, univariate_skip_rounds);
 
+	let proof_skip_rounds = transcript.message().read::<usize>()?;
+	if proof_skip_rounds != skip_rounds {
+		bail!(VerificationError::UnivariateSkipRoundsMismatch {
+			expected: skip_rounds,
+			got: proof_skip_rounds,
+		});
+	}
 	let zerocheck_challenges = transcript.sample_vec(max_n_vars - skip_rounds);
 
 	let univariate_cnt = zerocheck_claims
@@ -275,7 +347,8 @@ where
 		[non_zero_prodcheck_eval_claims, flush_eval_claims]
 			.concat()
 			.into_iter()
-			.chain(zerocheck_eval_claims),
+			.chain(zerocheck_eval_claims)
+			.chain(eval_query_claims),
 		&mut transcript,
 	)?;
 
@@ -308,6 +381,47 @@ where
 	Ok(())
 }
 
+/// Binds the evaluation queries and their claimed evaluations to the transcript.
+pub fn observe_eval_queries<F: TowerField>(
+	writer: &mut TranscriptWriter<impl ScalarBufMut>,
+	eval_queries: &[OracleEvalQuery<F>],
+	evals: &[F],
+) {
+	for query in eval_queries {
+		writer.write(&query.id);
+		writer.write_scalar_slice(&query.eval_point);
+	}
+	writer.write_scalar_slice(evals);
+}
+
+/// Resolves the number of univariate skip rounds of the batched zerocheck.
+///
+/// Returns the maximum number of variables among the claims, the number of skipped rounds, and
+/// whether the skipped rounds exceed what [`FDomain`] supports, in which case the prover evaluates
+/// the univariate round over [`FExtendedDomain`].
+pub fn zerocheck_skip_rounds<Tower, F, Composition>(
+	zerocheck_claims: &[ZerocheckClaim<F, Composition>],
+	univariate_skip_rounds: UnivariateSkipRounds,
+) -> (usize, usize, bool)
+where
+	Tower: TowerFamily,
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+{
+	let (max_n_vars, domain_max_skip_rounds) =
+		max_n_vars_and_skip_rounds(zerocheck_claims, <FDomain<Tower>>::N_BITS);
+
+	match univariate_skip_rounds {
+		UnivariateSkipRounds::Standard => (max_n_vars, domain_max_skip_rounds, false),
+		UnivariateSkipRounds::AtMost(max_skip_rounds) => {
+			let (_, extended_domain_max_skip_rounds) =
+				max_n_vars_and_skip_rounds(zerocheck_claims, <FExtendedDomain<Tower>>::N_BITS);
+			let skip_rounds = max_skip_rounds.min(extended_domain_max_skip_rounds);
+			(max_n_vars, skip_rounds, skip_rounds > domain_max_skip_rounds)
+		}
+	}
+}
+
 pub fn max_n_vars_and_skip_rounds<F, Composition>(
 	zerocheck_claims: &[ZerocheckClaim<F, Composition>],
 	domain_bits: usize,
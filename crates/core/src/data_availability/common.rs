@@ -0,0 +1,122 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::BinaryField;
+use binius_hal::ComputationBackend;
+use binius_ntt::NTTOptions;
+use binius_utils::bail;
+use getset::Getters;
+
+use super::error::Error;
+use crate::reed_solomon::reed_solomon::ReedSolomonCode;
+
+/// Parameters of the two-dimensional Reed–Solomon extension of a data blob.
+///
+/// The blob is arranged in row-major order as a matrix with `1 << log_rows` rows of
+/// `1 << log_cols` elements. Every row is encoded with the row code, and then every column of the
+/// result is encoded with the column code. Both codes have the same rate, so the extended matrix
+/// has `1 << log_inv_rate` times as many rows and columns as the blob matrix.
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct DAParams<F: BinaryField> {
+	/// The code that every row of the extended matrix belongs to.
+	row_code: ReedSolomonCode<F>,
+	/// The code that every column of the extended matrix belongs to.
+	column_code: ReedSolomonCode<F>,
+}
+
+impl<F: BinaryField> DAParams<F> {
+	pub fn new(
+		log_rows: usize,
+		log_cols: usize,
+		log_inv_rate: usize,
+		ntt_options: &NTTOptions,
+	) -> Result<Self, Error> {
+		Ok(Self {
+			row_code: ReedSolomonCode::new(log_cols, log_inv_rate, ntt_options)?,
+			column_code: ReedSolomonCode::new(log_rows, log_inv_rate, ntt_options)?,
+		})
+	}
+
+	/// Base-2 logarithm of the number of rows of the blob matrix.
+	pub const fn log_rows(&self) -> usize {
+		self.column_code.log_dim()
+	}
+
+	/// Base-2 logarithm of the number of columns of the blob matrix.
+	pub const fn log_cols(&self) -> usize {
+		self.row_code.log_dim()
+	}
+
+	/// Base-2 logarithm of the number of rows of the extended matrix.
+	pub const fn log_extended_rows(&self) -> usize {
+		self.column_code.log_len()
+	}
+
+	/// Base-2 logarithm of the number of columns of the extended matrix.
+	pub const fn log_extended_cols(&self) -> usize {
+		self.row_code.log_len()
+	}
+
+	/// The number of elements in the blob.
+	pub const fn blob_len(&self) -> usize {
+		1 << (self.log_rows() + self.log_cols())
+	}
+
+	/// The number of elements in the extended matrix.
+	pub const fn extended_len(&self) -> usize {
+		1 << (self.log_extended_rows() + self.log_extended_cols())
+	}
+}
+
+/// The commitment to an extended blob.
+///
+/// Each root commits to the cells of one row or column of the extended matrix, one cell per leaf.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataAvailabilityHeader<Digest> {
+	/// The Merkle roots of the rows of the extended matrix, from top to bottom.
+	pub row_roots: Vec<Digest>,
+	/// The Merkle roots of the columns of the extended matrix, from left to right.
+	pub column_roots: Vec<Digest>,
+}
+
+/// The line of the extended matrix whose Merkle tree a sample is opened against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Axis {
+	Row,
+	Column,
+}
+
+/// Encodes a batch of interleaved messages at the beginning of `code` in-place.
+///
+/// The encoding is multithreaded unless the code was created with single-threaded
+/// [`NTTOptions`].
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectCodewordLength`] if `code` does not have length
+///   `rs_code.len() << log_batch_size`.
+pub(super) fn encode_batch_inplace<F, Backend>(
+	rs_code: &ReedSolomonCode<F>,
+	code: &mut [F],
+	log_batch_size: usize,
+	backend: &Backend,
+) -> Result<(), Error>
+where
+	F: BinaryField,
+	Backend: ComputationBackend,
+{
+	let expected = rs_code.len() << log_batch_size;
+	if code.len() != expected {
+		bail!(Error::IncorrectCodewordLength { expected });
+	}
+
+	backend.reed_solomon_encode_batch(
+		rs_code.get_ntt(),
+		rs_code.log_dim(),
+		rs_code.log_inv_rate(),
+		log_batch_size,
+		rs_code.multithreaded(),
+		code,
+	)?;
+	Ok(())
+}
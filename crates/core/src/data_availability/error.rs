@@ -0,0 +1,27 @@
+// Copyright 2025 Irreducible Inc.
+
+use crate::{reed_solomon, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the blob must have exactly {expected} elements")]
+	IncorrectBlobLength { expected: usize },
+	#[error("the sample at row {row} and column {col} is outside of the extended matrix")]
+	SampleOutOfRange { row: usize, col: usize },
+	#[error("the header must contain one root per row and per column of the extended matrix")]
+	IncorrectHeaderShape,
+	#[error("the codeword buffer must have exactly {expected} elements")]
+	IncorrectCodewordLength { expected: usize },
+	#[error("the samples are not sufficient to reconstruct the blob")]
+	InsufficientSamples,
+	#[error("Reed-Solomon decoding error: {0}")]
+	DecodingError(#[from] reed_solomon::error::Error),
+	#[error("Reed-Solomon encoding error: {0}")]
+	EncodeError(#[from] binius_ntt::Error),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
+	#[error("vector commit error: {0}")]
+	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] transcript::Error),
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Data availability sampling over two-dimensional Reed–Solomon extensions.
+//!
+//! A data blob is arranged as a matrix whose rows and columns are extended with
+//! [`ReedSolomonCode`](crate::reed_solomon::reed_solomon::ReedSolomonCode), so that every row and
+//! every column of the extended matrix is a codeword. The prover commits to every row and every
+//! column with a Merkle tree, and the roots form the [`DataAvailabilityHeader`]. Light clients
+//! sample random cells of the extended matrix and check the openings against the header. Any set
+//! of samples that allows decoding the rows and columns in turn is sufficient to reconstruct the
+//! original blob.
+
+mod common;
+mod error;
+mod prove;
+mod reconstruct;
+#[cfg(test)]
+mod tests;
+mod verify;
+
+pub use common::{Axis, DAParams, DataAvailabilityHeader};
+pub use error::*;
+pub use prove::*;
+pub use reconstruct::*;
+pub use verify::*;
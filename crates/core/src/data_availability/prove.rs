@@ -0,0 +1,187 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{
+	common::{encode_batch_inplace, Axis, DAParams, DataAvailabilityHeader},
+	error::Error,
+};
+use crate::{
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	transcript::{ScalarBufMut, TranscriptWriter},
+};
+
+/// The prover's data for an extended and committed blob.
+#[derive(Debug)]
+pub struct CommittedBlob<F, Committed> {
+	/// The extended matrix in row-major order.
+	pub extended: Vec<F>,
+	/// The Merkle trees of the rows of the extended matrix.
+	pub row_trees: Vec<Committed>,
+	/// The Merkle trees of the columns of the extended matrix.
+	pub column_trees: Vec<Committed>,
+}
+
+#[derive(Debug)]
+pub struct CommitOutput<F, Digest, Committed> {
+	pub header: DataAvailabilityHeader<Digest>,
+	pub committed: CommittedBlob<F, Committed>,
+}
+
+/// Extends a blob to the two-dimensional Reed–Solomon encoding described by `params`.
+///
+/// Returns the extended matrix in row-major order.
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectBlobLength`] if `blob` does not have `params.blob_len()` elements.
+#[instrument(skip_all, level = "debug")]
+pub fn extend<F, Backend>(
+	params: &DAParams<F>,
+	blob: &[F],
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
+where
+	F: BinaryField,
+	Backend: ComputationBackend,
+{
+	if blob.len() != params.blob_len() {
+		bail!(Error::IncorrectBlobLength {
+			expected: params.blob_len()
+		});
+	}
+
+	// The rows of the blob are interleaved so that they are encoded as a single batch; symbol `k`
+	// of the codeword of row `r` ends up at index `k << log_rows | r`.
+	let log_rows = params.log_rows();
+	let mut rows = transpose(blob, params.log_cols());
+	rows.resize(params.row_code().len() << log_rows, F::ZERO);
+	encode_batch_inplace(params.row_code(), &mut rows, log_rows, backend)?;
+
+	// The encoded rows are the top rows of the extended matrix.
+	let mut extended = transpose(&rows, log_rows);
+	extended.resize(params.extended_len(), F::ZERO);
+
+	// In row-major order, the columns are the messages of a batch interleaved by row length.
+	encode_batch_inplace(params.column_code(), &mut extended, params.log_extended_cols(), backend)?;
+
+	Ok(extended)
+}
+
+/// Extends a blob and commits to every row and every column of the extended matrix.
+#[allow(clippy::type_complexity)]
+#[instrument(skip_all, level = "debug")]
+pub fn commit<F, MTProver, Backend>(
+	params: &DAParams<F>,
+	merkle_prover: &MTProver,
+	blob: &[F],
+	backend: &Backend,
+) -> Result<
+	CommitOutput<F, <MTProver::Scheme as MerkleTreeScheme<F>>::Digest, MTProver::Committed>,
+	Error,
+>
+where
+	F: TowerField,
+	MTProver: MerkleTreeProver<F>,
+	MTProver::Committed: Send,
+	<MTProver::Scheme as MerkleTreeScheme<F>>::Digest: Send,
+	Backend: ComputationBackend,
+{
+	let extended = extend(params, blob, backend)?;
+	let row_len = params.row_code().len();
+	let column_len = params.column_code().len();
+
+	let (row_roots, row_trees) = extended
+		.par_chunks_exact(row_len)
+		.map(|row| commit_line(merkle_prover, row))
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.unzip();
+
+	// The matrix is transposed once so that every column is contiguous.
+	let columns = transpose(&extended, params.log_extended_cols());
+	let (column_roots, column_trees) = columns
+		.par_chunks_exact(column_len)
+		.map(|column| commit_line(merkle_prover, column))
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.unzip();
+
+	Ok(CommitOutput {
+		header: DataAvailabilityHeader {
+			row_roots,
+			column_roots,
+		},
+		committed: CommittedBlob {
+			extended,
+			row_trees,
+			column_trees,
+		},
+	})
+}
+
+/// Writes the value of a cell of the extended matrix and its Merkle opening to `proof`.
+///
+/// The cell is opened against the tree of its row or of its column, depending on `axis`.
+pub fn prove_sample<F, MTProver, B>(
+	merkle_prover: &MTProver,
+	committed: &CommittedBlob<F, MTProver::Committed>,
+	axis: Axis,
+	row: usize,
+	col: usize,
+	proof: &mut TranscriptWriter<B>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	MTProver: MerkleTreeProver<F>,
+	B: ScalarBufMut,
+{
+	let n_rows = committed.row_trees.len();
+	let n_cols = committed.column_trees.len();
+	if row >= n_rows || col >= n_cols {
+		bail!(Error::SampleOutOfRange { row, col });
+	}
+
+	let (tree, index) = match axis {
+		Axis::Row => (&committed.row_trees[row], col),
+		Axis::Column => (&committed.column_trees[col], row),
+	};
+
+	proof.write_scalar(committed.extended[row * n_cols + col]);
+	merkle_prover
+		.prove_opening(tree, 0, index, proof)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(())
+}
+
+#[allow(clippy::type_complexity)]
+fn commit_line<F, MTProver>(
+	merkle_prover: &MTProver,
+	line: &[F],
+) -> Result<(<MTProver::Scheme as MerkleTreeScheme<F>>::Digest, MTProver::Committed), Error>
+where
+	F: TowerField,
+	MTProver: MerkleTreeProver<F>,
+{
+	let (commitment, committed) = merkle_prover
+		.commit(line, 1)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+	Ok((commitment.root, committed))
+}
+
+/// Transposes a matrix in row-major order with `1 << log_cols` columns.
+///
+/// Returns the transposed matrix in row-major order.
+fn transpose<F: Copy + Send + Sync>(matrix: &[F], log_cols: usize) -> Vec<F> {
+	let log_rows = (matrix.len() >> log_cols).ilog2() as usize;
+	let row_mask = (1 << log_rows) - 1;
+	(0..matrix.len())
+		.into_par_iter()
+		.map(|index| matrix[(index & row_mask) << log_cols | index >> log_rows])
+		.collect()
+}
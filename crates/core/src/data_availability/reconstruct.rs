@@ -0,0 +1,121 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::BinaryField;
+use binius_hal::ComputationBackend;
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{
+	common::{encode_batch_inplace, Axis, DAParams},
+	error::Error,
+};
+use crate::reed_solomon::reed_solomon::ReedSolomonCode;
+
+/// Reconstructs a blob from samples of its extended matrix.
+///
+/// `samples` yields the row, the column and the value of known cells of the extended matrix,
+/// which should have been checked against the header with [`verify_sample`](super::verify_sample).
+/// The missing cells are recovered by repeatedly erasure-decoding every row and column which has
+/// at least as many known cells as the code dimension. Reconstruction thus succeeds, for
+/// instance, from any `1 << log_cols` known cells in each of any `1 << log_rows` rows.
+///
+/// ## Throws
+///
+/// * [`Error::SampleOutOfRange`] if a sample is outside of the extended matrix.
+/// * [`Error::InsufficientSamples`] if the missing cells cannot be recovered.
+/// * [`Error::DecodingError`] if the samples are not consistent with any extended blob.
+#[instrument(skip_all, level = "debug")]
+pub fn reconstruct<F, Backend>(
+	params: &DAParams<F>,
+	samples: impl IntoIterator<Item = (usize, usize, F)>,
+	backend: &Backend,
+) -> Result<Vec<F>, Error>
+where
+	F: BinaryField,
+	Backend: ComputationBackend,
+{
+	let n_rows = params.column_code().len();
+	let n_cols = params.row_code().len();
+
+	let mut cells = vec![None; params.extended_len()];
+	for (row, col, value) in samples {
+		if row >= n_rows || col >= n_cols {
+			bail!(Error::SampleOutOfRange { row, col });
+		}
+		cells[row * n_cols + col] = Some(value);
+	}
+
+	let mut n_missing = cells.iter().filter(|cell| cell.is_none()).count();
+	while n_missing > 0 {
+		let mut progress = false;
+		for axis in [Axis::Row, Axis::Column] {
+			let (rs_code, n_lines, line_stride, cell_stride) = match axis {
+				Axis::Row => (params.row_code(), n_rows, n_cols, 1),
+				Axis::Column => (params.column_code(), n_cols, 1, n_cols),
+			};
+
+			for line in 0..n_lines {
+				let indices = (0..rs_code.len()).map(|i| line * line_stride + i * cell_stride);
+				let received = indices
+					.clone()
+					.map(|index| cells[index])
+					.collect::<Vec<_>>();
+				if let Some(codeword) = complete_line(rs_code, &received, backend)? {
+					n_missing -= received.iter().filter(|cell| cell.is_none()).count();
+					for (index, value) in indices.zip(codeword) {
+						cells[index] = Some(value);
+					}
+					progress = true;
+				}
+			}
+		}
+
+		if !progress {
+			bail!(Error::InsufficientSamples);
+		}
+	}
+
+	// Decoding the complete columns and then the rows of their messages also checks that the
+	// extended matrix is a valid encoding.
+	let log_rows = params.log_rows();
+	let mut row_messages = vec![F::ZERO; n_cols << log_rows];
+	for col in 0..n_cols {
+		let column = (0..n_rows)
+			.map(|row| cells[row * n_cols + col])
+			.collect::<Vec<_>>();
+		let message = params.column_code().decode_erasures(&column)?;
+		for (row, value) in message.into_iter().enumerate() {
+			row_messages[row * n_cols + col] = value;
+		}
+	}
+
+	let mut blob = Vec::with_capacity(params.blob_len());
+	for row in row_messages.chunks_exact(n_cols) {
+		let received = row.iter().copied().map(Some).collect::<Vec<_>>();
+		blob.extend(params.row_code().decode_erasures(&received)?);
+	}
+	Ok(blob)
+}
+
+/// Recovers the codeword of a row or column with erased cells.
+///
+/// Returns `None` if the line has no erasures or too many erasures to be decoded.
+fn complete_line<F, Backend>(
+	rs_code: &ReedSolomonCode<F>,
+	received: &[Option<F>],
+	backend: &Backend,
+) -> Result<Option<Vec<F>>, Error>
+where
+	F: BinaryField,
+	Backend: ComputationBackend,
+{
+	let n_known = received.iter().filter(|cell| cell.is_some()).count();
+	if n_known == received.len() || n_known < rs_code.dim() {
+		return Ok(None);
+	}
+
+	let mut codeword = rs_code.decode_erasures(received)?;
+	codeword.resize(rs_code.len(), F::ZERO);
+	encode_batch_inplace(rs_code, &mut codeword, 0, backend)?;
+	Ok(Some(codeword))
+}
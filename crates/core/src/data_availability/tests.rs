@@ -0,0 +1,247 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use assert_matches::assert_matches;
+use binius_field::{BinaryField32b, Field};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_ntt::{NTTOptions, ThreadingSettings};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+use super::*;
+use crate::{
+	fiat_shamir::HasherChallenger, merkle_tree::BinaryMerkleTreeProver, reed_solomon,
+	transcript::ProverTranscript,
+};
+
+type F = BinaryField32b;
+
+const LOG_ROWS: usize = 3;
+const LOG_COLS: usize = 4;
+const LOG_INV_RATE: usize = 1;
+
+fn setup(rng: &mut StdRng) -> (DAParams<F>, Vec<F>) {
+	let params = DAParams::new(LOG_ROWS, LOG_COLS, LOG_INV_RATE, &NTTOptions::default()).unwrap();
+	let blob = repeat_with(|| <F as Field>::random(&mut *rng))
+		.take(params.blob_len())
+		.collect();
+	(params, blob)
+}
+
+fn cells(extended: &[F], n_cols: usize) -> impl Iterator<Item = (usize, usize, F)> + '_ {
+	extended
+		.iter()
+		.enumerate()
+		.map(move |(i, &value)| (i / n_cols, i % n_cols, value))
+}
+
+#[test]
+fn test_extended_lines_are_codewords() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let backend = make_portable_backend();
+
+	let extended = extend(&params, &blob, &backend).unwrap();
+	assert_eq!(extended.len(), params.extended_len());
+
+	let n_rows = 1 << params.log_extended_rows();
+	let n_cols = 1 << params.log_extended_cols();
+	for row in extended.chunks_exact(n_cols) {
+		let received = row.iter().copied().map(Some).collect::<Vec<_>>();
+		params.row_code().decode_erasures(&received).unwrap();
+	}
+	for col in 0..n_cols {
+		let received = (0..n_rows)
+			.map(|row| Some(extended[row * n_cols + col]))
+			.collect::<Vec<_>>();
+		params.column_code().decode_erasures(&received).unwrap();
+	}
+}
+
+#[test]
+fn test_extend_incorrect_blob_length() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+
+	assert_matches!(
+		extend(&params, &blob[1..], &make_portable_backend()),
+		Err(Error::IncorrectBlobLength { .. })
+	);
+}
+
+#[test]
+fn test_extend_matches_line_by_line_encoding() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (_, blob) = setup(&mut rng);
+	let params = DAParams::<F>::new(
+		LOG_ROWS,
+		LOG_COLS,
+		LOG_INV_RATE,
+		&NTTOptions {
+			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
+		},
+	)
+	.unwrap();
+	assert!(!params.row_code().multithreaded());
+	let backend = make_portable_backend();
+
+	let extended = extend(&params, &blob, &backend).unwrap();
+
+	let row_len = params.row_code().len();
+	let column_len = params.column_code().len();
+	let mut rows = Vec::with_capacity(row_len << LOG_ROWS);
+	for blob_row in blob.chunks_exact(1 << LOG_COLS) {
+		let mut row = blob_row.to_vec();
+		row.resize(row_len, F::ZERO);
+		common::encode_batch_inplace(params.row_code(), &mut row, 0, &backend).unwrap();
+		rows.extend(row);
+	}
+	for col in 0..row_len {
+		let mut column = (0..1 << LOG_ROWS)
+			.map(|row| rows[row * row_len + col])
+			.collect::<Vec<_>>();
+		column.resize(column_len, F::ZERO);
+		common::encode_batch_inplace(params.column_code(), &mut column, 0, &backend).unwrap();
+		for (row, &value) in column.iter().enumerate() {
+			assert_eq!(extended[row * row_len + col], value);
+		}
+	}
+}
+
+#[test]
+fn test_encode_incorrect_codeword_length() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, _) = setup(&mut rng);
+	let mut codeword = vec![F::ZERO; params.row_code().len() + 1];
+
+	assert_matches!(
+		common::encode_batch_inplace(params.row_code(), &mut codeword, 0, &make_portable_backend()),
+		Err(Error::IncorrectCodewordLength { .. })
+	);
+}
+
+#[test]
+fn test_prove_verify_samples() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let CommitOutput { header, committed } =
+		commit(&params, &merkle_prover, &blob, &make_portable_backend()).unwrap();
+
+	let n_cols = 1 << params.log_extended_cols();
+	for (row, col, value) in cells(&committed.extended, n_cols) {
+		for axis in [Axis::Row, Axis::Column] {
+			let mut prover_proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+			prove_sample(&merkle_prover, &committed, axis, row, col, &mut prover_proof.message())
+				.unwrap();
+
+			let mut verifier_proof = prover_proof.into_verifier();
+			let sample = verify_sample(
+				&params,
+				merkle_prover.scheme(),
+				&header,
+				axis,
+				row,
+				col,
+				&mut verifier_proof.message(),
+			)
+			.unwrap();
+			assert_eq!(sample, value);
+			verifier_proof.finalize().unwrap();
+		}
+	}
+}
+
+#[test]
+fn test_verify_sample_at_wrong_cell() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let CommitOutput { header, committed } =
+		commit(&params, &merkle_prover, &blob, &make_portable_backend()).unwrap();
+
+	let mut prover_proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	prove_sample(&merkle_prover, &committed, Axis::Row, 1, 2, &mut prover_proof.message()).unwrap();
+
+	let mut verifier_proof = prover_proof.into_verifier();
+	assert_matches!(
+		verify_sample(
+			&params,
+			merkle_prover.scheme(),
+			&header,
+			Axis::Row,
+			1,
+			3,
+			&mut verifier_proof.message(),
+		),
+		Err(Error::VectorCommit(_))
+	);
+}
+
+#[test]
+fn test_reconstruct_from_random_samples() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let backend = make_portable_backend();
+	let extended = extend(&params, &blob, &backend).unwrap();
+
+	// Erase a quarter of the cells at random.
+	let n_cols = 1 << params.log_extended_cols();
+	let mut samples = cells(&extended, n_cols).map(Some).collect::<Vec<_>>();
+	for index in sample(&mut rng, extended.len(), extended.len() / 4) {
+		samples[index] = None;
+	}
+
+	let reconstructed = reconstruct(&params, samples.into_iter().flatten(), &backend).unwrap();
+	assert_eq!(reconstructed, blob);
+}
+
+#[test]
+fn test_reconstruct_from_parity_quadrant() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let backend = make_portable_backend();
+	let extended = extend(&params, &blob, &backend).unwrap();
+
+	let n_cols = 1 << params.log_extended_cols();
+	let samples = cells(&extended, n_cols)
+		.filter(|&(row, col, _)| row >= 1 << LOG_ROWS && col >= 1 << LOG_COLS);
+
+	let reconstructed = reconstruct(&params, samples, &backend).unwrap();
+	assert_eq!(reconstructed, blob);
+}
+
+#[test]
+fn test_reconstruct_insufficient_samples() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let backend = make_portable_backend();
+	let extended = extend(&params, &blob, &backend).unwrap();
+
+	// Without one row of the parity quadrant, the columns remain one cell short.
+	let n_cols = 1 << params.log_extended_cols();
+	let samples = cells(&extended, n_cols)
+		.filter(|&(row, col, _)| row > 1 << LOG_ROWS && col >= 1 << LOG_COLS);
+
+	assert_matches!(reconstruct(&params, samples, &backend), Err(Error::InsufficientSamples));
+}
+
+#[test]
+fn test_reconstruct_inconsistent_samples() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let (params, blob) = setup(&mut rng);
+	let backend = make_portable_backend();
+	let mut extended = extend(&params, &blob, &backend).unwrap();
+	extended[5] += F::ONE;
+
+	let n_cols = 1 << params.log_extended_cols();
+	assert_matches!(
+		reconstruct(&params, cells(&extended, n_cols), &backend),
+		Err(Error::DecodingError(reed_solomon::error::Error::DecodingFailure))
+	);
+}
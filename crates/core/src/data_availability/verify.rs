@@ -0,0 +1,60 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::slice;
+
+use binius_field::TowerField;
+use binius_utils::bail;
+
+use super::{
+	common::{Axis, DAParams, DataAvailabilityHeader},
+	error::Error,
+};
+use crate::{
+	merkle_tree::MerkleTreeScheme,
+	transcript::{ScalarBuf, TranscriptReader},
+};
+
+/// Reads a sample of the extended matrix from `proof` and verifies its Merkle opening.
+///
+/// This is the verifier of [`prove_sample`](super::prove_sample). Returns the value of the cell
+/// at `row` and `col`.
+///
+/// ## Throws
+///
+/// * [`Error::IncorrectHeaderShape`] if the header does not match the dimensions in `params`.
+/// * [`Error::SampleOutOfRange`] if the cell is outside of the extended matrix.
+/// * [`Error::VectorCommit`] if the opening is invalid.
+pub fn verify_sample<F, MTScheme, B>(
+	params: &DAParams<F>,
+	vcs: &MTScheme,
+	header: &DataAvailabilityHeader<MTScheme::Digest>,
+	axis: Axis,
+	row: usize,
+	col: usize,
+	proof: &mut TranscriptReader<B>,
+) -> Result<F, Error>
+where
+	F: TowerField,
+	MTScheme: MerkleTreeScheme<F>,
+	B: ScalarBuf,
+{
+	let log_n_rows = params.log_extended_rows();
+	let log_n_cols = params.log_extended_cols();
+	if header.row_roots.len() != 1 << log_n_rows || header.column_roots.len() != 1 << log_n_cols {
+		bail!(Error::IncorrectHeaderShape);
+	}
+	if row >= header.row_roots.len() || col >= header.column_roots.len() {
+		bail!(Error::SampleOutOfRange { row, col });
+	}
+
+	let (root, index, tree_depth) = match axis {
+		Axis::Row => (&header.row_roots[row], col, log_n_cols),
+		Axis::Column => (&header.column_roots[col], row, log_n_rows),
+	};
+
+	let value = proof.read_scalar::<F>()?;
+	vcs.verify_opening(index, slice::from_ref(&value), 0, tree_depth, slice::from_ref(root), proof)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(value)
+}
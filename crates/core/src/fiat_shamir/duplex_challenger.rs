@@ -0,0 +1,596 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{
+	AESTowerField32b, AESTowerField8b, PackedAESBinaryField64x8b, PackedAESBinaryField8x32b,
+	TowerField,
+};
+use binius_hash::{permutation::CryptographicPermutation, Groestl256Core, Vision32bPermutation};
+use binius_utils::SerializationMode;
+use bytemuck::{bytes_of, bytes_of_mut, pod_read_unaligned, Pod};
+use bytes::{buf::UninitSlice, Buf, BufMut};
+
+use super::Challenger;
+
+const PADDING_START: u8 = 0x01;
+const PADDING_END: u8 = 0x80;
+
+/// Size in bytes of the canonical encoding of the largest tower field.
+const MAX_SCALAR_BYTES: usize = size_of::<u128>();
+
+/// Duplex-sponge challenger over the Vision-32b permutation.
+///
+/// The state is 24 `AESTowerField32b` elements, of which the first 16 form the rate.
+pub type Vision32bDuplexChallenger =
+	DuplexChallenger<AESTowerField32b, [PackedAESBinaryField8x32b; 3], Vision32bPermutation, 16>;
+
+/// Duplex-sponge challenger over the Grøstl-256 P permutation.
+///
+/// The state is 64 `AESTowerField8b` elements, of which the first 32 form the rate.
+pub type Groestl256DuplexChallenger =
+	DuplexChallenger<AESTowerField8b, PackedAESBinaryField64x8b, Groestl256Core, 32>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mode {
+	Observing,
+	Sampling,
+}
+
+/// Challenger that runs a cryptographic permutation in duplex-sponge mode.
+///
+/// The sponge state has type `T` and is viewed as an array of elements of the field `F` that the
+/// permutation is defined over. The first `RATE` elements of the state form the rate and the
+/// remaining elements form the capacity. Observed data overwrites the rate in place. This is
+/// cheaper than [`HasherChallenger`], because each permutation call absorbs or squeezes a full
+/// rate.
+///
+/// Field elements observed with [`Challenger::observe_scalars`] are absorbed natively. Each
+/// element is split into its coordinates over the canonical tower field isomorphic to `F`, where
+/// elements of smaller fields are embedded into it. Every coordinate is mapped into `F` with the
+/// field isomorphism and written to its own state element, so an arithmetized verifier absorbs
+/// field elements with field operations only. The coordinates of each element are preceded by a
+/// state element holding the tower level of its field, so that elements of different fields with
+/// the same embedding are distinguished. Observed bytes are absorbed by reading each group of
+/// `size_of::<F>()` bytes as the canonical encoding of a state element.
+///
+/// Squeezed state elements are mapped back to their canonical encoding, so sampled bytes are
+/// likewise obtained from state elements by the inverse isomorphism. Field elements sampled with
+/// [`Challenger::sample_scalar`] are built from whole state elements: each coordinate is read from
+/// the next unread state element, skipping any partially sampled one.
+///
+/// The state is permuted whenever the rate is full and when switching from observing to
+/// sampling. On that switch the observed input is padded with the `10*1` padding rule so that
+/// transcripts of different lengths are distinguished. When switching from sampling back to
+/// observing, the number of bytes sampled is absorbed as a little-endian `u64` before the
+/// observed input, which binds the number of sampled challenges to the transcript.
+///
+/// [`HasherChallenger`]: super::HasherChallenger
+#[derive(Debug, Clone)]
+pub struct DuplexChallenger<F, T, Perm, const RATE: usize> {
+	permutation: Perm,
+	state: T,
+	mode: Mode,
+	/// Byte offset into the rate.
+	///
+	/// Invariant: `index` is always strictly less than the rate size while observing and at most
+	/// the rate size while sampling.
+	index: usize,
+	/// Byte offset into the rate up to which observed bytes have been mapped to state elements.
+	///
+	/// Invariant: `converted` is a multiple of `size_of::<F>()` and at most `index`.
+	converted: usize,
+	/// The number of bytes sampled since the last switch to sampling mode.
+	n_sampled: usize,
+	_marker: PhantomData<F>,
+}
+
+impl<F, T, Perm, const RATE: usize> DuplexChallenger<F, T, Perm, RATE>
+where
+	F: TowerField + Pod,
+	T: Pod,
+	Perm: CryptographicPermutation<T>,
+{
+	const RATE_BYTES: usize = RATE * size_of::<F>();
+
+	/// Constructs a new challenger with an all-zero initial state.
+	pub fn new(permutation: Perm) -> Self {
+		const {
+			assert!(RATE > 0, "the rate must be non-zero");
+			assert!(Self::RATE_BYTES < size_of::<T>(), "the capacity must be non-zero");
+			assert!(
+				size_of::<T>() % size_of::<F>() == 0,
+				"the state must be an array of field elements"
+			);
+			assert!(size_of::<F>() <= MAX_SCALAR_BYTES, "the state field is too large");
+		};
+
+		Self {
+			permutation,
+			state: T::zeroed(),
+			mode: Mode::Observing,
+			index: 0,
+			converted: 0,
+			n_sampled: 0,
+			_marker: PhantomData,
+		}
+	}
+
+	fn rate(&self) -> &[u8] {
+		&bytes_of(&self.state)[..Self::RATE_BYTES]
+	}
+
+	fn rate_mut(&mut self) -> &mut [u8] {
+		&mut bytes_of_mut(&mut self.state)[..Self::RATE_BYTES]
+	}
+
+	/// Maps the canonical encodings in the byte range `start..end` of the rate to state elements.
+	fn decode_rate(&mut self, start: usize, end: usize) {
+		for lane in self.rate_mut()[start..end].chunks_exact_mut(size_of::<F>()) {
+			let elem = F::deserialize(&*lane, SerializationMode::CanonicalTower)
+				.expect("lane has the size of a state element");
+			lane.copy_from_slice(bytes_of(&elem));
+		}
+	}
+
+	/// Maps the state elements in the rate to their canonical encodings.
+	fn encode_rate(&mut self) {
+		for lane in self.rate_mut().chunks_exact_mut(size_of::<F>()) {
+			let elem = pod_read_unaligned::<F>(lane);
+			elem.serialize(lane, SerializationMode::CanonicalTower)
+				.expect("lane has the size of a state element");
+		}
+	}
+
+	/// Maps the observed bytes that are not yet converted to state elements.
+	///
+	/// `index` must be a multiple of `size_of::<F>()`.
+	fn convert_observed(&mut self) {
+		self.decode_rate(self.converted, self.index);
+		self.converted = self.index;
+	}
+
+	fn permute(&mut self) {
+		self.permutation.permute_mut(&mut self.state);
+		self.index = 0;
+		self.converted = 0;
+	}
+
+	fn pad_and_permute(&mut self) {
+		let index = self.index;
+		let rate = self.rate_mut();
+		rate[index..].fill(0);
+		rate[index] |= PADDING_START;
+		rate[Self::RATE_BYTES - 1] |= PADDING_END;
+		self.index = Self::RATE_BYTES;
+		self.convert_observed();
+		self.permute();
+	}
+
+	/// Squeezes the next rate of output while sampling.
+	fn squeeze(&mut self) {
+		self.decode_rate(0, Self::RATE_BYTES);
+		self.permute();
+		self.encode_rate();
+	}
+
+	fn start_sampling(&mut self) {
+		if self.mode == Mode::Observing {
+			self.pad_and_permute();
+			self.encode_rate();
+			self.mode = Mode::Sampling;
+			self.n_sampled = 0;
+		}
+	}
+
+	fn start_observing(&mut self) {
+		if self.mode == Mode::Sampling {
+			self.decode_rate(0, Self::RATE_BYTES);
+			self.mode = Mode::Observing;
+			self.index = 0;
+			self.converted = 0;
+			let n_sampled = self.n_sampled as u64;
+			DuplexObserver::wrap(self).put_u64_le(n_sampled);
+		}
+	}
+
+	/// Zero-pads the observed bytes to a whole number of state elements.
+	fn align(&mut self) {
+		let index = self.index;
+		let aligned = index.next_multiple_of(size_of::<F>());
+		self.rate_mut()[index..aligned].fill(0);
+		self.index = aligned;
+		self.convert_observed();
+		if self.index == Self::RATE_BYTES {
+			self.permute();
+		}
+	}
+
+	/// Absorbs the state element that tags the field of the next observed element.
+	fn absorb_tag<FE: TowerField>(&mut self) {
+		let mut tag = [0u8; MAX_SCALAR_BYTES];
+		tag[0] = FE::TOWER_LEVEL as u8;
+		let tag = F::deserialize(&tag[..size_of::<F>()], SerializationMode::CanonicalTower)
+			.expect("tag has the size of a state element");
+		self.absorb_element(tag);
+	}
+
+	fn absorb_element(&mut self, elem: F) {
+		let index = self.index;
+		self.rate_mut()[index..index + size_of::<F>()].copy_from_slice(bytes_of(&elem));
+		self.index += size_of::<F>();
+		self.converted = self.index;
+		if self.index == Self::RATE_BYTES {
+			self.permute();
+		}
+	}
+}
+
+impl<F, T, Perm, const RATE: usize> Default for DuplexChallenger<F, T, Perm, RATE>
+where
+	F: TowerField + Pod,
+	T: Pod,
+	Perm: CryptographicPermutation<T> + Default,
+{
+	fn default() -> Self {
+		Self::new(Perm::default())
+	}
+}
+
+impl<F, T, Perm, const RATE: usize> Challenger for DuplexChallenger<F, T, Perm, RATE>
+where
+	F: TowerField + Pod,
+	T: Pod,
+	Perm: CryptographicPermutation<T>,
+{
+	/// This returns the inner challenger which implements `[BufMut]`
+	fn observer(&mut self) -> &mut impl BufMut {
+		self.start_observing();
+		DuplexObserver::wrap(self)
+	}
+
+	/// This returns the inner challenger which implements `[Buf]`
+	fn sampler(&mut self) -> &mut impl Buf {
+		self.start_sampling();
+		DuplexSampler::wrap(self)
+	}
+
+	fn observe_scalars<FE: TowerField>(&mut self, elems: &[FE]) {
+		self.start_observing();
+		self.align();
+		for elem in elems {
+			self.absorb_tag::<FE>();
+
+			let mut bytes = [0u8; MAX_SCALAR_BYTES];
+			let n_bytes = {
+				let mut buf = &mut bytes[..];
+				elem.serialize(&mut buf, SerializationMode::CanonicalTower)
+					.expect("tower field elements have at most 128 bits");
+				MAX_SCALAR_BYTES - buf.len()
+			};
+			// The zero bytes after the encoding embed elements of smaller fields into `F`.
+			let n_coords = n_bytes.div_ceil(size_of::<F>());
+			for coord in bytes.chunks_exact(size_of::<F>()).take(n_coords) {
+				let coord = F::deserialize(coord, SerializationMode::CanonicalTower)
+					.expect("coordinate has the size of a state element");
+				self.absorb_element(coord);
+			}
+		}
+	}
+
+	fn sample_scalar<FE: TowerField>(&mut self) -> FE {
+		self.start_sampling();
+		let n_bytes = FE::N_BITS.div_ceil(8);
+		let mut bytes = [0u8; MAX_SCALAR_BYTES];
+		for coord in
+			bytes[..n_bytes.next_multiple_of(size_of::<F>())].chunks_exact_mut(size_of::<F>())
+		{
+			let start = self.index.next_multiple_of(size_of::<F>());
+			self.n_sampled += start - self.index + size_of::<F>();
+			if start == Self::RATE_BYTES {
+				self.squeeze();
+			} else {
+				self.index = start;
+			}
+			let index = self.index;
+			coord.copy_from_slice(&self.rate()[index..index + size_of::<F>()]);
+			self.index += size_of::<F>();
+		}
+		// Elements of fields smaller than a byte take the low bits of the first byte.
+		if FE::N_BITS < 8 {
+			bytes[0] &= (1 << FE::N_BITS) - 1;
+		}
+		FE::deserialize(&bytes[..n_bytes], SerializationMode::CanonicalTower)
+			.expect("every value of the canonical encoding is a valid element")
+	}
+}
+
+/// View of a [`DuplexChallenger`] in observing mode, which implements [`BufMut`].
+#[repr(transparent)]
+struct DuplexObserver<F, T, Perm, const RATE: usize> {
+	inner: DuplexChallenger<F, T, Perm, RATE>,
+}
+
+impl<F, T, Perm, const RATE: usize> DuplexObserver<F, T, Perm, RATE> {
+	fn wrap(challenger: &mut DuplexChallenger<F, T, Perm, RATE>) -> &mut Self {
+		// SAFETY: `Self` is a `repr(transparent)` wrapper around `DuplexChallenger`.
+		unsafe { &mut *(challenger as *mut DuplexChallenger<F, T, Perm, RATE>).cast::<Self>() }
+	}
+}
+
+unsafe impl<F, T, Perm, const RATE: usize> BufMut for DuplexObserver<F, T, Perm, RATE>
+where
+	F: TowerField + Pod,
+	T: Pod,
+	Perm: CryptographicPermutation<T>,
+{
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, mut cnt: usize) {
+		let rate_bytes = DuplexChallenger::<F, T, Perm, RATE>::RATE_BYTES;
+		while cnt > 0 {
+			let remaining = (rate_bytes - self.inner.index).min(cnt);
+			cnt -= remaining;
+			self.inner.index += remaining;
+			if self.inner.index == rate_bytes {
+				self.inner.convert_observed();
+				self.inner.permute();
+			}
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		let index = self.inner.index;
+		(&mut self.inner.rate_mut()[index..]).into()
+	}
+}
+
+/// View of a [`DuplexChallenger`] in sampling mode, which implements [`Buf`].
+#[repr(transparent)]
+struct DuplexSampler<F, T, Perm, const RATE: usize> {
+	inner: DuplexChallenger<F, T, Perm, RATE>,
+}
+
+impl<F, T, Perm, const RATE: usize> DuplexSampler<F, T, Perm, RATE> {
+	fn wrap(challenger: &mut DuplexChallenger<F, T, Perm, RATE>) -> &mut Self {
+		// SAFETY: `Self` is a `repr(transparent)` wrapper around `DuplexChallenger`.
+		unsafe { &mut *(challenger as *mut DuplexChallenger<F, T, Perm, RATE>).cast::<Self>() }
+	}
+}
+
+impl<F, T, Perm, const RATE: usize> Buf for DuplexSampler<F, T, Perm, RATE>
+where
+	F: TowerField + Pod,
+	T: Pod,
+	Perm: CryptographicPermutation<T>,
+{
+	fn remaining(&self) -> usize {
+		usize::MAX
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.inner.rate()[self.inner.index..]
+	}
+
+	fn advance(&mut self, mut cnt: usize) {
+		let rate_bytes = DuplexChallenger::<F, T, Perm, RATE>::RATE_BYTES;
+		self.inner.n_sampled += cnt;
+
+		// Must handle the case when `cnt` is 0
+		if self.inner.index == rate_bytes {
+			self.inner.squeeze();
+		}
+
+		while cnt > 0 {
+			let remaining = (rate_bytes - self.inner.index).min(cnt);
+			if remaining == 0 {
+				self.inner.squeeze();
+				continue;
+			}
+			cnt -= remaining;
+			self.inner.index += remaining;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b, BinaryField8b, ExtensionField, Field};
+	use binius_hash::permutation::Permutation;
+	use bytemuck::{cast_slice, cast_slice_mut};
+	use rand::{thread_rng, RngCore};
+
+	use super::*;
+	use crate::{fiat_shamir::CanSample, transcript::ProverTranscript};
+
+	type VisionState = [PackedAESBinaryField8x32b; 3];
+
+	/// Overwrites the start of the rate with the state elements canonically encoded by `block`.
+	fn absorb_block(state: &mut VisionState, block: &[u8]) {
+		let lanes = cast_slice_mut::<_, AESTowerField32b>(state.as_mut_slice());
+		for (lane, word) in lanes.iter_mut().zip(block.chunks_exact(4)) {
+			*lane = BinaryField32b::new(u32::from_le_bytes(word.try_into().unwrap())).into();
+		}
+	}
+
+	/// Returns the canonical encoding of the first `n_lanes` state elements.
+	fn squeeze_lanes(state: &VisionState, n_lanes: usize) -> Vec<u8> {
+		cast_slice::<_, AESTowerField32b>(state.as_slice())[..n_lanes]
+			.iter()
+			.flat_map(|&lane| BinaryField32b::from(lane).val().to_le_bytes())
+			.collect()
+	}
+
+	#[test]
+	fn test_sampling_matches_manual_permutation() {
+		let mut challenger = Vision32bDuplexChallenger::default();
+		let mut observed = [0u8; 100];
+		thread_rng().fill_bytes(&mut observed);
+		challenger.observer().put_slice(&observed[..30]);
+		challenger.observer().put_slice(&observed[30..]);
+
+		let mut out = [0u8; 80];
+		challenger.sampler().copy_to_slice(&mut out);
+
+		// Absorb the first full block, then the padded remainder.
+		let mut state = VisionState::default();
+		let permutation = Vision32bPermutation::default();
+		absorb_block(&mut state, &observed[..64]);
+		permutation.permute_mut(&mut state);
+
+		let mut block = [0u8; 64];
+		block[..36].copy_from_slice(&observed[64..]);
+		block[36] = PADDING_START;
+		block[63] = PADDING_END;
+		absorb_block(&mut state, &block);
+		permutation.permute_mut(&mut state);
+
+		assert_eq!(out[..64], squeeze_lanes(&state, 16));
+		permutation.permute_mut(&mut state);
+		assert_eq!(out[64..], squeeze_lanes(&state, 4));
+	}
+
+	#[test]
+	fn test_scalars_are_absorbed_as_tagged_coordinates() {
+		let mut rng = thread_rng();
+		let elem = BinaryField128b::random(&mut rng);
+		let mut challenger = Vision32bDuplexChallenger::default();
+		challenger.observe_scalars(&[elem]);
+		let mut out = [0u8; 64];
+		challenger.sampler().copy_to_slice(&mut out);
+
+		let mut state = VisionState::default();
+		let lanes = cast_slice_mut::<_, AESTowerField32b>(state.as_mut_slice());
+		lanes[0] = BinaryField32b::new(BinaryField128b::TOWER_LEVEL as u32).into();
+		for (lane, coord) in lanes[1..]
+			.iter_mut()
+			.zip(ExtensionField::<BinaryField32b>::iter_bases(&elem))
+		{
+			*lane = coord.into();
+		}
+		lanes[5] = BinaryField32b::new(PADDING_START as u32).into();
+		lanes[15] = BinaryField32b::new((PADDING_END as u32) << 24).into();
+		Vision32bPermutation::default().permute_mut(&mut state);
+		assert_eq!(out[..], squeeze_lanes(&state, 16));
+	}
+
+	#[test]
+	fn test_scalar_field_is_bound() {
+		let mut challenger_1 = Vision32bDuplexChallenger::default();
+		challenger_1.observe_scalars(&[BinaryField8b::new(0x2a)]);
+		let mut challenger_2 = Vision32bDuplexChallenger::default();
+		challenger_2.observe_scalars(&[BinaryField32b::new(0x2a)]);
+
+		let samples = [&mut challenger_1, &mut challenger_2].map(|challenger| {
+			let mut out = [0u8; 64];
+			challenger.sampler().copy_to_slice(&mut out);
+			out
+		});
+		assert_ne!(samples[0], samples[1]);
+	}
+
+	#[test]
+	fn test_scalars_are_sampled_from_whole_state_elements() {
+		let mut challenger = Vision32bDuplexChallenger::default();
+		challenger.observer().put_slice(&[1, 2, 3]);
+		let mut sampled_bytes = [0u8; 2];
+		challenger.sampler().copy_to_slice(&mut sampled_bytes);
+		let small = challenger.sample_scalar::<BinaryField8b>();
+		let large = (0..4)
+			.map(|_| challenger.sample_scalar::<BinaryField128b>())
+			.collect::<Vec<_>>();
+
+		let mut state = VisionState::default();
+		let mut block = [0u8; 64];
+		block[..3].copy_from_slice(&[1, 2, 3]);
+		block[3] = PADDING_START;
+		block[63] = PADDING_END;
+		absorb_block(&mut state, &block);
+		let permutation = Vision32bPermutation::default();
+		permutation.permute_mut(&mut state);
+		let mut squeezed = squeeze_lanes(&state, 16);
+		permutation.permute_mut(&mut state);
+		squeezed.extend(squeeze_lanes(&state, 16));
+
+		// The partially sampled first state element is skipped.
+		assert_eq!(sampled_bytes, squeezed[..2]);
+		assert_eq!(small, BinaryField8b::new(squeezed[4]));
+		// The coordinates of the last large element span both rates.
+		for (i, elem) in large.into_iter().enumerate() {
+			let offset = 8 + 16 * i;
+			let expected = u128::from_le_bytes(squeezed[offset..offset + 16].try_into().unwrap());
+			assert_eq!(elem, BinaryField128b::new(expected));
+		}
+	}
+
+	#[test]
+	fn test_padding_distinguishes_observations() {
+		let mut challenger_1 = Groestl256DuplexChallenger::default();
+		challenger_1.observer().put_slice(&[1, 2, 3]);
+		let mut challenger_2 = Groestl256DuplexChallenger::default();
+		challenger_2.observer().put_slice(&[1, 2, 3, 0]);
+		let mut challenger_3 = Groestl256DuplexChallenger::default();
+		challenger_3.observer().put_slice(&[1, 2, 3, PADDING_START]);
+
+		let samples = [&mut challenger_1, &mut challenger_2, &mut challenger_3].map(|challenger| {
+			let mut out = [0u8; 32];
+			challenger.sampler().copy_to_slice(&mut out);
+			out
+		});
+		assert_ne!(samples[0], samples[1]);
+		assert_ne!(samples[0], samples[2]);
+		assert_ne!(samples[1], samples[2]);
+	}
+
+	#[test]
+	fn test_sample_count_is_bound() {
+		let samples = [4, 8].map(|n_sampled| {
+			let mut challenger = Groestl256DuplexChallenger::default();
+			challenger.observer().put_slice(&[1, 2, 3]);
+			challenger.sampler().advance(n_sampled);
+			challenger.observer().put_slice(&[4, 5, 6]);
+
+			let mut out = [0u8; 32];
+			challenger.sampler().copy_to_slice(&mut out);
+			out
+		});
+		assert_ne!(samples[0], samples[1]);
+	}
+
+	#[test]
+	fn test_transcript_prover_verifier_agree() {
+		let mut prover = ProverTranscript::<Vision32bDuplexChallenger>::new();
+		prover.message().write_scalar(BinaryField32b::new(7));
+		prover.message().write_bytes(&[1, 2, 3]);
+		prover.message().write_scalar(BinaryField8b::new(9));
+		let prover_challenge_1: BinaryField128b = prover.sample();
+		prover
+			.message()
+			.write_scalar_slice(&[BinaryField128b::new(1), prover_challenge_1]);
+		let prover_challenges: Vec<BinaryField128b> = prover.sample_vec(5);
+
+		let mut verifier = prover.into_verifier();
+		assert_eq!(
+			verifier.message().read_scalar::<BinaryField32b>().unwrap(),
+			BinaryField32b::new(7)
+		);
+		let mut bytes = [0u8; 3];
+		verifier.message().read_bytes(&mut bytes).unwrap();
+		assert_eq!(bytes, [1, 2, 3]);
+		assert_eq!(
+			verifier.message().read_scalar::<BinaryField8b>().unwrap(),
+			BinaryField8b::new(9)
+		);
+		let verifier_challenge_1: BinaryField128b = verifier.sample();
+		assert_eq!(verifier_challenge_1, prover_challenge_1);
+		verifier
+			.message()
+			.read_scalar_slice::<BinaryField128b>(2)
+			.unwrap();
+		let verifier_challenges: Vec<BinaryField128b> = verifier.sample_vec(5);
+		assert_eq!(verifier_challenges, prover_challenges);
+		verifier.finalize().unwrap();
+	}
+}
@@ -1,9 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+mod duplex_challenger;
 mod hasher_challenger;
 mod sampling;
 
+use binius_field::TowerField;
+use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
 use bytes::{Buf, BufMut};
+pub use duplex_challenger::{
+	DuplexChallenger, Groestl256DuplexChallenger, Vision32bDuplexChallenger,
+};
 pub use hasher_challenger::HasherChallenger;
 pub use sampling::*;
 
@@ -14,4 +20,26 @@ pub trait Challenger {
 
 	/// Returns and infinite buffer for writing data that the challenger observes.
 	fn observer(&mut self) -> &mut impl BufMut;
+
+	/// Observes a slice of field elements.
+	///
+	/// The default implementation observes the canonical tower serialization of the elements.
+	/// Challengers over an arithmetic permutation override this to absorb the elements natively.
+	fn observe_scalars<F: TowerField>(&mut self, elems: &[F]) {
+		let observer = self.observer();
+		for elem in elems {
+			SerializeBytes::serialize(elem, &mut *observer, SerializationMode::CanonicalTower)
+				.expect("observer has infinite buffer");
+		}
+	}
+
+	/// Samples a field element.
+	///
+	/// The default implementation deserializes the canonical tower encoding of the element from
+	/// the sampled bytes. Challengers over an arithmetic permutation override this to build the
+	/// element from state elements natively.
+	fn sample_scalar<F: TowerField>(&mut self) -> F {
+		DeserializeBytes::deserialize(self.sampler(), SerializationMode::CanonicalTower)
+			.expect("sampler has infinite buffer")
+	}
 }
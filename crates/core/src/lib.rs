@@ -14,10 +14,12 @@
 
 pub mod composition;
 pub mod constraint_system;
+pub mod data_availability;
 pub mod fiat_shamir;
 pub mod merkle_tree;
 pub mod oracle;
 pub mod piop;
+pub mod poly_commit;
 pub mod polynomial;
 pub mod protocols;
 #[allow(clippy::module_inception)]
@@ -1,13 +1,12 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, mem::MaybeUninit};
+use std::{fmt::Debug, marker::PhantomData, mem::MaybeUninit, ops::Deref};
 
 use binius_field::TowerField;
-use binius_hash::{HashBuffer, PseudoCompressionFunction};
+use binius_hal::ComputationBackend;
+use binius_hash::PseudoCompressionFunction;
 use binius_maybe_rayon::{prelude::*, slice::ParallelSlice};
-use binius_utils::{
-	bail, checked_arithmetics::log2_strict_usize, SerializationMode, SerializeBytes,
-};
+use binius_utils::{bail, checked_arithmetics::log2_strict_usize};
 use digest::{crypto_common::BlockSizeUser, Digest, FixedOutputReset, Output};
 use tracing::instrument;
 
@@ -18,23 +17,32 @@ use super::errors::Error;
 /// The vector entries at each index in a batch are hashed together into leaf digests. Then a
 /// Merkle tree is constructed over the leaf digests. The implementation requires that the vector
 /// lengths are all equal to each other and a power of two.
+///
+/// `Nodes` is the buffer holding the inner nodes, which is allocated by the computation backend.
 #[derive(Debug, Clone)]
-pub struct BinaryMerkleTree<D> {
+pub struct BinaryMerkleTree<D, Nodes = Vec<D>> {
 	/// Base-2 logarithm of the number of leaves
 	pub log_len: usize,
 	/// The inner nodes, arranged as a flattened array of layers with the root at the end
-	pub inner_nodes: Vec<D>,
+	pub inner_nodes: Nodes,
+	_marker: PhantomData<D>,
 }
 
-pub fn build<F, H, C>(
+/// A [`BinaryMerkleTree`] over `H` digests whose inner nodes are allocated by `Backend`.
+pub type BackendMerkleTree<H, Backend> =
+	BinaryMerkleTree<Output<H>, <Backend as ComputationBackend>::Vec<Output<H>>>;
+
+pub fn build<F, H, C, Backend>(
+	backend: &Backend,
 	compression: &C,
 	elements: &[F],
 	batch_size: usize,
-) -> Result<BinaryMerkleTree<Output<H>>, Error>
+) -> Result<BackendMerkleTree<H, Backend>, Error>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser + FixedOutputReset,
 	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	if elements.len() % batch_size != 0 {
 		bail!(Error::IncorrectBatchSize);
@@ -49,77 +57,71 @@ where
 	let log_len = log2_strict_usize(len);
 
 	internal_build(
+		backend,
 		compression,
-		|inner_nodes| hash_interleaved::<_, H>(elements, inner_nodes),
+		|inner_nodes| hash_interleaved::<_, H, _>(backend, elements, inner_nodes),
 		log_len,
 	)
 }
 
-fn internal_build<Digest, C>(
+fn internal_build<Digest, C, Backend>(
+	backend: &Backend,
 	compression: &C,
-	// Must either successfully initialize the passed in slice or return error
-	hash_leaves: impl FnOnce(&mut [MaybeUninit<Digest>]) -> Result<(), Error>,
+	// Must either successfully overwrite the passed in slice or return error
+	hash_leaves: impl FnOnce(&mut [Digest]) -> Result<(), Error>,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Digest>, Error>
+) -> Result<BinaryMerkleTree<Digest, Backend::Vec<Digest>>, Error>
 where
-	Digest: Clone + Send + Sync,
+	Digest: Clone + Default + Debug + Send + Sync + 'static,
 	C: PseudoCompressionFunction<Digest, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	let total_length = (1 << (log_len + 1)) - 1;
-	let mut inner_nodes = Vec::with_capacity(total_length);
-
-	hash_leaves(&mut inner_nodes.spare_capacity_mut()[..(1 << log_len)])?;
+	let mut inner_nodes = backend.filled_hal_slice(total_length, Digest::default());
 
-	let (prev_layer, mut remaining) = inner_nodes.spare_capacity_mut().split_at_mut(1 << log_len);
+	let (mut prev_layer, mut remaining) = inner_nodes.split_at_mut(1 << log_len);
+	hash_leaves(prev_layer)?;
 
-	let mut prev_layer = unsafe {
-		// SAFETY: prev-layer was initialized by hash_leaves
-		slice_assume_init_mut(prev_layer)
-	};
 	for i in 1..(log_len + 1) {
 		let (next_layer, next_remaining) = remaining.split_at_mut(1 << (log_len - i));
 		remaining = next_remaining;
 
-		compress_layer(compression, prev_layer, next_layer);
+		let _scope = tracing::debug_span!("MerkleTree::compress_layer").entered();
+		backend.merkle_compress_layer(compression, prev_layer, next_layer)?;
 
-		prev_layer = unsafe {
-			// SAFETY: next_layer was just initialized by compress_layer
-			slice_assume_init_mut(next_layer)
-		};
+		prev_layer = next_layer;
 	}
 
-	unsafe {
-		// SAFETY: inner_nodes should be entirely initialized by now
-		// Note that we don't incrementally update inner_nodes.len() since
-		// that doesn't play well with using split_at_mut on spare capacity.
-		inner_nodes.set_len(total_length);
-	}
 	Ok(BinaryMerkleTree {
 		log_len,
 		inner_nodes,
+		_marker: PhantomData,
 	})
 }
 
 #[instrument("BinaryMerkleTree::build", skip_all, level = "debug")]
-pub fn build_from_iterator<F, H, C, ParIter>(
+pub fn build_from_iterator<F, H, C, Backend, ParIter>(
+	backend: &Backend,
 	compression: &C,
 	iterated_chunks: ParIter,
 	log_len: usize,
-) -> Result<BinaryMerkleTree<Output<H>>, Error>
+) -> Result<BackendMerkleTree<H, Backend>, Error>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser + FixedOutputReset,
 	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	Backend: ComputationBackend,
 	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 {
 	internal_build(
+		backend,
 		compression,
-		|inner_nodes| hash_iterated::<F, H, _>(iterated_chunks, inner_nodes),
+		|inner_nodes| Ok(backend.merkle_hash_leaves::<F, H, _>(iterated_chunks, inner_nodes)?),
 		log_len,
 	)
 }
 
-impl<D: Clone> BinaryMerkleTree<D> {
+impl<D: Clone, Nodes: Deref<Target = [D]>> BinaryMerkleTree<D, Nodes> {
 	pub fn root(&self) -> D {
 		self.inner_nodes
 			.last()
@@ -155,20 +157,44 @@ impl<D: Clone> BinaryMerkleTree<D> {
 
 		Ok(branch)
 	}
-}
 
-#[tracing::instrument("MerkleTree::compress_layer", skip_all, level = "debug")]
-fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [MaybeUninit<D>])
-where
-	D: Clone + Send + Sync,
-	C: PseudoCompressionFunction<D, 2> + Sync,
-{
-	prev_layer
-		.par_chunks_exact(2)
-		.zip(next_layer.par_iter_mut())
-		.for_each(|(prev_pair, next_digest)| {
-			next_digest.write(compression.compress(array::from_fn(|i| prev_pair[i].clone())));
-		})
+	/// Get the deduplicated Merkle branch nodes for a batch of indices.
+	///
+	/// The nodes are listed layer by layer, starting from the leaves, and in increasing order of
+	/// position within each layer. A node is omitted if it lies on the path of another opened
+	/// index, since the verifier can compute it.
+	///
+	/// Throws if any index is out of range
+	pub fn multi_branch(&self, indices: &[usize], layer_depth: usize) -> Result<Vec<D>, Error> {
+		if indices.iter().any(|&index| index >= 1 << self.log_len) || layer_depth > self.log_len {
+			return Err(Error::IndexOutOfRange {
+				max: (1 << self.log_len) - 1,
+			});
+		}
+
+		let mut layer_indices = indices.to_vec();
+		layer_indices.sort_unstable();
+		layer_indices.dedup();
+
+		let mut branches = Vec::new();
+		for j in 0..self.log_len - layer_depth {
+			let layer_start = ((1 << j) - 1) << (self.log_len + 1 - j);
+
+			let mut next_layer_indices = Vec::with_capacity(layer_indices.len());
+			let mut iter = layer_indices.iter().copied().peekable();
+			while let Some(index) = iter.next() {
+				if index & 1 == 0 && iter.peek() == Some(&(index | 1)) {
+					iter.next();
+				} else {
+					branches.push(self.inner_nodes[layer_start | (index ^ 1)].clone());
+				}
+				next_layer_indices.push(index >> 1);
+			}
+			layer_indices = next_layer_indices;
+		}
+
+		Ok(branches)
+	}
 }
 
 /// Hashes the elements in chunks of a vector into digests.
@@ -177,10 +203,15 @@ where
 /// into N equal-sized chunks and hashes each chunks into the corresponding output digest. This
 /// returns the number of elements hashed into each digest.
 #[tracing::instrument("hash_interleaved", skip_all, level = "debug")]
-fn hash_interleaved<F, H>(elems: &[F], digests: &mut [MaybeUninit<Output<H>>]) -> Result<(), Error>
+fn hash_interleaved<F, H, Backend>(
+	backend: &Backend,
+	elems: &[F],
+	digests: &mut [Output<H>],
+) -> Result<(), Error>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser + FixedOutputReset,
+	Backend: ComputationBackend,
 {
 	if elems.len() % digests.len() != 0 {
 		return Err(Error::IncorrectVectorLen {
@@ -188,37 +219,12 @@ where
 		});
 	}
 	let batch_size = elems.len() / digests.len();
-	hash_iterated::<F, H, _>(
+	backend.merkle_hash_leaves::<F, H, _>(
 		elems
 			.par_chunks(batch_size)
 			.map(|chunk| chunk.iter().copied()),
 		digests,
-	)
-}
-
-fn hash_iterated<F, H, ParIter>(
-	iterated_chunks: ParIter,
-	digests: &mut [MaybeUninit<Output<H>>],
-) -> Result<(), Error>
-where
-	F: TowerField,
-	H: Digest + BlockSizeUser + FixedOutputReset,
-	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
-{
-	digests
-		.par_iter_mut()
-		.zip(iterated_chunks)
-		.for_each_init(H::new, |hasher, (digest, elems)| {
-			{
-				let mut hash_buffer = HashBuffer::new(hasher);
-				for elem in elems {
-					let mode = SerializationMode::CanonicalTower;
-					SerializeBytes::serialize(&elem, &mut hash_buffer, mode)
-						.expect("HashBuffer has infinite capacity");
-				}
-			}
-			digest.write(Digest::finalize_reset(hasher));
-		});
+	)?;
 	Ok(())
 }
 
@@ -63,6 +63,29 @@ pub trait MerkleTreeScheme<T>: Sync {
 		layer_digests: &[Self::Digest],
 		proof: &mut TranscriptReader<B>,
 	) -> Result<(), Error>;
+
+	/// Verify a batched opening proof for entries in a committed vector at multiple indices.
+	///
+	/// The proof is the one generated by [`MerkleTreeProver::prove_multi_opening`]. Branch nodes
+	/// that are shared between the openings, or that the verifier can compute from the opened
+	/// values, are not included in the proof.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - the entry indices, possibly unsorted and with repetitions
+	/// * `values` - the concatenation of the opened values, in the same order as `indices`
+	/// * `layer_depth` - depth of the layer to verify inclusion in
+	/// * `tree_depth` - depth of the Merkle tree
+	/// * `layer_digests` - the digests of the layer at `layer_depth`
+	fn verify_multi_opening<B: Buf>(
+		&self,
+		indices: &[usize],
+		values: &[T],
+		layer_depth: usize,
+		tree_depth: usize,
+		layer_digests: &[Self::Digest],
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error>;
 }
 
 /// A Merkle tree prover for a particular scheme.
@@ -116,4 +139,22 @@ pub trait MerkleTreeProver<T>: Sync {
 		index: usize,
 		proof: &mut TranscriptWriter<B>,
 	) -> Result<(), Error>;
+
+	/// Generate a batched opening proof for entries in a committed vector at multiple indices.
+	///
+	/// Unlike calling [`Self::prove_opening`] for each index, every branch node is written at
+	/// most once, and nodes that can be computed from the opened entries are omitted.
+	///
+	/// ## Arguments
+	///
+	/// * `committed` - helper data generated during commitment
+	/// * `layer_depth` - depth of the layer to prove inclusion in
+	/// * `indices` - the entry indices, possibly unsorted and with repetitions
+	fn prove_multi_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		indices: &[usize],
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error>;
 }
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::TowerField;
+use binius_hal::{make_portable_backend, ComputationBackend, CpuBackend};
 use binius_hash::PseudoCompressionFunction;
 use binius_maybe_rayon::iter::IndexedParallelIterator;
 use bytes::BufMut;
@@ -9,35 +10,48 @@ use getset::Getters;
 use tracing::instrument;
 
 use super::{
-	binary_merkle_tree::{self, BinaryMerkleTree},
+	binary_merkle_tree::{self, BackendMerkleTree},
 	errors::Error,
 	merkle_tree_vcs::{Commitment, MerkleTreeProver},
 	scheme::BinaryMerkleTreeScheme,
 };
 use crate::transcript::TranscriptWriter;
 
+/// Prover for [`BinaryMerkleTreeScheme`].
+///
+/// Leaf hashing and layer compression are delegated to the computation backend.
 #[derive(Debug, Getters)]
-pub struct BinaryMerkleTreeProver<T, H, C> {
+pub struct BinaryMerkleTreeProver<T, H, C, Backend = CpuBackend> {
 	#[getset(get = "pub")]
 	scheme: BinaryMerkleTreeScheme<T, H, C>,
+	backend: Backend,
 }
 
 impl<T, C, H> BinaryMerkleTreeProver<T, H, C> {
 	pub fn new(compression: C) -> Self {
+		Self::with_backend(compression, make_portable_backend())
+	}
+}
+
+impl<T, C, H, Backend> BinaryMerkleTreeProver<T, H, C, Backend> {
+	/// Constructs a prover that builds trees with the given computation backend.
+	pub fn with_backend(compression: C, backend: Backend) -> Self {
 		Self {
 			scheme: BinaryMerkleTreeScheme::new(compression),
+			backend,
 		}
 	}
 }
 
-impl<F, H, C> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C>
+impl<F, H, C, Backend> MerkleTreeProver<F> for BinaryMerkleTreeProver<F, H, C, Backend>
 where
 	F: TowerField,
 	H: Digest + BlockSizeUser + FixedOutputReset,
 	C: PseudoCompressionFunction<Output<H>, 2> + Sync,
+	Backend: ComputationBackend,
 {
 	type Scheme = BinaryMerkleTreeScheme<F, H, C>;
-	type Committed = BinaryMerkleTree<Output<H>>;
+	type Committed = BackendMerkleTree<H, Backend>;
 
 	fn scheme(&self) -> &Self::Scheme {
 		&self.scheme
@@ -48,8 +62,12 @@ where
 		data: &[F],
 		batch_size: usize,
 	) -> Result<(Commitment<Output<H>>, Self::Committed), Error> {
-		let tree =
-			binary_merkle_tree::build::<_, H, _>(self.scheme.compression(), data, batch_size)?;
+		let tree = binary_merkle_tree::build::<_, H, _, _>(
+			&self.backend,
+			self.scheme.compression(),
+			data,
+			batch_size,
+		)?;
 
 		let commitment = Commitment {
 			root: tree.root(),
@@ -79,6 +97,18 @@ where
 		Ok(())
 	}
 
+	fn prove_multi_opening<B: BufMut>(
+		&self,
+		committed: &Self::Committed,
+		layer_depth: usize,
+		indices: &[usize],
+		proof: &mut TranscriptWriter<B>,
+	) -> Result<(), Error> {
+		let branches = committed.multi_branch(indices, layer_depth)?;
+		proof.write_slice(&branches);
+		Ok(())
+	}
+
 	#[instrument(skip_all, level = "debug")]
 	#[allow(clippy::type_complexity)]
 	fn commit_iterated<ParIter>(
@@ -92,7 +122,8 @@ where
 	where
 		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
 	{
-		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _>(
+		let tree = binary_merkle_tree::build_from_iterator::<F, H, C, _, _>(
+			&self.backend,
 			self.scheme.compression(),
 			iterated_chunks,
 			log_len,
@@ -140,6 +140,87 @@ where
 			.then_some(())
 			.ok_or_else(|| VerificationError::InvalidProof.into())
 	}
+
+	fn verify_multi_opening<B: Buf>(
+		&self,
+		indices: &[usize],
+		values: &[F],
+		layer_depth: usize,
+		tree_depth: usize,
+		layer_digests: &[Self::Digest],
+		proof: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		if (1 << layer_depth) != layer_digests.len() || layer_depth > tree_depth {
+			bail!(VerificationError::IncorrectVectorLength);
+		}
+
+		if indices.is_empty() {
+			if !values.is_empty() {
+				bail!(Error::IncorrectBatchSize);
+			}
+			return Ok(());
+		}
+
+		if values.len() % indices.len() != 0 {
+			bail!(Error::IncorrectBatchSize);
+		}
+
+		if indices.iter().any(|&index| index >= 1 << tree_depth) {
+			bail!(Error::IndexOutOfRange {
+				max: (1 << tree_depth) - 1
+			});
+		}
+
+		let mut nodes = indices
+			.iter()
+			.copied()
+			.zip(values.chunks(values.len() / indices.len()))
+			.map(|(index, values)| (index, hash_field_elems::<_, H>(values)))
+			.collect::<Vec<_>>();
+		nodes.sort_unstable_by_key(|&(index, _)| index);
+
+		// Repeated indices must open to the same values.
+		let mut leaves = Vec::with_capacity(nodes.len());
+		for (index, digest) in nodes {
+			match leaves.last() {
+				Some((last_index, last_digest)) if *last_index == index => {
+					if *last_digest != digest {
+						bail!(VerificationError::InvalidProof);
+					}
+				}
+				_ => leaves.push((index, digest)),
+			}
+		}
+
+		let mut nodes = leaves;
+		for _ in layer_depth..tree_depth {
+			let mut next_nodes = Vec::with_capacity(nodes.len());
+			let mut iter = nodes.into_iter().peekable();
+			while let Some((index, digest)) = iter.next() {
+				let sibling =
+					iter.next_if(|&(next_index, _)| index & 1 == 0 && next_index == index | 1);
+				let digest = match sibling {
+					Some((_, sibling)) => self.compression.compress([digest, sibling]),
+					None => {
+						let sibling = proof.read()?;
+						self.compression.compress(if index & 1 == 0 {
+							[digest, sibling]
+						} else {
+							[sibling, digest]
+						})
+					}
+				};
+				next_nodes.push((index >> 1, digest));
+			}
+			nodes = next_nodes;
+		}
+
+		nodes
+			.into_iter()
+			.all(|(index, digest)| digest == layer_digests[index])
+			.then_some(())
+			.ok_or_else(|| VerificationError::InvalidProof.into())
+	}
 }
 
 // Merkle-tree-like folding
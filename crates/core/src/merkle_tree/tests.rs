@@ -101,3 +101,95 @@ fn test_binary_merkle_vcs_verify_vector() {
 		.verify_vector(&commitment.root, &data, 1)
 		.unwrap();
 }
+
+#[test]
+fn test_binary_merkle_vcs_commit_multi_prove_open_correctly() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let batch_size = 2;
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(32 * batch_size)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, batch_size).unwrap();
+
+	assert_eq!(commitment.root, tree.root());
+
+	// Unsorted indices with a repetition and a sibling pair
+	let indices = [13, 2, 3, 27, 13, 31];
+	let values = indices
+		.iter()
+		.flat_map(|&i| data[i * batch_size..(i + 1) * batch_size].iter().copied())
+		.collect::<Vec<_>>();
+
+	for layer_depth in 0..5 {
+		let layer = mr_prover.layer(&tree, layer_depth).unwrap();
+
+		let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		mr_prover
+			.prove_multi_opening(&tree, layer_depth, &indices, &mut proof_writer.message())
+			.unwrap();
+
+		let mut proof_reader = proof_writer.into_verifier();
+		mr_prover
+			.scheme()
+			.verify_multi_opening(
+				&indices,
+				&values,
+				layer_depth,
+				5,
+				layer,
+				&mut proof_reader.message(),
+			)
+			.unwrap();
+		proof_reader.finalize().unwrap();
+	}
+
+	// The batched proof is smaller than the individual proofs combined
+	let mut multi_proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	mr_prover
+		.prove_multi_opening(&tree, 0, &indices, &mut multi_proof.message())
+		.unwrap();
+	let mut single_proofs = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	for &i in &indices {
+		mr_prover
+			.prove_opening(&tree, 0, i, &mut single_proofs.message())
+			.unwrap();
+	}
+	assert!(multi_proof.finalize().len() < single_proofs.finalize().len());
+}
+
+#[test]
+fn test_binary_merkle_vcs_multi_open_rejects_wrong_values() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let mr_prover = BinaryMerkleTreeProver::<_, Groestl256, _>::new(Groestl256ByteCompression);
+
+	let data = repeat_with(|| Field::random(&mut rng))
+		.take(16)
+		.collect::<Vec<BinaryField16b>>();
+	let (commitment, tree) = mr_prover.commit(&data, 1).unwrap();
+
+	let indices = [1, 6, 7];
+	let mut values = indices.iter().map(|&i| data[i]).collect::<Vec<_>>();
+	values[1] += BinaryField16b::ONE;
+
+	let mut proof_writer = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	mr_prover
+		.prove_multi_opening(&tree, 0, &indices, &mut proof_writer.message())
+		.unwrap();
+
+	let mut proof_reader = proof_writer.into_verifier();
+	assert!(mr_prover
+		.scheme()
+		.verify_multi_opening(
+			&indices,
+			&values,
+			0,
+			4,
+			&[commitment.root],
+			&mut proof_reader.message(),
+		)
+		.is_err());
+}
@@ -6,21 +6,64 @@ use std::sync::Arc;
 use binius_field::{Field, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
 use binius_math::{ArithExpr, CompositionPoly};
-use binius_utils::bail;
+use binius_utils::{bail, DeserializeBytes, SerializationError, SerializationMode, SerializeBytes};
 use itertools::Itertools;
 
 use super::{Error, MultilinearOracleSet, MultilinearPolyVariant, OracleId};
+use crate::polynomial::CircuitEvaluator;
 
 /// Composition trait object that can be used to create lists of compositions of differing
 /// concrete types.
 pub type TypeErasedComposition<P> = Arc<dyn CompositionPoly<P>>;
 
 /// Constraint is a type erased composition along with a predicate on its values on the boolean hypercube
-#[derive(Debug, Clone, SerializeBytes, DeserializeBytes)]
+#[derive(Debug, Clone)]
 pub struct Constraint<F: Field> {
 	pub name: String,
 	pub composition: ArithExpr<F>,
 	pub predicate: ConstraintPredicate<F>,
+	/// Strategy the prover uses to evaluate the composition.
+	///
+	/// This is a prover-side tuning knob that does not change the statement, so it is not
+	/// serialized and deserializes to the default.
+	pub evaluator: CircuitEvaluator,
+}
+
+impl<F: Field> SerializeBytes for Constraint<F>
+where
+	ArithExpr<F>: SerializeBytes,
+	ConstraintPredicate<F>: SerializeBytes,
+{
+	fn serialize(
+		&self,
+		mut write_buf: impl bytes::BufMut,
+		mode: SerializationMode,
+	) -> Result<(), SerializationError> {
+		self.name.serialize(&mut write_buf, mode)?;
+		self.composition.serialize(&mut write_buf, mode)?;
+		self.predicate.serialize(&mut write_buf, mode)
+	}
+}
+
+impl<F: Field> DeserializeBytes for Constraint<F>
+where
+	ArithExpr<F>: DeserializeBytes,
+	ConstraintPredicate<F>: DeserializeBytes,
+{
+	fn deserialize(
+		mut read_buf: impl bytes::Buf,
+		mode: SerializationMode,
+	) -> Result<Self, SerializationError>
+	where
+		Self: Sized,
+	{
+		Ok(Self {
+			name: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			composition: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			predicate: DeserializeBytes::deserialize(&mut read_buf, mode)?,
+			evaluator: CircuitEvaluator::default(),
+		})
+	}
 }
 
 /// Predicate can either be a sum of values of a composition on the hypercube (sumcheck) or equality to zero
@@ -46,6 +89,7 @@ struct UngroupedConstraint<F: Field> {
 	oracle_ids: Vec<OracleId>,
 	composition: ArithExpr<F>,
 	predicate: ConstraintPredicate<F>,
+	evaluator: CircuitEvaluator,
 }
 
 /// A builder struct that turns individual compositions over oraclized multilinears into a set of
@@ -53,15 +97,22 @@ struct UngroupedConstraint<F: Field> {
 #[derive(Default)]
 pub struct ConstraintSetBuilder<F: Field> {
 	constraints: Vec<UngroupedConstraint<F>>,
+	evaluator: CircuitEvaluator,
 }
 
 impl<F: Field> ConstraintSetBuilder<F> {
 	pub const fn new() -> Self {
 		Self {
 			constraints: Vec::new(),
+			evaluator: CircuitEvaluator::Interpreted,
 		}
 	}
 
+	/// Sets the evaluator for the compositions of constraints added after this call.
+	pub fn set_evaluator(&mut self, evaluator: CircuitEvaluator) {
+		self.evaluator = evaluator;
+	}
+
 	pub fn add_sumcheck(
 		&mut self,
 		oracle_ids: impl IntoIterator<Item = OracleId>,
@@ -73,6 +124,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 			oracle_ids: oracle_ids.into_iter().collect(),
 			composition,
 			predicate: ConstraintPredicate::Sum(sum),
+			evaluator: self.evaluator,
 		});
 	}
 
@@ -87,6 +139,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 			oracle_ids: oracle_ids.into_iter().collect(),
 			composition,
 			predicate: ConstraintPredicate::Zero,
+			evaluator: self.evaluator,
 		});
 	}
 
@@ -140,6 +193,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 						))
 						.expect("Infallible by ConstraintSetBuilder invariants."),
 					predicate: constraint.predicate,
+					evaluator: constraint.evaluator,
 				})
 				.collect();
 
@@ -241,6 +295,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 							))
 							.expect("Infallible by ConstraintSetBuilder invariants."),
 						predicate: constraint.predicate,
+						evaluator: constraint.evaluator,
 					})
 					.collect();
 
@@ -24,6 +24,8 @@ pub enum Error {
 	TowerLevelTooHigh { tower_level: usize },
 	#[error("constraint set is empty")]
 	EmptyConstraintSet,
+	#[error("got {n_evals} evaluations for {n_queries} evaluation queries")]
+	EvalQueryCountMismatch { n_queries: usize, n_evals: usize },
 	#[error("expected constraint set to contain only constraints with n_vars={expected}, but found n_vars={got}")]
 	ConstraintSetNvarsMismatch { got: usize, expected: usize },
 }
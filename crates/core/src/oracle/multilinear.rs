@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, sync::Arc};
+use std::{array, fmt::Debug, iter, sync::Arc};
 
 use binius_field::{BinaryField128b, Field, TowerField};
 use binius_macros::{DeserializeBytes, SerializeBytes};
@@ -14,6 +14,7 @@ use crate::{
 	polynomial::{
 		ArithCircuitPoly, Error as PolynomialError, IdentityCompositionPoly, MultivariatePoly,
 	},
+	protocols::evalcheck::EvalcheckMultilinearClaim,
 };
 
 /// Identifier for a multilinear oracle in a [`MultilinearOracleSet`].
@@ -478,6 +479,61 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn tower_level(&self, id: OracleId) -> usize {
 		self.oracles[id].binary_tower_level()
 	}
+
+	/// Checks that an evaluation query refers to an oracle in the set and that its evaluation
+	/// point has the oracle's number of variables.
+	pub fn check_eval_query(&self, query: &OracleEvalQuery<F>) -> Result<(), Error> {
+		if !self.is_valid_oracle_id(query.id) {
+			bail!(Error::InvalidOracleId(query.id));
+		}
+		let n_vars = self.n_vars(query.id);
+		if query.eval_point.len() != n_vars {
+			bail!(Error::IncorrectNumberOfVariables { expected: n_vars });
+		}
+		Ok(())
+	}
+
+	/// Opens a batch of oracles at arbitrary points, pairing each evaluation query with its
+	/// claimed evaluation.
+	///
+	/// The resulting claims can be reduced together with the claims output by the other
+	/// protocols through evalcheck.
+	pub fn eval_claims(
+		&self,
+		queries: &[OracleEvalQuery<F>],
+		evals: &[F],
+	) -> Result<Vec<EvalcheckMultilinearClaim<F>>, Error> {
+		if queries.len() != evals.len() {
+			bail!(Error::EvalQueryCountMismatch {
+				n_queries: queries.len(),
+				n_evals: evals.len(),
+			});
+		}
+
+		iter::zip(queries, evals)
+			.map(|(query, &eval)| {
+				self.check_eval_query(query)?;
+				Ok(EvalcheckMultilinearClaim {
+					id: query.id,
+					eval_point: query.eval_point.as_slice().into(),
+					eval,
+				})
+			})
+			.collect()
+	}
+}
+
+/// A query for the evaluation of a multilinear oracle at an arbitrary point.
+///
+/// Besides the evaluation claims generated internally by the protocol reductions, a prover can be
+/// asked to open oracles at points chosen outside of the protocol, for example to link the
+/// statement of one proof to another.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OracleEvalQuery<F: Field> {
+	/// Identifier of the queried oracle.
+	pub id: OracleId,
+	/// The point to evaluate the oracle at.
+	pub eval_point: Vec<F>,
 }
 
 /// A multilinear polynomial oracle in the polynomial IOP model.
@@ -15,8 +15,6 @@ pub enum Error {
 	CommittedsNotSorted,
 	#[error("transparent polynomials are not sorted in ascending order by number of variables")]
 	TransparentsNotSorted,
-	#[error("committed polynomial witness for oracle {id} is missing packed evaluations")]
-	CommittedPackedEvaluationsMissing { id: OracleId },
 	#[error("invalid committed ID")]
 	InvalidCommittedId { max_index: usize },
 	#[error("invalid transparent ID")]
@@ -1,5 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::borrow::Cow;
+
 use binius_field::{
 	packed::set_packed_slice, BinaryField, Field, PackedExtension, PackedField,
 	PackedFieldIndexable, TowerField,
@@ -12,6 +14,7 @@ use binius_math::{
 use binius_maybe_rayon::{iter::IntoParallelIterator, prelude::*};
 use binius_ntt::{NTTOptions, ThreadingSettings};
 use binius_utils::{bail, sorting::is_sorted_ascending, SerializeBytes};
+use bytemuck::zeroed_vec;
 use either::Either;
 use itertools::{chain, Itertools};
 
@@ -36,50 +39,84 @@ use crate::{
 		},
 	},
 	reed_solomon::reed_solomon::ReedSolomonCode,
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 };
 
+/// Returns the packed evaluations of a committed multilinear.
+///
+/// Multilinears that are not backed by dense evaluation data, like sparse multilinears, have
+/// their packed evaluations materialized from [`MultilinearPoly::subcube_evals`].
+fn committed_packed_evals<P, M>(multilin: &M) -> Result<Cow<'_, [P]>, Error>
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	if let Some(packed_evals) = multilin.packed_evals() {
+		return Ok(Cow::Borrowed(packed_evals));
+	}
+
+	let n_vars = multilin.n_vars();
+	let log_extension_degree = multilin.log_extension_degree();
+	let mut packed_evals =
+		zeroed_vec(1 << n_vars.saturating_sub(log_extension_degree + P::LOG_WIDTH));
+	multilin.subcube_evals(n_vars, 0, log_extension_degree, &mut packed_evals)?;
+	Ok(Cow::Owned(packed_evals))
+}
+
+// Writes the packed evaluations of a committed multilinear to `dst`.
+//
+// Multilinears that are not backed by dense evaluation data, like sparse multilinears, write
+// their evaluations directly from their own representation with
+// [`MultilinearPoly::subcube_evals`], without materializing a dense copy first.
+fn write_committed_packed_evals<P, M>(mle: &M, dst: &mut [P])
+where
+	P: PackedField,
+	M: MultilinearPoly<P>,
+{
+	match mle.packed_evals() {
+		Some(packed_evals) => dst.copy_from_slice(packed_evals),
+		None => mle
+			.subcube_evals(mle.n_vars(), 0, mle.log_extension_degree(), dst)
+			.expect("dst has the length of the packed evaluations"),
+	}
+}
+
 // ## Preconditions
 //
 // * all multilinears in `multilins` have at least log_extension_degree packed variables
-// * all multilinears in `multilins` have `packed_evals()` is Some
 // * multilinears are sorted in ascending order by number of packed variables
 // * `message_buffer` is initialized to all zeros
 // * `message_buffer` is larger than the total number of scalars in the multilinears
 fn merge_multilins<P, M>(multilins: &[M], message_buffer: &mut [P])
 where
 	P: PackedField,
-	M: MultilinearPoly<P>,
+	M: MultilinearPoly<P> + Sync,
 {
 	let mut mle_iter = multilins.iter().rev();
 
 	// First copy all the polynomials where the number of elements is a multiple of the packing
 	// width.
 	let get_n_packed_vars = |mle: &M| mle.n_vars() - mle.log_extension_degree();
-	let mut full_packed_mles = Vec::new(); // (evals, corresponding buffer where to copy)
+	let mut full_packed_mles = Vec::new(); // (multilinear, corresponding buffer where to copy)
 	let mut remaining_buffer = message_buffer;
 	for mle in mle_iter.peeking_take_while(|mle| get_n_packed_vars(mle) >= P::LOG_WIDTH) {
-		let evals = mle
-			.packed_evals()
-			.expect("guaranteed by function precondition");
-		let (chunk, rest) = remaining_buffer.split_at_mut(evals.len());
-		full_packed_mles.push((evals, chunk));
+		let (chunk, rest) =
+			remaining_buffer.split_at_mut(1 << (get_n_packed_vars(mle) - P::LOG_WIDTH));
+		full_packed_mles.push((mle, chunk));
 		remaining_buffer = rest;
 	}
-	full_packed_mles.into_par_iter().for_each(|(evals, chunk)| {
-		chunk.copy_from_slice(evals);
+	full_packed_mles.into_par_iter().for_each(|(mle, chunk)| {
+		write_committed_packed_evals(mle, chunk);
 	});
 
 	// Now copy scalars from the remaining multilinears, which have too few elements to copy full
 	// packed elements.
 	let mut scalar_offset = 0;
 	for mle in mle_iter {
-		let evals = mle
-			.packed_evals()
-			.expect("guaranteed by function precondition");
-		let packed_eval = evals[0];
+		let mut packed_eval = [P::default()];
+		write_committed_packed_evals(mle, &mut packed_eval);
 		for i in 0..1 << mle.n_vars() {
-			set_packed_slice(remaining_buffer, scalar_offset, packed_eval.get(i));
+			set_packed_slice(remaining_buffer, scalar_offset, packed_eval[0].get(i));
 			scalar_offset += 1;
 		}
 	}
@@ -99,19 +136,23 @@ where
 /// * `multilins` - a batch of multilinear polynomials to commit. The multilinears provided may be
 ///     defined over subfields of `F`. They must be in ascending order by the number of variables
 ///     in the packed multilinear (ie. number of variables minus log extension degree).
+/// * `backend` - the computation backend used to encode the committed codeword
 #[tracing::instrument("piop::commit", skip_all)]
-pub fn commit<F, FEncode, P, M, MTScheme, MTProver>(
+#[allow(clippy::type_complexity)]
+pub fn commit<F, FEncode, P, M, MTScheme, MTProver, Backend>(
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_prover: &MTProver,
 	multilins: &[M],
-) -> Result<fri::CommitOutput<P, MTScheme::Digest, MTProver::Committed>, Error>
+	backend: &Backend,
+) -> Result<fri::CommitOutput<Backend::Vec<P>, MTScheme::Digest, MTProver::Committed>, Error>
 where
 	F: BinaryField,
 	FEncode: BinaryField,
 	P: PackedField<Scalar = F> + PackedExtension<FEncode>,
-	M: MultilinearPoly<P>,
+	M: MultilinearPoly<P> + Sync,
 	MTScheme: MerkleTreeScheme<F>,
 	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
+	Backend: ComputationBackend,
 {
 	for (i, multilin) in multilins.iter().enumerate() {
 		if multilin.n_vars() < multilin.log_extension_degree() {
@@ -122,9 +163,6 @@ where
 				min_vars: multilin.log_extension_degree(),
 			});
 		}
-		if multilin.packed_evals().is_none() {
-			return Err(Error::CommittedPackedEvaluationsMissing { id: i });
-		}
 	}
 
 	let n_packed_vars = multilins
@@ -141,12 +179,16 @@ where
 		&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		},
 	)?;
-	let output =
-		fri::commit_interleaved_with(&rs_code, fri_params, merkle_prover, |message_buffer| {
-			merge_multilins(multilins, message_buffer)
-		})?;
+	let output = fri::commit_interleaved_with(
+		&rs_code,
+		fri_params,
+		merkle_prover,
+		|message_buffer| merge_multilins(multilins, message_buffer),
+		backend,
+	)?;
 
 	Ok(output)
 }
@@ -195,13 +237,13 @@ where
 	// The committed multilinears provided by argument are committed *small field* multilinears.
 	// Create multilinears representing the packed polynomials here. Eventually, we would like to
 	// refactor the calling code so that the PIOP only handles *big field* multilinear witnesses.
-	let packed_committed_multilins = committed_multilins
+	let committed_packed_evals = committed_multilins
 		.iter()
-		.enumerate()
-		.map(|(i, committed_multilin)| {
-			let packed_evals = committed_multilin
-				.packed_evals()
-				.ok_or(Error::CommittedPackedEvaluationsMissing { id: i })?;
+		.map(committed_packed_evals)
+		.collect::<Result<Vec<_>, _>>()?;
+	let packed_committed_multilins = committed_packed_evals
+		.iter()
+		.map(|packed_evals| {
 			let packed_multilin = MultilinearExtension::from_values_slice(packed_evals)?;
 			Ok::<_, Error>(MLEDirectAdapter::from(packed_multilin))
 		})
@@ -242,12 +284,14 @@ where
 		codeword,
 		&committed,
 		transcript,
+		backend,
 	)?;
 
 	Ok(())
 }
 
-fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_>(
+#[allow(clippy::too_many_arguments)]
+fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_, Backend>(
 	n_rounds: usize,
 	fri_params: &FRIParams<F, FEncode>,
 	merkle_prover: &MTProver,
@@ -255,6 +299,7 @@ fn prove_interleaved_fri_sumcheck<F, FEncode, P, MTScheme, MTProver, Challenger_
 	codeword: &[P],
 	committed: &MTProver::Committed,
 	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
 ) -> Result<(), Error>
 where
 	F: TowerField,
@@ -263,9 +308,12 @@ where
 	MTScheme: MerkleTreeScheme<F, Digest: SerializeBytes>,
 	MTProver: MerkleTreeProver<F, Scheme = MTScheme>,
 	Challenger_: Challenger,
+	Backend: ComputationBackend,
 {
+	transcript.phase(labels::FRI_SUMCHECK);
+
 	let mut fri_prover =
-		FRIFolder::new(fri_params, merkle_prover, P::unpack_scalars(codeword), committed)?;
+		FRIFolder::new(fri_params, merkle_prover, P::unpack_scalars(codeword), committed, backend)?;
 
 	let mut sumcheck_batch_prover = SumcheckBatchProver::new(sumcheck_provers, transcript)?;
 
@@ -297,16 +345,13 @@ where
 	P: PackedField<Scalar = F>,
 	M: MultilinearPoly<P> + Send + Sync,
 {
-	let packed_committed = committed_multilins
+	let committed_packed_evals = committed_multilins
 		.iter()
-		.enumerate()
-		.map(|(i, unpacked_committed)| {
-			let packed_evals = unpacked_committed
-				.packed_evals()
-				.ok_or(Error::CommittedPackedEvaluationsMissing { id: i })?;
-			let packed_committed = MultilinearExtension::from_values_slice(packed_evals)?;
-			Ok::<_, Error>(packed_committed)
-		})
+		.map(committed_packed_evals)
+		.collect::<Result<Vec<_>, _>>()?;
+	let packed_committed = committed_packed_evals
+		.iter()
+		.map(|packed_evals| MultilinearExtension::from_values_slice(packed_evals))
 		.collect::<Result<Vec<_>, _>>()?;
 
 	for (i, claim) in claims.iter().enumerate() {
@@ -134,7 +134,7 @@ fn commit_prove_verify<F, FDomain, FEncode, P, MTScheme>(
 		commitment,
 		committed,
 		codeword,
-	} = commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
+	} = commit(&fri_params, merkle_prover, &committed_multilins, &backend).unwrap();
 
 	let transparent_multilins_by_vars = commit_meta
 		.n_multilins_by_vars()
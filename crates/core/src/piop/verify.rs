@@ -23,7 +23,7 @@ use crate::{
 		},
 	},
 	reed_solomon::reed_solomon::ReedSolomonCode,
-	transcript::VerifierTranscript,
+	transcript::{labels, VerifierTranscript},
 };
 
 /// Metadata about a batch of committed multilinear polynomials.
@@ -413,6 +413,10 @@ where
 	Challenger_: Challenger,
 	MTScheme: MerkleTreeScheme<F, Digest: DeserializeBytes>,
 {
+	proof
+		.phase(labels::FRI_SUMCHECK)
+		.map_err(VerificationError::Transcript)?;
+
 	let mut arities_iter = fri_params.fold_arities().iter();
 	let mut fri_commitments = Vec::with_capacity(fri_params.n_oracles());
 	let mut next_commit_round = arities_iter.next().copied();
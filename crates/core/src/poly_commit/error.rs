@@ -0,0 +1,39 @@
+// Copyright 2025 Irreducible Inc.
+
+use crate::{oracle::OracleId, piop, ring_switch, transcript};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the batch must contain at least one polynomial")]
+	EmptyBatch,
+	#[error(
+		"polynomial {index} has tower level {tower_level}, which exceeds the maximum of {max}"
+	)]
+	TowerLevelTooHigh {
+		index: usize,
+		tower_level: usize,
+		max: usize,
+	},
+	#[error("expected {expected} multilinears, got {actual}")]
+	IncorrectNumberOfMultilinears { expected: usize, actual: usize },
+	#[error("multilinear {index} does not match the shape of the committed batch")]
+	MultilinearShapeMismatch { index: usize },
+	#[error(
+		"evaluation claim {index} references polynomial {poly_index}, which is not in the batch"
+	)]
+	InvalidPolyIndex { index: usize, poly_index: OracleId },
+	#[error("evaluation claim {index} has a point with {actual} coordinates, expected {expected}")]
+	IncorrectEvalPointLength {
+		index: usize,
+		expected: usize,
+		actual: usize,
+	},
+	#[error("binius_math error: {0}")]
+	Math(#[from] binius_math::Error),
+	#[error("PIOP compiler error: {0}")]
+	PIOP(#[from] piop::Error),
+	#[error("ring switch error: {0}")]
+	RingSwitch(#[from] ring_switch::Error),
+	#[error("transcript error: {0}")]
+	Transcript(#[from] transcript::Error),
+}
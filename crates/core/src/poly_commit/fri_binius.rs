@@ -0,0 +1,382 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::marker::PhantomData;
+
+use binius_field::{PackedExtension, PackedFieldIndexable, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{EvaluationDomainFactory, MultilinearPoly, MultilinearQuery};
+use binius_utils::{bail, sparse_index::SparseIndex, DeserializeBytes, SerializeBytes};
+use either::Either;
+
+use super::{error::Error, EvalClaim, PolyCommitScheme};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
+	oracle::MultilinearOracleSet,
+	piop::{self, CommitMeta},
+	protocols::{
+		evalcheck::EvalcheckMultilinearClaim,
+		fri::{CommitOutput, FRIParams},
+	},
+	ring_switch::{self, EvalClaimSystem, ReducedClaim, ReducedWitness},
+	tower::{PackedTop, TowerFamily},
+	transcript::{labels, ProverTranscript, ScalarBufMut, TranscriptWriter, VerifierTranscript},
+};
+
+type FExt<Tower> = <Tower as TowerFamily>::B128;
+type FDomain<Tower> = <Tower as TowerFamily>::B8;
+type FEncode<Tower> = <Tower as TowerFamily>::B32;
+
+/// The shape of one polynomial in a committed batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CommittedPolyInfo {
+	/// Number of variables of the multilinear.
+	pub n_vars: usize,
+	/// Binary logarithm of the degree of the field the multilinear is defined over, ie. its level
+	/// in the binary tower.
+	pub tower_level: usize,
+}
+
+/// Prover-side data for a batch committed with [`FRIPCS`].
+#[derive(Debug)]
+pub struct FRIPCSCommitted<Codeword, Committed> {
+	committed: Committed,
+	codeword: Codeword,
+}
+
+/// The FRI-Binius multilinear polynomial commitment scheme.
+///
+/// Polynomials may be defined over any subfield of the tower's 128-bit field and are committed
+/// together in a single FRI codeword. Evaluation claims are first reduced to sumcheck claims
+/// with ring-switching and then proven with the interleaved sumcheck-FRI protocol.
+///
+/// The evaluation claims are observed by the transcript before they are proven. The final FRI
+/// consistency check covers the whole committed codeword, so every polynomial in the batch must be
+/// opened. Polynomials without an evaluation claim are therefore opened at a random point sampled
+/// after the claims are observed, with the evaluation sent by the prover.
+#[derive(Debug)]
+pub struct FRIPCS<Tower: TowerFamily, DomainFactory, MTProver> {
+	polys: Vec<CommittedPolyInfo>,
+	oracles: MultilinearOracleSet<FExt<Tower>>,
+	commit_meta: CommitMeta,
+	oracle_to_commit_index: SparseIndex<usize>,
+	fri_params: FRIParams<FExt<Tower>, FEncode<Tower>>,
+	merkle_prover: MTProver,
+	domain_factory: DomainFactory,
+	_marker: PhantomData<Tower>,
+}
+
+impl<Tower, DomainFactory, MTProver> FRIPCS<Tower, DomainFactory, MTProver>
+where
+	Tower: TowerFamily,
+	MTProver: MerkleTreeProver<FExt<Tower>>,
+{
+	/// Instantiates the scheme for batches of the given shape.
+	///
+	/// ## Arguments
+	///
+	/// * `polys` - the shapes of the polynomials in a batch, in the order they will be committed
+	/// * `merkle_prover` - the Merkle tree prover used in FRI
+	/// * `domain_factory` - the evaluation domain factory used by the opening sumchecks
+	/// * `security_bits` - the target security level of the FRI proximity test
+	/// * `log_inv_rate` - the binary logarithm of the inverse Reed–Solomon code rate
+	pub fn new(
+		polys: impl IntoIterator<Item = CommittedPolyInfo>,
+		merkle_prover: MTProver,
+		domain_factory: DomainFactory,
+		security_bits: usize,
+		log_inv_rate: usize,
+	) -> Result<Self, Error> {
+		let polys = polys.into_iter().collect::<Vec<_>>();
+		if polys.is_empty() {
+			bail!(Error::EmptyBatch);
+		}
+
+		// The batch is described as a set of committed oracles so that it can be indexed with
+		// the same helpers as a constraint system trace. Oracle IDs are the batch indices.
+		let mut oracles = MultilinearOracleSet::new();
+		for (index, poly) in polys.iter().enumerate() {
+			if poly.tower_level > FExt::<Tower>::TOWER_LEVEL {
+				bail!(Error::TowerLevelTooHigh {
+					index,
+					tower_level: poly.tower_level,
+					max: FExt::<Tower>::TOWER_LEVEL,
+				});
+			}
+			oracles.add_committed(poly.n_vars, poly.tower_level);
+		}
+
+		let (commit_meta, oracle_to_commit_index) = piop::make_oracle_commit_meta(&oracles)?;
+		let fri_params = piop::make_commit_params_with_optimal_arity::<_, FEncode<Tower>, _>(
+			&commit_meta,
+			merkle_prover.scheme(),
+			security_bits,
+			log_inv_rate,
+		)?;
+
+		Ok(Self {
+			polys,
+			oracles,
+			commit_meta,
+			oracle_to_commit_index,
+			fri_params,
+			merkle_prover,
+			domain_factory,
+			_marker: PhantomData,
+		})
+	}
+
+	/// Returns the shapes of the polynomials in a batch.
+	pub fn polys(&self) -> &[CommittedPolyInfo] {
+		&self.polys
+	}
+
+	/// Returns the FRI parameters of the commitment.
+	pub const fn fri_params(&self) -> &FRIParams<FExt<Tower>, FEncode<Tower>> {
+		&self.fri_params
+	}
+
+	/// Checks that the multilinears match the batch shape and reorders them into commitment
+	/// order.
+	fn committed_order<'a, M, P>(&self, multilins: &'a [M]) -> Result<Vec<&'a M>, Error>
+	where
+		M: MultilinearPoly<P>,
+		P: PackedFieldIndexable<Scalar = FExt<Tower>>,
+	{
+		if multilins.len() != self.polys.len() {
+			bail!(Error::IncorrectNumberOfMultilinears {
+				expected: self.polys.len(),
+				actual: multilins.len(),
+			});
+		}
+
+		let mut ordered = vec![None; multilins.len()];
+		for (index, (multilin, poly)) in multilins.iter().zip(&self.polys).enumerate() {
+			if multilin.n_vars() != poly.n_vars
+				|| multilin.log_extension_degree() != FExt::<Tower>::TOWER_LEVEL - poly.tower_level
+			{
+				bail!(Error::MultilinearShapeMismatch { index });
+			}
+			let commit_idx = *self
+				.oracle_to_commit_index
+				.get(index)
+				.expect("every oracle in the batch is committed");
+			ordered[commit_idx] = Some(multilin);
+		}
+		Ok(ordered
+			.into_iter()
+			.map(|multilin| multilin.expect("the commit index is a bijection"))
+			.collect())
+	}
+
+	/// Checks the evaluation claims against the batch shape and converts them to evalcheck claims.
+	///
+	/// Also returns the batch indices of the polynomials without an evaluation claim.
+	#[allow(clippy::type_complexity)]
+	fn make_eval_claims(
+		&self,
+		claims: &[EvalClaim<FExt<Tower>>],
+	) -> Result<(Vec<EvalcheckMultilinearClaim<FExt<Tower>>>, Vec<usize>), Error> {
+		let mut claimed = vec![false; self.polys.len()];
+		let eval_claims = claims
+			.iter()
+			.enumerate()
+			.map(|(index, claim)| {
+				let Some(poly) = self.polys.get(claim.index) else {
+					bail!(Error::InvalidPolyIndex {
+						index,
+						poly_index: claim.index,
+					});
+				};
+				if claim.point.len() != poly.n_vars {
+					bail!(Error::IncorrectEvalPointLength {
+						index,
+						expected: poly.n_vars,
+						actual: claim.point.len(),
+					});
+				}
+				claimed[claim.index] = true;
+				Ok(EvalcheckMultilinearClaim {
+					id: claim.index,
+					eval_point: claim.point.as_slice().into(),
+					eval: claim.eval,
+				})
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let unclaimed = (0..self.polys.len())
+			.filter(|&index| !claimed[index])
+			.collect();
+		Ok((eval_claims, unclaimed))
+	}
+}
+
+/// Observes the evaluation claims, so that the challenges of the opening proof depend on them.
+fn observe_claims<F: TowerField>(
+	mut observer: TranscriptWriter<impl ScalarBufMut>,
+	claims: &[EvalClaim<F>],
+) {
+	for claim in claims {
+		observer.write(&claim.index);
+		observer.write_scalar_slice(&claim.point);
+		observer.write_scalar(claim.eval);
+	}
+}
+
+impl<Tower, P, DomainFactory, MTScheme, MTProver> PolyCommitScheme<P>
+	for FRIPCS<Tower, DomainFactory, MTProver>
+where
+	Tower: TowerFamily,
+	FExt<Tower>: PackedTop<Tower>,
+	P: PackedFieldIndexable<Scalar = FExt<Tower>>
+		+ PackedExtension<FExt<Tower>, PackedSubfield = P>
+		+ PackedExtension<FDomain<Tower>>
+		+ PackedExtension<FEncode<Tower>>,
+	DomainFactory: EvaluationDomainFactory<FDomain<Tower>>,
+	MTScheme: MerkleTreeScheme<FExt<Tower>, Digest: SerializeBytes + DeserializeBytes>,
+	MTProver: MerkleTreeProver<FExt<Tower>, Scheme = MTScheme>,
+{
+	type Commitment = MTScheme::Digest;
+	type Committed<Backend: ComputationBackend> =
+		FRIPCSCommitted<Backend::Vec<P>, MTProver::Committed>;
+	type Error = Error;
+
+	fn n_polys(&self) -> usize {
+		self.polys.len()
+	}
+
+	fn commit<M, Backend>(
+		&self,
+		multilins: &[M],
+		backend: &Backend,
+	) -> Result<(Self::Commitment, Self::Committed<Backend>), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Backend: ComputationBackend,
+	{
+		let committed_multilins = self.committed_order::<_, P>(multilins)?;
+		let CommitOutput {
+			commitment,
+			committed,
+			codeword,
+		} = piop::commit(&self.fri_params, &self.merkle_prover, &committed_multilins, backend)?;
+		Ok((
+			commitment,
+			FRIPCSCommitted {
+				committed,
+				codeword,
+			},
+		))
+	}
+
+	fn prove_evaluation<M, Challenger_, Backend>(
+		&self,
+		transcript: &mut ProverTranscript<Challenger_>,
+		committed: Self::Committed<Backend>,
+		multilins: &[M],
+		claims: &[EvalClaim<FExt<Tower>>],
+		backend: &Backend,
+	) -> Result<(), Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		Backend: ComputationBackend,
+	{
+		let committed_multilins = self.committed_order::<_, P>(multilins)?;
+		let (mut eval_claims, unclaimed) = self.make_eval_claims(claims)?;
+		transcript.phase(labels::FRI_BINIUS_EVALUATION);
+		observe_claims(transcript.observe(), claims);
+		for index in unclaimed {
+			let eval_point = transcript.sample_vec(self.polys[index].n_vars);
+			let query = MultilinearQuery::<P>::expand(&eval_point);
+			let eval = multilins[index].evaluate(query.to_ref())?;
+			transcript.message().write_scalar(eval);
+			eval_claims.push(EvalcheckMultilinearClaim {
+				id: index,
+				eval_point: eval_point.into(),
+				eval,
+			});
+		}
+		let system = EvalClaimSystem::new(
+			&self.oracles,
+			&self.commit_meta,
+			&self.oracle_to_commit_index,
+			&eval_claims,
+		)?;
+
+		let ReducedWitness {
+			transparents,
+			sumcheck_claims,
+		} = ring_switch::prove::<_, _, _, Tower, _, _>(
+			&system,
+			&committed_multilins,
+			transcript,
+			backend,
+		)?;
+
+		let committed_multilins = committed_multilins
+			.into_iter()
+			.map(Either::Left)
+			.collect::<Vec<_>>();
+		let transparent_multilins = transparents.iter().map(Either::Right).collect::<Vec<_>>();
+		piop::prove::<_, FDomain<Tower>, _, _, _, _, _, _, _, _>(
+			&self.fri_params,
+			&self.merkle_prover,
+			self.domain_factory.clone(),
+			&self.commit_meta,
+			committed.committed,
+			&committed.codeword,
+			&committed_multilins,
+			&transparent_multilins,
+			&sumcheck_claims,
+			transcript,
+			backend,
+		)?;
+		Ok(())
+	}
+
+	fn verify_evaluation<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		commitment: &Self::Commitment,
+		claims: &[EvalClaim<FExt<Tower>>],
+	) -> Result<(), Error>
+	where
+		Challenger_: Challenger,
+	{
+		let (mut eval_claims, unclaimed) = self.make_eval_claims(claims)?;
+		transcript.phase(labels::FRI_BINIUS_EVALUATION)?;
+		observe_claims(transcript.observe(), claims);
+		for index in unclaimed {
+			let eval_point = transcript.sample_vec(self.polys[index].n_vars);
+			let eval = transcript.message().read_scalar()?;
+			eval_claims.push(EvalcheckMultilinearClaim {
+				id: index,
+				eval_point: eval_point.into(),
+				eval,
+			});
+		}
+		let system = EvalClaimSystem::new(
+			&self.oracles,
+			&self.commit_meta,
+			&self.oracle_to_commit_index,
+			&eval_claims,
+		)?;
+
+		let ReducedClaim {
+			transparents,
+			sumcheck_claims,
+		} = ring_switch::verify::<_, Tower, _>(&system, transcript)?;
+
+		piop::verify(
+			&self.commit_meta,
+			self.merkle_prover.scheme(),
+			&self.fri_params,
+			commitment,
+			&transparents,
+			&sumcheck_claims,
+			transcript,
+		)?;
+		Ok(())
+	}
+}
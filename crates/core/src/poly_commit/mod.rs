@@ -0,0 +1,108 @@
+// Copyright 2025 Irreducible Inc.
+
+//! A standalone multilinear polynomial commitment scheme.
+//!
+//! The FRI-Binius protocol from [DP24] is used inside [`crate::constraint_system::prove`] as a
+//! compiler from multilinear polynomial IOPs to interactive protocols. This module exposes the
+//! same machinery as a conventional polynomial commitment scheme, so that protocols that are not
+//! expressed as a [`crate::constraint_system::ConstraintSystem`] can commit to multilinears over
+//! small binary tower fields and prove their evaluations at arbitrary extension field points.
+//!
+//! The scheme is described by the [`PolyCommitScheme`] trait and implemented by [`FRIPCS`], which
+//! composes the ring-switching reduction in [`crate::ring_switch`] with the interleaved
+//! sumcheck-FRI opening protocol in [`crate::piop`].
+//!
+//! [DP24]: <https://eprint.iacr.org/2024/504>
+
+mod error;
+mod fri_binius;
+#[cfg(test)]
+mod tests;
+
+use binius_field::{Field, PackedField};
+use binius_hal::ComputationBackend;
+use binius_math::MultilinearPoly;
+pub use error::*;
+pub use fri_binius::*;
+
+use crate::{
+	fiat_shamir::Challenger,
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// A claim that a polynomial in a committed batch evaluates to `eval` at `point`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EvalClaim<F: Field> {
+	/// Index of the polynomial in the committed batch.
+	pub index: usize,
+	/// The evaluation point, with one coordinate per variable of the polynomial.
+	pub point: Vec<F>,
+	/// The claimed evaluation.
+	pub eval: F,
+}
+
+/// A polynomial commitment scheme for batches of multilinear polynomials.
+///
+/// The shape of the batch, meaning the number of polynomials, their number of variables and the
+/// fields they are defined over, is fixed when the scheme is instantiated. The polynomials are
+/// given to the prover embedded into the packed field `P`.
+///
+/// The scheme does not write the commitment to the transcript. The caller must send the
+/// commitment as a prover message, or otherwise observe it, before the evaluation points of the
+/// claims are sampled. The evaluation claims themselves are observed by
+/// [`Self::prove_evaluation`] and [`Self::verify_evaluation`].
+pub trait PolyCommitScheme<P: PackedField> {
+	type Commitment: Clone;
+	/// Prover-side commitment data, which may hold buffers allocated by the computation backend.
+	type Committed<Backend: ComputationBackend>;
+	type Error: std::error::Error + Send + Sync + 'static;
+
+	/// Returns the number of polynomials in a committed batch.
+	fn n_polys(&self) -> usize;
+
+	/// Commits a batch of multilinear polynomials.
+	///
+	/// Returns the commitment, which is sent to the verifier, and the prover-side data required
+	/// to later open it.
+	#[allow(clippy::type_complexity)]
+	fn commit<M, Backend>(
+		&self,
+		multilins: &[M],
+		backend: &Backend,
+	) -> Result<(Self::Commitment, Self::Committed<Backend>), Self::Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Backend: ComputationBackend;
+
+	/// Proves a batch of evaluation claims on the committed polynomials.
+	///
+	/// ## Arguments
+	///
+	/// * `transcript` - the prover transcript
+	/// * `committed` - the prover-side commitment data returned by [`Self::commit`]
+	/// * `multilins` - the committed multilinears, in the same order as given to [`Self::commit`]
+	/// * `claims` - the evaluation claims to prove
+	/// * `backend` - the computation backend, which must be the one the batch was committed with
+	fn prove_evaluation<M, Challenger_, Backend>(
+		&self,
+		transcript: &mut ProverTranscript<Challenger_>,
+		committed: Self::Committed<Backend>,
+		multilins: &[M],
+		claims: &[EvalClaim<P::Scalar>],
+		backend: &Backend,
+	) -> Result<(), Self::Error>
+	where
+		M: MultilinearPoly<P> + Send + Sync,
+		Challenger_: Challenger,
+		Backend: ComputationBackend;
+
+	/// Verifies a batch of evaluation claims on the committed polynomials.
+	fn verify_evaluation<Challenger_>(
+		&self,
+		transcript: &mut VerifierTranscript<Challenger_>,
+		commitment: &Self::Commitment,
+		claims: &[EvalClaim<P::Scalar>],
+	) -> Result<(), Self::Error>
+	where
+		Challenger_: Challenger;
+}
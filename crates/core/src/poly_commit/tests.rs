@@ -0,0 +1,294 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	arch::OptimalUnderlier128b,
+	as_packed_field::{PackScalar, PackedType},
+	ExtensionField, Field, PackedField,
+};
+use binius_hal::{
+	make_portable_backend, mmap_vec::MmapVec, ComputationBackend, CpuBackend, MmapBackend,
+};
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_math::{
+	DefaultEvaluationDomainFactory, MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly,
+	MultilinearQuery,
+};
+use groestl_crypto::Groestl256;
+use rand::prelude::*;
+
+use super::{CommittedPolyInfo, Error, EvalClaim, PolyCommitScheme, FRIPCS};
+use crate::{
+	fiat_shamir::HasherChallenger,
+	merkle_tree::BinaryMerkleTreeProver,
+	tower::{CanonicalTowerFamily, TowerFamily},
+	transcript::ProverTranscript,
+	witness::MultilinearWitness,
+};
+
+type U = OptimalUnderlier128b;
+type Tower = CanonicalTowerFamily;
+type FExt = <Tower as TowerFamily>::B128;
+type P = PackedType<U, FExt>;
+
+/// The spill threshold of the out-of-core tests, which is small enough for the test buffers to be
+/// stored in memory-mapped files.
+const OUT_OF_CORE_SPILL_THRESHOLD: usize = 1 << 10;
+
+fn generate_multilinear<F>(
+	mut rng: impl Rng,
+	n_vars: usize,
+	mapped: bool,
+) -> MultilinearWitness<'static, P>
+where
+	U: PackScalar<F>,
+	F: Field,
+	FExt: ExtensionField<F>,
+{
+	let data = repeat_with(|| <PackedType<U, F>>::random(&mut rng))
+		.take(1 << n_vars.saturating_sub(<PackedType<U, F>>::LOG_WIDTH))
+		.collect::<Vec<_>>();
+	if mapped {
+		let data = MmapVec::from_vec_with_spill_threshold(data, OUT_OF_CORE_SPILL_THRESHOLD);
+		let mle = MultilinearExtension::new(n_vars, data).unwrap();
+		MLEEmbeddingAdapter::from(mle).upcast_arc_dyn()
+	} else {
+		let mle = MultilinearExtension::new(n_vars, data).unwrap();
+		MLEEmbeddingAdapter::from(mle).upcast_arc_dyn()
+	}
+}
+
+fn generate_batch(
+	mut rng: impl Rng,
+	polys: &[CommittedPolyInfo],
+	mapped: bool,
+) -> Vec<MultilinearWitness<'static, P>> {
+	polys
+		.iter()
+		.map(|poly| match poly.tower_level {
+			0 => generate_multilinear::<<Tower as TowerFamily>::B1>(&mut rng, poly.n_vars, mapped),
+			3 => generate_multilinear::<<Tower as TowerFamily>::B8>(&mut rng, poly.n_vars, mapped),
+			5 => generate_multilinear::<<Tower as TowerFamily>::B32>(&mut rng, poly.n_vars, mapped),
+			7 => generate_multilinear::<FExt>(&mut rng, poly.n_vars, mapped),
+			_ => panic!("unsupported tower level"),
+		})
+		.collect()
+}
+
+fn make_claim(
+	mut rng: impl Rng,
+	index: usize,
+	multilin: &MultilinearWitness<'static, P>,
+) -> EvalClaim<FExt> {
+	let point = repeat_with(|| <FExt as Field>::random(&mut rng))
+		.take(multilin.n_vars())
+		.collect::<Vec<_>>();
+	let eval = multilin
+		.evaluate(MultilinearQuery::expand(&point).to_ref())
+		.unwrap();
+	EvalClaim { index, point, eval }
+}
+
+type TestPCS<Backend> = FRIPCS<
+	Tower,
+	DefaultEvaluationDomainFactory<<Tower as TowerFamily>::B8>,
+	BinaryMerkleTreeProver<FExt, Groestl256, Groestl256ByteCompression, Backend>,
+>;
+
+fn make_pcs(polys: &[CommittedPolyInfo]) -> TestPCS<CpuBackend> {
+	make_pcs_with_backend(polys, make_portable_backend())
+}
+
+fn make_pcs_with_backend<Backend: ComputationBackend>(
+	polys: &[CommittedPolyInfo],
+	backend: Backend,
+) -> TestPCS<Backend> {
+	let merkle_prover = BinaryMerkleTreeProver::<_, Groestl256, _, _>::with_backend(
+		Groestl256ByteCompression,
+		backend,
+	);
+	FRIPCS::new(polys.iter().copied(), merkle_prover, Default::default(), 32, 1).unwrap()
+}
+
+fn test_polys() -> Vec<CommittedPolyInfo> {
+	// Deliberately not in ascending order by number of packed variables.
+	[(10, 3), (8, 0), (9, 5), (8, 7), (10, 0)]
+		.into_iter()
+		.map(|(n_vars, tower_level)| CommittedPolyInfo {
+			n_vars,
+			tower_level,
+		})
+		.collect()
+}
+
+#[test]
+fn test_commit_prove_verify() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs(&polys);
+	let multilins = generate_batch(&mut rng, &polys, false);
+
+	let backend = make_portable_backend();
+	let (commitment, committed) =
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	transcript.message().write(&commitment);
+	// A polynomial may have several claims.
+	let claims = [0, 2, 1, 3, 2, 4]
+		.into_iter()
+		.map(|index| make_claim(&mut rng, index, &multilins[index]))
+		.collect::<Vec<_>>();
+	pcs.prove_evaluation(&mut transcript, committed, &multilins, &claims, &backend)
+		.unwrap();
+
+	let mut transcript = transcript.into_verifier();
+	let commitment = transcript.message().read().unwrap();
+	PolyCommitScheme::<P>::verify_evaluation(&pcs, &mut transcript, &commitment, &claims).unwrap();
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_evaluation_proof_phases_are_labelled() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs(&polys);
+	let multilins = generate_batch(&mut rng, &polys, false);
+
+	let backend = make_portable_backend();
+	let (commitment, committed) =
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	transcript.set_debug(true);
+	transcript.message().write(&commitment);
+	let claims = (0..polys.len())
+		.map(|index| make_claim(&mut rng, index, &multilins[index]))
+		.collect::<Vec<_>>();
+	pcs.prove_evaluation(&mut transcript, committed, &multilins, &claims, &backend)
+		.unwrap();
+	let prover_history = transcript.label_history().to_vec();
+
+	let mut transcript = transcript.into_verifier();
+	transcript.set_debug(true);
+	let commitment = transcript.message().read().unwrap();
+	PolyCommitScheme::<P>::verify_evaluation(&pcs, &mut transcript, &commitment, &claims).unwrap();
+
+	let expected = [
+		"fri_binius_pcs::evaluation",
+		"ring_switch",
+		"piop::fri_sumcheck",
+		"fri::query",
+	];
+	assert_eq!(prover_history, expected);
+	assert_eq!(transcript.label_history(), expected);
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_verify_rejects_incorrect_evaluation() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs(&polys);
+	let multilins = generate_batch(&mut rng, &polys, false);
+
+	let backend = make_portable_backend();
+	let (commitment, committed) =
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	transcript.message().write(&commitment);
+	let mut claims = multilins
+		.iter()
+		.enumerate()
+		.map(|(index, multilin)| make_claim(&mut rng, index, multilin))
+		.collect::<Vec<_>>();
+	pcs.prove_evaluation(&mut transcript, committed, &multilins, &claims, &backend)
+		.unwrap();
+
+	claims[0].eval += FExt::ONE;
+	let mut transcript = transcript.into_verifier();
+	let commitment = transcript.message().read().unwrap();
+	assert!(PolyCommitScheme::<P>::verify_evaluation(&pcs, &mut transcript, &commitment, &claims)
+		.is_err());
+}
+
+#[test]
+fn test_unclaimed_polys_are_opened() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs(&polys);
+	let multilins = generate_batch(&mut rng, &polys, false);
+
+	let backend = make_portable_backend();
+	let (commitment, committed) =
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	transcript.message().write(&commitment);
+	let claims = [0, 1, 2, 4]
+		.into_iter()
+		.map(|index| make_claim(&mut rng, index, &multilins[index]))
+		.collect::<Vec<_>>();
+	pcs.prove_evaluation(&mut transcript, committed, &multilins, &claims, &backend)
+		.unwrap();
+
+	let mut transcript = transcript.into_verifier();
+	let commitment = transcript.message().read().unwrap();
+	PolyCommitScheme::<P>::verify_evaluation(&pcs, &mut transcript, &commitment, &claims).unwrap();
+	transcript.finalize().unwrap();
+}
+
+#[test]
+fn test_commit_rejects_mismatched_batch() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs(&polys);
+	let mut multilins = generate_batch(&mut rng, &polys, false);
+	let backend = make_portable_backend();
+
+	multilins.swap(0, 1);
+	assert!(matches!(
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend),
+		Err(Error::MultilinearShapeMismatch { index: 0 })
+	));
+
+	multilins.pop();
+	assert!(matches!(
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend),
+		Err(Error::IncorrectNumberOfMultilinears {
+			expected: 5,
+			actual: 4
+		})
+	));
+}
+
+#[test]
+fn test_commit_prove_verify_out_of_core() {
+	// Store the committed multilinears, the Merkle trees and every buffer allocated by the
+	// backend that is larger than the threshold in memory-mapped files.
+	let backend = MmapBackend::with_spill_threshold(OUT_OF_CORE_SPILL_THRESHOLD);
+	let mut rng = StdRng::seed_from_u64(0);
+	let polys = test_polys();
+	let pcs = make_pcs_with_backend(&polys, backend.clone());
+	let multilins = generate_batch(&mut rng, &polys, true);
+
+	let (commitment, committed) =
+		PolyCommitScheme::<P>::commit(&pcs, &multilins, &backend).unwrap();
+
+	let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	transcript.message().write(&commitment);
+	let claims = multilins
+		.iter()
+		.enumerate()
+		.map(|(index, multilin)| make_claim(&mut rng, index, multilin))
+		.collect::<Vec<_>>();
+	pcs.prove_evaluation(&mut transcript, committed, &multilins, &claims, &backend)
+		.unwrap();
+
+	let mut transcript = transcript.into_verifier();
+	let commitment = transcript.message().read().unwrap();
+	PolyCommitScheme::<P>::verify_evaluation(&pcs, &mut transcript, &commitment, &claims).unwrap();
+	transcript.finalize().unwrap();
+}
@@ -10,6 +10,8 @@ use stackalloc::{
 	stackalloc_uninit,
 };
 
+use super::compiled_circuit::CompiledCircuit;
+
 /// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
 fn circuit_steps_for_expr<F: Field>(
 	expr: &ArithExpr<F>,
@@ -116,6 +118,20 @@ enum CircuitStep<F: Field> {
 	AddMul(usize, CircuitStepArgument<F>, CircuitStepArgument<F>),
 }
 
+/// Strategy used by [`ArithCircuitPoly`] to evaluate its expression.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitEvaluator {
+	/// Evaluate the circuit steps obtained by walking the expression tree.
+	#[default]
+	Interpreted,
+	/// Evaluate a bytecode compiled from the expression, with common subexpressions eliminated
+	/// and intermediate values allocated to a minimal number of registers.
+	///
+	/// Compilation has a one-time cost at construction, which pays off for large compositions with
+	/// repeated subexpressions that are evaluated many times.
+	Compiled,
+}
+
 /// Describes polynomial evaluations using a directed acyclic graph of expressions.
 ///
 /// This is meant as an alternative to a hard-coded CompositionPoly.
@@ -131,6 +147,8 @@ pub struct ArithCircuitPoly<F: Field> {
 	degree: usize,
 	n_vars: usize,
 	tower_level: usize,
+	/// Compiled form of the expression, present when using [`CircuitEvaluator::Compiled`].
+	compiled: Option<Arc<CompiledCircuit<F>>>,
 }
 
 impl<F: Field> PartialEq for ArithCircuitPoly<F> {
@@ -180,6 +198,7 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 			degree,
 			n_vars,
 			tower_level,
+			compiled: None,
 		}
 	}
 
@@ -205,8 +224,27 @@ impl<F: TowerField> ArithCircuitPoly<F> {
 			n_vars,
 			degree,
 			tower_level,
+			compiled: None,
 		})
 	}
+
+	/// Sets the strategy used to evaluate the polynomial, compiling the expression if necessary.
+	pub fn with_evaluator(mut self, evaluator: CircuitEvaluator) -> Self {
+		self.compiled = match evaluator {
+			CircuitEvaluator::Interpreted => None,
+			CircuitEvaluator::Compiled => Some(Arc::new(CompiledCircuit::new(&self.expr))),
+		};
+		self
+	}
+
+	/// Returns the strategy used to evaluate the polynomial.
+	pub const fn evaluator(&self) -> CircuitEvaluator {
+		if self.compiled.is_some() {
+			CircuitEvaluator::Compiled
+		} else {
+			CircuitEvaluator::Interpreted
+		}
+	}
 }
 
 impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P>
@@ -235,6 +273,10 @@ impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P
 			});
 		}
 
+		if let Some(compiled) = &self.compiled {
+			return Ok(compiled.evaluate(query));
+		}
+
 		fn write_result<T>(target: &mut [MaybeUninit<T>], value: T) {
 			// Safety: The index is guaranteed to be within bounds because
 			// we initialize at least `self.steps.len()` using `stackalloc`.
@@ -304,6 +346,11 @@ impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPoly<P
 			}
 		}
 
+		if let Some(compiled) = &self.compiled {
+			compiled.batch_evaluate(batch_query, evals);
+			return Ok(());
+		}
+
 		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
 		stackalloc_uninit::<P, (), _>((self.steps.len() * row_len).max(1), |sparse_evals| {
 			for (i, expr) in self.steps.iter().enumerate() {
@@ -1127,4 +1174,59 @@ mod tests {
 			"Final result should be stored in Slot(3)"
 		);
 	}
+
+	#[test]
+	fn test_compiled_evaluator() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let x = ArithExpr::<F>::Var(0);
+		let y = ArithExpr::<F>::Var(1);
+		let expr = (x.clone() * y.clone() + ArithExpr::Const(F::new(3))).pow(5) + x * y;
+
+		let interpreted = ArithCircuitPoly::with_n_vars(3, expr).unwrap();
+		let compiled = interpreted
+			.clone()
+			.with_evaluator(CircuitEvaluator::Compiled);
+		assert_eq!(interpreted.evaluator(), CircuitEvaluator::Interpreted);
+		assert_eq!(compiled.evaluator(), CircuitEvaluator::Compiled);
+		assert_eq!(compiled, interpreted);
+
+		let query = [
+			P::broadcast(F::new(7).into()),
+			P::broadcast(F::new(91).into()),
+			P::one(),
+		];
+		assert_eq!(
+			CompositionPoly::<P>::evaluate(&compiled, &query).unwrap(),
+			CompositionPoly::<P>::evaluate(&interpreted, &query).unwrap()
+		);
+		assert!(matches!(
+			CompositionPoly::<P>::evaluate(&compiled, &query[..2]),
+			Err(Error::IncorrectQuerySize { expected: 3 })
+		));
+
+		let columns = [
+			[
+				P::broadcast(F::new(1).into()),
+				P::broadcast(F::new(200).into()),
+			],
+			[
+				P::broadcast(F::new(2).into()),
+				P::broadcast(F::new(17).into()),
+			],
+			[P::zero(), P::one()],
+		];
+		let batch_query = columns
+			.iter()
+			.map(|column| column.as_slice())
+			.collect::<Vec<_>>();
+		let mut expected = [P::zero(); 2];
+		let mut actual = [P::zero(); 2];
+		interpreted
+			.batch_evaluate(&batch_query, &mut expected)
+			.unwrap();
+		compiled.batch_evaluate(&batch_query, &mut actual).unwrap();
+		assert_eq!(actual, expected);
+	}
 }
@@ -0,0 +1,475 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::collections::HashMap;
+
+use binius_field::{ExtensionField, Field, PackedField};
+use binius_math::ArithExpr;
+use stackalloc::stackalloc_with_default;
+
+/// Value in the expression DAG before register allocation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Value<F> {
+	Const(F),
+	Var(usize),
+	/// Result of the node with the given index.
+	Node(usize),
+}
+
+impl<F> Value<F> {
+	/// Ordering key used to canonicalize the operands of commutative operations, so that
+	/// `a * b` and `b * a` are recognized as the same subexpression.
+	const fn sort_key(&self) -> (u8, usize) {
+		match self {
+			Self::Const(_) => (0, 0),
+			Self::Var(index) => (1, *index),
+			Self::Node(index) => (2, *index),
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Node<F> {
+	Add(Value<F>, Value<F>),
+	Mul(Value<F>, Value<F>),
+	Square(Value<F>),
+}
+
+/// Builds a hash-consed DAG out of an expression tree, so that every distinct subexpression is
+/// computed exactly once.
+struct DagBuilder<F> {
+	nodes: Vec<Node<F>>,
+	cache: HashMap<Node<F>, usize>,
+}
+
+impl<F: Field> DagBuilder<F> {
+	fn new() -> Self {
+		Self {
+			nodes: Vec::new(),
+			cache: HashMap::new(),
+		}
+	}
+
+	fn add(&mut self, lhs: Value<F>, rhs: Value<F>) -> Value<F> {
+		match (lhs, rhs) {
+			(Value::Const(lhs), Value::Const(rhs)) => Value::Const(lhs + rhs),
+			_ => self.insert(Node::Add(lhs, rhs)),
+		}
+	}
+
+	fn mul(&mut self, lhs: Value<F>, rhs: Value<F>) -> Value<F> {
+		match (lhs, rhs) {
+			(Value::Const(lhs), Value::Const(rhs)) => Value::Const(lhs * rhs),
+			_ if lhs == rhs => self.square(lhs),
+			_ => self.insert(Node::Mul(lhs, rhs)),
+		}
+	}
+
+	fn square(&mut self, value: Value<F>) -> Value<F> {
+		match value {
+			Value::Const(value) => Value::Const(value.square()),
+			_ => self.insert(Node::Square(value)),
+		}
+	}
+
+	fn insert(&mut self, node: Node<F>) -> Value<F> {
+		let node = match node {
+			Node::Add(lhs, rhs) if rhs.sort_key() < lhs.sort_key() => Node::Add(rhs, lhs),
+			Node::Mul(lhs, rhs) if rhs.sort_key() < lhs.sort_key() => Node::Mul(rhs, lhs),
+			node => node,
+		};
+		let nodes = &mut self.nodes;
+		let index = *self.cache.entry(node).or_insert_with(|| {
+			nodes.push(node);
+			nodes.len() - 1
+		});
+		Value::Node(index)
+	}
+
+	fn lower(&mut self, expr: &ArithExpr<F>) -> Value<F> {
+		match expr {
+			ArithExpr::Const(value) => Value::Const(*value),
+			ArithExpr::Var(index) => Value::Var(*index),
+			ArithExpr::Add(left, right) => {
+				let left = self.lower(left);
+				let right = self.lower(right);
+				self.add(left, right)
+			}
+			ArithExpr::Mul(left, right) => {
+				let left = self.lower(left);
+				let right = self.lower(right);
+				self.mul(left, right)
+			}
+			ArithExpr::Pow(base, exp) => {
+				let base = self.lower(base);
+				if *exp == 0 {
+					return Value::Const(F::ONE);
+				}
+
+				let mut acc = base;
+				for i in (0..exp.ilog2()).rev() {
+					acc = self.square(acc);
+					if (exp >> i) & 1 != 0 {
+						acc = self.mul(acc, base);
+					}
+				}
+				acc
+			}
+		}
+	}
+}
+
+/// Operand of a bytecode instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operand<F> {
+	Const(F),
+	Var(usize),
+	Reg(usize),
+}
+
+/// Bytecode instruction writing its result into the `dst` register.
+///
+/// The destination register may coincide with one of the operand registers, when this instruction
+/// is the last use of that operand, so all operands must be read before the result is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Instruction<F> {
+	Add {
+		dst: usize,
+		lhs: Operand<F>,
+		rhs: Operand<F>,
+	},
+	Mul {
+		dst: usize,
+		lhs: Operand<F>,
+		rhs: Operand<F>,
+	},
+	Square {
+		dst: usize,
+		arg: Operand<F>,
+	},
+}
+
+/// Arithmetic expression compiled to a register-allocated bytecode.
+///
+/// Compilation eliminates common subexpressions, folds constants, and assigns the intermediate
+/// values to registers with a linear scan over their live ranges, which keeps the working set of
+/// the evaluation small for large compositions.
+#[derive(Debug)]
+pub(super) struct CompiledCircuit<F> {
+	instructions: Vec<Instruction<F>>,
+	n_registers: usize,
+	result: Operand<F>,
+}
+
+impl<F: Field> CompiledCircuit<F> {
+	pub fn new(expr: &ArithExpr<F>) -> Self {
+		let mut dag = DagBuilder::new();
+		let result = dag.lower(&expr.optimize());
+		let nodes = dag.nodes;
+
+		// Index of the last node reading each node's value.
+		let mut last_use = vec![0; nodes.len()];
+		for (i, node) in nodes.iter().enumerate() {
+			let (lhs, rhs) = match *node {
+				Node::Add(lhs, rhs) | Node::Mul(lhs, rhs) => (lhs, Some(rhs)),
+				Node::Square(arg) => (arg, None),
+			};
+			for value in [Some(lhs), rhs].into_iter().flatten() {
+				if let Value::Node(j) = value {
+					last_use[j] = i;
+				}
+			}
+		}
+		if let Value::Node(j) = result {
+			last_use[j] = usize::MAX;
+		}
+
+		let mut registers = vec![0; nodes.len()];
+		let mut free_registers = Vec::new();
+		let mut n_registers = 0;
+		let mut instructions = Vec::with_capacity(nodes.len());
+
+		for (i, node) in nodes.iter().enumerate() {
+			let operand = |value: Value<F>| match value {
+				Value::Const(value) => Operand::Const(value),
+				Value::Var(index) => Operand::Var(index),
+				Value::Node(j) => Operand::Reg(registers[j]),
+			};
+			let (lhs, rhs) = match *node {
+				Node::Add(lhs, rhs) | Node::Mul(lhs, rhs) => (lhs, Some(rhs)),
+				Node::Square(arg) => (arg, None),
+			};
+			let (lhs_operand, rhs_operand) = (operand(lhs), rhs.map(operand));
+
+			// Release the registers of operands that die here before allocating the destination,
+			// allowing the result to overwrite one of them.
+			for (k, value) in [Some(lhs), rhs].into_iter().flatten().enumerate() {
+				if let Value::Node(j) = value {
+					if last_use[j] == i && !(k == 1 && Some(lhs) == rhs) {
+						free_registers.push(registers[j]);
+					}
+				}
+			}
+			let dst = free_registers.pop().unwrap_or_else(|| {
+				n_registers += 1;
+				n_registers - 1
+			});
+			registers[i] = dst;
+
+			instructions.push(match (node, rhs_operand) {
+				(Node::Add(..), Some(rhs)) => Instruction::Add {
+					dst,
+					lhs: lhs_operand,
+					rhs,
+				},
+				(Node::Mul(..), Some(rhs)) => Instruction::Mul {
+					dst,
+					lhs: lhs_operand,
+					rhs,
+				},
+				(Node::Square(_), None) => Instruction::Square {
+					dst,
+					arg: lhs_operand,
+				},
+				_ => unreachable!("binary nodes have two operands and unary nodes have one"),
+			});
+		}
+
+		let result = match result {
+			Value::Const(value) => Operand::Const(value),
+			Value::Var(index) => Operand::Var(index),
+			Value::Node(j) => Operand::Reg(registers[j]),
+		};
+
+		Self {
+			instructions,
+			n_registers,
+			result,
+		}
+	}
+
+	#[cfg(test)]
+	fn n_instructions(&self) -> usize {
+		self.instructions.len()
+	}
+
+	#[cfg(test)]
+	const fn n_registers(&self) -> usize {
+		self.n_registers
+	}
+
+	/// Evaluate the bytecode at a single point.
+	///
+	/// The caller must ensure that `query` holds a value for every variable of the expression.
+	pub fn evaluate<P: PackedField<Scalar: ExtensionField<F>>>(&self, query: &[P]) -> P {
+		let load = |operand: Operand<F>, registers: &[P]| match operand {
+			Operand::Const(value) => P::broadcast(value.into()),
+			Operand::Var(index) => query[index],
+			Operand::Reg(reg) => registers[reg],
+		};
+
+		// `stackalloc` throws a debug assert if `size` is 0, so set minimum of 1.
+		stackalloc_with_default::<P, _, _>(self.n_registers.max(1), |registers| {
+			for instruction in &self.instructions {
+				match *instruction {
+					Instruction::Add { dst, lhs, rhs } => {
+						registers[dst] = load(lhs, registers) + load(rhs, registers);
+					}
+					Instruction::Mul { dst, lhs, rhs } => {
+						registers[dst] = load(lhs, registers) * load(rhs, registers);
+					}
+					Instruction::Square { dst, arg } => {
+						registers[dst] = load(arg, registers).square();
+					}
+				}
+			}
+			load(self.result, registers)
+		})
+	}
+
+	/// Evaluate the bytecode over a batch of points, writing the results into `evals`.
+	///
+	/// The caller must ensure that `batch_query` holds a column for every variable of the
+	/// expression and that every column has the same length as `evals`.
+	pub fn batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) {
+		let row_len = evals.len();
+
+		// `stackalloc` throws a debug assert if `size` is 0, so set minimum of 1.
+		stackalloc_with_default::<P, _, _>((self.n_registers * row_len).max(1), |registers| {
+			for instruction in &self.instructions {
+				match *instruction {
+					Instruction::Add { dst, lhs, rhs } => {
+						apply_binary_op(lhs, rhs, dst, batch_query, registers, row_len, |x, y| {
+							x + y
+						})
+					}
+					Instruction::Mul { dst, lhs, rhs } => {
+						apply_binary_op(lhs, rhs, dst, batch_query, registers, row_len, |x, y| {
+							x * y
+						})
+					}
+					Instruction::Square { dst, arg } => apply_binary_op(
+						arg,
+						Operand::Const(F::ZERO),
+						dst,
+						batch_query,
+						registers,
+						row_len,
+						|x, _| x.square(),
+					),
+				}
+			}
+
+			match self.result {
+				Operand::Const(value) => evals.fill(P::broadcast(value.into())),
+				Operand::Var(index) => evals.copy_from_slice(batch_query[index]),
+				Operand::Reg(reg) => {
+					evals.copy_from_slice(&registers[reg * row_len..(reg + 1) * row_len])
+				}
+			}
+		});
+	}
+}
+
+/// Apply `op` elementwise to two operand columns, storing the result in the `dst` register.
+///
+/// The destination register may alias an operand register, which is sound because each element
+/// is read before the corresponding element of the destination is written.
+fn apply_binary_op<F: Field, P: PackedField<Scalar: ExtensionField<F>>>(
+	lhs: Operand<F>,
+	rhs: Operand<F>,
+	dst: usize,
+	batch_query: &[&[P]],
+	registers: &mut [P],
+	row_len: usize,
+	op: impl Fn(P, P) -> P,
+) {
+	enum Column<'a, P> {
+		Const(P),
+		Slice(&'a [P]),
+		Reg(usize),
+	}
+
+	let column = |operand: Operand<F>| match operand {
+		Operand::Const(value) => Column::Const(P::broadcast(value.into())),
+		Operand::Var(index) => Column::Slice(batch_query[index]),
+		Operand::Reg(reg) => Column::Reg(reg * row_len),
+	};
+	let (lhs, rhs) = (column(lhs), column(rhs));
+	assert!((dst + 1) * row_len <= registers.len());
+	let registers = registers.as_mut_ptr();
+	// Safety: the destination register lies within `registers`, as asserted above.
+	let dst = unsafe { registers.add(dst * row_len) };
+
+	let load = |column: &Column<'_, P>, j: usize| match column {
+		Column::Const(value) => *value,
+		// Safety: every column of `batch_query` has length `row_len` and `j < row_len`.
+		Column::Slice(slice) => unsafe { *slice.get_unchecked(j) },
+		// Safety: register `reg` spans `row_len` elements of `registers` starting at `offset`.
+		Column::Reg(offset) => unsafe { *registers.add(offset + j) },
+	};
+
+	for j in 0..row_len {
+		let value = op(load(&lhs, j), load(&rhs, j));
+		// Safety: `dst` points to a register of `row_len` elements and `j < row_len`. The operands
+		// for this element have been read above, so an aliased operand register is not clobbered
+		// before it is consumed.
+		unsafe {
+			*dst.add(j) = value;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, PackedBinaryField8x16b, PackedField};
+	use binius_math::CompositionPoly;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::polynomial::ArithCircuitPoly;
+
+	type F = BinaryField8b;
+	type P = PackedBinaryField8x16b;
+
+	fn assert_matches_interpreter(expr: ArithExpr<F>, n_vars: usize) {
+		let mut rng = StdRng::seed_from_u64(0);
+		let interpreted = ArithCircuitPoly::with_n_vars(n_vars, expr.clone()).unwrap();
+		let compiled = CompiledCircuit::new(&expr);
+
+		let query = (0..n_vars).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		assert_eq!(compiled.evaluate(&query), interpreted.evaluate(&query).unwrap());
+
+		let batch = (0..n_vars)
+			.map(|_| (0..5).map(|_| P::random(&mut rng)).collect::<Vec<_>>())
+			.collect::<Vec<_>>();
+		let batch = batch
+			.iter()
+			.map(|column| column.as_slice())
+			.collect::<Vec<_>>();
+		let mut expected = vec![P::zero(); 5];
+		let mut actual = vec![P::zero(); 5];
+		interpreted.batch_evaluate(&batch, &mut expected).unwrap();
+		compiled.batch_evaluate(&batch, &mut actual);
+		assert_eq!(actual, expected);
+	}
+
+	#[test]
+	fn test_common_subexpressions_are_shared() {
+		let x = ArithExpr::<F>::Var(0);
+		let y = ArithExpr::<F>::Var(1);
+		let z = ArithExpr::<F>::Var(2);
+		// Both products are the same subexpression up to commutativity.
+		let expr = (x.clone() * y.clone() + z.clone()) * (y * x + z.clone()) + z;
+
+		let compiled = CompiledCircuit::new(&expr);
+		// x * y, (x * y) + z, square, + z
+		assert_eq!(compiled.n_instructions(), 4);
+		assert_eq!(compiled.n_registers(), 1);
+		assert_matches_interpreter(expr, 3);
+	}
+
+	#[test]
+	fn test_registers_are_reused() {
+		// Sum of independent products only needs two live values at a time.
+		let expr = (0..8)
+			.map(|i| ArithExpr::<F>::Var(2 * i) * ArithExpr::Var(2 * i + 1))
+			.reduce(|acc, term| acc + term)
+			.unwrap();
+
+		let compiled = CompiledCircuit::new(&expr);
+		assert_eq!(compiled.n_instructions(), 15);
+		assert_eq!(compiled.n_registers(), 2);
+		assert_matches_interpreter(expr, 16);
+	}
+
+	#[test]
+	fn test_trivial_expressions() {
+		assert_matches_interpreter(ArithExpr::Const(F::new(123)), 0);
+		assert_matches_interpreter(ArithExpr::Var(1), 2);
+		assert_matches_interpreter(ArithExpr::Var(0).pow(1), 1);
+	}
+
+	#[test]
+	fn test_matches_interpreter() {
+		let x = ArithExpr::<F>::Var(0);
+		let y = ArithExpr::<F>::Var(1);
+		let z = ArithExpr::<F>::Var(2);
+		let w = ArithExpr::<F>::Var(3);
+
+		assert_matches_interpreter(
+			x.clone() * y.clone() + (ArithExpr::Const(F::ONE) - x.clone()) * z.clone() - w.clone(),
+			4,
+		);
+		assert_matches_interpreter(x.clone().pow(254) * y.clone() + z.clone().pow(13), 3);
+		assert_matches_interpreter(
+			(x.clone() * x.clone() + y.clone()).pow(3) * (y + x.clone() * x)
+				+ ArithExpr::Const(F::new(7)) * w.pow(2)
+				+ z,
+			4,
+		);
+	}
+}
@@ -1,6 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 mod arith_circuit;
+mod compiled_circuit;
 mod error;
 mod multivariate;
 #[allow(dead_code)]
@@ -7,12 +7,11 @@ use std::{
 };
 
 use binius_field::{Field, TowerField};
-use bytes::{Buf, BufMut};
 
 use super::error::Error;
 use crate::{
 	oracle::OracleId,
-	transcript::{TranscriptReader, TranscriptWriter},
+	transcript::{ScalarBuf, ScalarBufMut, TranscriptReader, TranscriptWriter},
 };
 
 #[derive(Debug, Clone)]
@@ -91,7 +90,7 @@ impl EvalcheckNumerics {
 }
 
 /// Serializes the `EvalcheckProof` into the transcript
-pub fn serialize_evalcheck_proof<B: BufMut, F: TowerField>(
+pub fn serialize_evalcheck_proof<B: ScalarBufMut, F: TowerField>(
 	transcript: &mut TranscriptWriter<B>,
 	evalcheck: &EvalcheckProof<F>,
 ) {
@@ -133,7 +132,7 @@ pub fn serialize_evalcheck_proof<B: BufMut, F: TowerField>(
 }
 
 /// Deserializes the `EvalcheckProof` object from the given transcript.
-pub fn deserialize_evalcheck_proof<B: Buf, F: TowerField>(
+pub fn deserialize_evalcheck_proof<B: ScalarBuf, F: TowerField>(
 	transcript: &mut TranscriptReader<B>,
 ) -> Result<EvalcheckProof<F>, Error> {
 	let mut ty = 0;
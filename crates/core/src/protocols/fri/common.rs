@@ -2,9 +2,8 @@
 
 use std::marker::PhantomData;
 
-use binius_field::{util::inner_product_unchecked, BinaryField, ExtensionField, PackedField};
-use binius_math::extrapolate_line_scalar;
-use binius_ntt::AdditiveNTT;
+use binius_field::{BinaryField, ExtensionField, PackedField};
+use binius_hal::fri_fold;
 use binius_utils::bail;
 use getset::{CopyGetters, Getters};
 
@@ -13,45 +12,15 @@ use crate::{
 	reed_solomon::reed_solomon::ReedSolomonCode,
 };
 
-/// Calculate fold of `values` at `index` with `r` random coefficient.
-///
-/// See [DP24], Def. 3.6.
-///
-/// [DP24]: <https://eprint.iacr.org/2024/504>
-#[inline]
-fn fold_pair<F, FS>(
-	rs_code: &ReedSolomonCode<FS>,
-	round: usize,
-	index: usize,
-	values: (F, F),
-	r: F,
-) -> F
-where
-	F: BinaryField + ExtensionField<FS>,
-	FS: BinaryField,
-{
-	// Perform inverse additive NTT butterfly
-	let t = rs_code.get_ntt().get_subspace_eval(round, index);
-	let (mut u, mut v) = values;
-	v += u;
-	u += v * t;
-	extrapolate_line_scalar(u, v, r)
-}
-
 /// Calculate FRI fold of `values` at a `chunk_index` with random folding challenges.
 ///
+/// This is [`binius_hal::fri_fold::fold_chunk`] over the NTT of `rs_code`.
+///
 /// REQUIRES:
 /// - `folding_challenges` is not empty.
 /// - `values.len() == 1 << folding_challenges.len()`.
 /// - `scratch_buffer.len() == values.len()`.
 /// - `start_round + folding_challenges.len() - 1 < rs_code.log_dim()`.
-///
-/// NB: This method is on a hot path and does not perform any allocations or
-/// precondition checks.
-///
-/// See [DP24], Def. 3.6 and Lemma 3.9 for more details.
-///
-/// [DP24]: <https://eprint.iacr.org/2024/504>
 #[inline]
 pub fn fold_chunk<F, FS>(
 	rs_code: &ReedSolomonCode<FS>,
@@ -65,64 +34,21 @@ where
 	F: BinaryField + ExtensionField<FS>,
 	FS: BinaryField,
 {
-	// Preconditions
-	debug_assert!(!folding_challenges.is_empty());
 	debug_assert!(start_round + folding_challenges.len() <= rs_code.log_dim());
-	debug_assert_eq!(values.len(), 1 << folding_challenges.len());
-	debug_assert!(scratch_buffer.len() >= values.len());
-
-	// Fold the chunk with the folding challenges one by one
-	for n_challenges_processed in 0..folding_challenges.len() {
-		let n_remaining_challenges = folding_challenges.len() - n_challenges_processed;
-		let scratch_buffer_len = values.len() >> n_challenges_processed;
-		let new_scratch_buffer_len = scratch_buffer_len >> 1;
-		let round = start_round + n_challenges_processed;
-		let r = folding_challenges[n_challenges_processed];
-		let index_start = chunk_index << (n_remaining_challenges - 1);
 
-		// Fold the (2i) and (2i+1)th cells of the scratch buffer in-place into the i-th cell
-		if n_challenges_processed > 0 {
-			(0..new_scratch_buffer_len).for_each(|index_offset| {
-				let values =
-					(scratch_buffer[index_offset << 1], scratch_buffer[(index_offset << 1) + 1]);
-				scratch_buffer[index_offset] =
-					fold_pair(rs_code, round, index_start + index_offset, values, r)
-			});
-		} else {
-			// For the first round, we read values directly from the `values` slice.
-			(0..new_scratch_buffer_len).for_each(|index_offset| {
-				let values = (values[index_offset << 1], values[(index_offset << 1) + 1]);
-				scratch_buffer[index_offset] =
-					fold_pair(rs_code, round, index_start + index_offset, values, r)
-			});
-		}
-	}
-
-	scratch_buffer[0]
+	fri_fold::fold_chunk(
+		rs_code.get_ntt(),
+		start_round,
+		chunk_index,
+		values,
+		folding_challenges,
+		scratch_buffer,
+	)
 }
 
 /// Calculate the fold of an interleaved chunk of values with random folding challenges.
 ///
-/// The elements in the `values` vector are the interleaved cosets of a batch of codewords at the
-/// index `coset_index`. That is, the layout of elements in the values slice is
-///
-/// ```text
-/// [a0, b0, c0, d0, a1, b1, c1, d1, ...]
-/// ```
-///
-/// where `a0, a1, ...` form a coset of a codeword `a`, `b0, b1, ...` form a coset of a codeword
-/// `b`, and similarly for `c` and `d`.
-///
-/// The fold operation first folds the adjacent symbols in the slice using regular multilinear
-/// tensor folding for the symbols from different cosets and FRI folding for the cosets themselves
-/// using the remaining challenges.
-//
-/// NB: This method is on a hot path and does not perform any allocations or
-/// precondition checks.
-///
-/// See [DP24], Def. 3.6 and Lemma 3.9 for more details.
-///
-/// [DP24]: <https://eprint.iacr.org/2024/504>
+/// This is [`binius_hal::fri_fold::fold_interleaved_chunk`] over the NTT of `rs_code`.
 #[inline]
 pub fn fold_interleaved_chunk<F, FS>(
 	rs_code: &ReedSolomonCode<FS>,
@@ -137,28 +63,17 @@ where
 	F: BinaryField + ExtensionField<FS>,
 	FS: BinaryField,
 {
-	// Preconditions
 	debug_assert!(fold_challenges.len() <= rs_code.log_dim());
-	debug_assert_eq!(values.len(), 1 << (log_batch_size + fold_challenges.len()));
-	debug_assert_eq!(tensor.len(), 1 << log_batch_size);
-	debug_assert!(scratch_buffer.len() >= 2 * (values.len() >> log_batch_size));
-
-	// There are two types of mixing we do in this loop. Buffer 1 is populated with the
-	// folding of symbols from the interleaved codewords into a single codeword. These
-	// values are mixed as a regular tensor product combination. Buffer 2 is then
-	// populated with `fold_chunk`, which folds a coset of a codeword using the FRI
-	// folding algorithm.
-	let (buffer1, buffer2) = scratch_buffer.split_at_mut(1 << fold_challenges.len());
 
-	for (interleave_chunk, val) in values.chunks(1 << log_batch_size).zip(buffer1.iter_mut()) {
-		*val = inner_product_unchecked(interleave_chunk.iter().copied(), tensor.iter().copied());
-	}
-
-	if fold_challenges.is_empty() {
-		buffer1[0]
-	} else {
-		fold_chunk(rs_code, 0, chunk_index, buffer1, fold_challenges, buffer2)
-	}
+	fri_fold::fold_interleaved_chunk(
+		rs_code.get_ntt(),
+		log_batch_size,
+		chunk_index,
+		values,
+		tensor,
+		fold_challenges,
+		scratch_buffer,
+	)
 }
 
 /// Parameters for an FRI interleaved code proximity protocol.
@@ -30,6 +30,8 @@ pub enum Error {
 	RoundVCSLengthsNotPowerOfTwo,
 	#[error("Reed-Solomon encoding error: {0}")]
 	EncodeError(#[from] NttError),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
 	#[error("vector commit error: {0}")]
 	VectorCommit(#[source] Box<dyn std::error::Error + Send + Sync>),
 	#[error("verification error: {0}")]
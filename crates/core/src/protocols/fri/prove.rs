@@ -1,11 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+use std::ops::Deref;
+
 use binius_field::{BinaryField, ExtensionField, PackedExtension, PackedField, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackend};
 use binius_maybe_rayon::prelude::*;
 use binius_utils::{bail, SerializeBytes};
-use bytemuck::zeroed_vec;
-use bytes::BufMut;
 use itertools::izip;
 use tracing::instrument;
 
@@ -17,16 +17,19 @@ use super::{
 use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::{MerkleTreeProver, MerkleTreeScheme},
-	protocols::fri::common::{fold_chunk, fold_interleaved_chunk},
 	reed_solomon::reed_solomon::ReedSolomonCode,
-	transcript::{ProverTranscript, TranscriptWriter},
+	transcript::{labels, ProverTranscript, ScalarBufMut, TranscriptWriter},
 };
 
+/// Folds a Reed–Solomon codeword on the CPU.
+///
+/// Deprecated in favor of the backend hook, which lets the computation backend choose where the
+/// folded codeword is stored.
+#[deprecated(note = "use `ComputationBackend::fri_fold_codeword` instead")]
 #[instrument(skip_all, level = "debug")]
 pub fn fold_codeword<F, FS>(
 	rs_code: &ReedSolomonCode<FS>,
 	codeword: &[F],
-	// Round is the number of total folding challenges received so far.
 	round: usize,
 	folding_challenges: &[F],
 ) -> Vec<F>
@@ -39,91 +42,25 @@ where
 	assert!(round >= folding_challenges.len());
 	assert!(round <= rs_code.log_dim());
 
-	if folding_challenges.is_empty() {
-		return codeword.to_vec();
-	}
-
-	let start_round = round - folding_challenges.len();
-	let chunk_size = 1 << folding_challenges.len();
-
-	// For each chunk of size `2^chunk_size` in the codeword, fold it with the folding challenges
-	codeword
-		.par_chunks(chunk_size)
-		.enumerate()
-		.map_init(
-			|| vec![F::default(); chunk_size],
-			|scratch_buffer, (chunk_index, chunk)| {
-				fold_chunk(
-					rs_code,
-					start_round,
-					chunk_index,
-					chunk,
-					folding_challenges,
-					scratch_buffer,
-				)
-			},
+	make_portable_backend()
+		.fri_fold_codeword(
+			rs_code.get_ntt(),
+			codeword,
+			round - folding_challenges.len(),
+			folding_challenges,
 		)
-		.collect()
+		.expect("folding on the CPU backend is infallible")
 }
 
-/// Fold the interleaved codeword into a single codeword with the same block length.
-///
-/// ## Arguments
+/// The output of committing to an interleaved codeword.
 ///
-/// * `rs_code` - the Reed–Solomon code the protocol tests proximity to.
-/// * `codeword` - an interleaved codeword.
-/// * `challenges` - the folding challenges. The length must be at least `log_batch_size`.
-/// * `log_batch_size` - the base-2 logarithm of the batch size of the interleaved code.
-#[instrument(skip_all, level = "debug")]
-fn fold_interleaved<F, FS>(
-	rs_code: &ReedSolomonCode<FS>,
-	codeword: &[F],
-	challenges: &[F],
-	log_batch_size: usize,
-) -> Vec<F>
-where
-	F: BinaryField + ExtensionField<FS>,
-	FS: BinaryField,
-{
-	assert_eq!(codeword.len(), 1 << (rs_code.log_len() + log_batch_size));
-	assert!(challenges.len() >= log_batch_size);
-
-	let backend = make_portable_backend();
-
-	let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
-	let tensor = backend
-		.tensor_product_full_query(interleave_challenges)
-		.expect("number of challenges is less than 32");
-
-	// For each chunk of size `2^chunk_size` in the codeword, fold it with the folding challenges
-	let fold_chunk_size = 1 << fold_challenges.len();
-	let interleave_chunk_size = 1 << log_batch_size;
-	let chunk_size = fold_chunk_size * interleave_chunk_size;
-	codeword
-		.par_chunks(chunk_size)
-		.enumerate()
-		.map_init(
-			|| vec![F::default(); 2 * fold_chunk_size],
-			|scratch_buffer, (i, chunk)| {
-				fold_interleaved_chunk(
-					rs_code,
-					log_batch_size,
-					i,
-					chunk,
-					&tensor,
-					fold_challenges,
-					scratch_buffer,
-				)
-			},
-		)
-		.collect()
-}
-
+/// `Codeword` is the buffer holding the encoded codeword, which is allocated by the computation
+/// backend.
 #[derive(Debug)]
-pub struct CommitOutput<P, VCSCommitment, VCSCommitted> {
+pub struct CommitOutput<Codeword, VCSCommitment, VCSCommitted> {
 	pub commitment: VCSCommitment,
 	pub committed: VCSCommitted,
-	pub codeword: Vec<P>,
+	pub codeword: Codeword,
 }
 
 /// Creates a parallel iterator over scalars of subfield elementsAssumes chunk_size to be a power of two
@@ -166,13 +103,16 @@ where
 /// * `params` - common FRI protocol parameters.
 /// * `merkle_prover` - the merke tree prover to use for committing
 /// * `message` - the interleaved message to encode and commit
+/// * `backend` - the computation backend used for allocating and encoding the codeword
 #[instrument(skip_all, level = "debug")]
-pub fn commit_interleaved<F, FA, P, PA, MerkleProver, VCS>(
+#[allow(clippy::type_complexity)]
+pub fn commit_interleaved<F, FA, P, PA, MerkleProver, VCS, Backend>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message: &[P],
-) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+	backend: &Backend,
+) -> Result<CommitOutput<Backend::Vec<P>, VCS::Digest, MerkleProver::Committed>, Error>
 where
 	F: BinaryField,
 	FA: BinaryField,
@@ -180,6 +120,7 @@ where
 	PA: PackedField<Scalar = FA>,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	let n_elems = rs_code.dim() << params.log_batch_size();
 	if message.len() * P::WIDTH != n_elems {
@@ -188,9 +129,13 @@ where
 		));
 	}
 
-	commit_interleaved_with(rs_code, params, merkle_prover, move |buffer| {
-		buffer.copy_from_slice(message)
-	})
+	commit_interleaved_with(
+		rs_code,
+		params,
+		merkle_prover,
+		move |buffer| buffer.copy_from_slice(message),
+		backend,
+	)
 }
 
 /// Encodes and commits the input message with a closure for writing the message.
@@ -201,13 +146,16 @@ where
 /// * `params` - common FRI protocol parameters.
 /// * `merkle_prover` - the Merkle tree prover to use for committing
 /// * `message_writer` - a closure that writes the interleaved message to encode and commit
+/// * `backend` - the computation backend used for allocating and encoding the codeword
 #[instrument(skip_all, level = "debug")]
-pub fn commit_interleaved_with<F, FA, P, PA, MerkleProver, VCS>(
+#[allow(clippy::type_complexity)]
+pub fn commit_interleaved_with<F, FA, P, PA, MerkleProver, VCS, Backend>(
 	rs_code: &ReedSolomonCode<PA>,
 	params: &FRIParams<F, FA>,
 	merkle_prover: &MerkleProver,
 	message_writer: impl FnOnce(&mut [P]),
-) -> Result<CommitOutput<P, VCS::Digest, MerkleProver::Committed>, Error>
+	backend: &Backend,
+) -> Result<CommitOutput<Backend::Vec<P>, VCS::Digest, MerkleProver::Committed>, Error>
 where
 	F: BinaryField,
 	FA: BinaryField,
@@ -215,6 +163,7 @@ where
 	PA: PackedField<Scalar = FA>,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	let log_batch_size = params.log_batch_size();
 	let log_elems = rs_code.log_dim() + log_batch_size;
@@ -222,10 +171,11 @@ where
 		todo!("can't handle this case well");
 	}
 
-	let mut encoded = tracing::debug_span!("allocate codeword")
-		.in_scope(|| zeroed_vec(1 << (log_elems - P::LOG_WIDTH + rs_code.log_inv_rate())));
+	let mut encoded = tracing::debug_span!("allocate codeword").in_scope(|| {
+		backend.zeroed_hal_slice(1 << (log_elems - P::LOG_WIDTH + rs_code.log_inv_rate()))
+	});
 	message_writer(&mut encoded[..1 << (log_elems - P::LOG_WIDTH)]);
-	rs_code.encode_ext_batch_inplace(&mut encoded, log_batch_size)?;
+	rs_code.encode_ext_batch_inplace(&mut encoded, log_batch_size, backend)?;
 
 	// take the first arity as coset_log_len, or use log_inv_rate if arities are empty
 	let coset_log_len = params
@@ -263,36 +213,42 @@ pub enum FoldRoundOutput<VCSCommitment> {
 }
 
 /// A stateful prover for the FRI fold phase.
-pub struct FRIFolder<'a, F, FA, MerkleProver, VCS>
+pub struct FRIFolder<'a, F, FA, MerkleProver, VCS, Backend>
 where
 	FA: BinaryField,
 	F: BinaryField,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Backend: ComputationBackend,
 {
 	params: &'a FRIParams<F, FA>,
 	merkle_prover: &'a MerkleProver,
+	backend: &'a Backend,
 	codeword: &'a [F],
 	codeword_committed: &'a MerkleProver::Committed,
-	round_committed: Vec<(Vec<F>, MerkleProver::Committed)>,
+	round_committed: Vec<(Backend::Vec<F>, MerkleProver::Committed)>,
 	curr_round: usize,
 	next_commit_round: Option<usize>,
 	unprocessed_challenges: Vec<F>,
 }
 
-impl<'a, F, FA, MerkleProver, VCS> FRIFolder<'a, F, FA, MerkleProver, VCS>
+impl<'a, F, FA, MerkleProver, VCS, Backend> FRIFolder<'a, F, FA, MerkleProver, VCS, Backend>
 where
 	F: TowerField + ExtensionField<FA>,
 	FA: BinaryField,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F, Digest: SerializeBytes>,
+	Backend: ComputationBackend,
 {
 	/// Constructs a new folder.
+	///
+	/// The codewords are folded with the given computation backend.
 	pub fn new(
 		params: &'a FRIParams<F, FA>,
 		merkle_prover: &'a MerkleProver,
 		committed_codeword: &'a [F],
 		committed: &'a MerkleProver::Committed,
+		backend: &'a Backend,
 	) -> Result<Self, Error> {
 		if committed_codeword.len() != 1 << params.log_len() {
 			bail!(Error::InvalidArgs(
@@ -304,6 +260,7 @@ where
 		Ok(Self {
 			params,
 			merkle_prover,
+			backend,
 			codeword: committed_codeword,
 			codeword_committed: committed,
 			round_committed: Vec::with_capacity(params.n_oracles()),
@@ -349,23 +306,31 @@ where
 			Some((prev_codeword, _)) => {
 				// Fold a full codeword committed in the previous FRI round into a codeword with
 				// reduced dimension and rate.
-				fold_codeword(
-					self.params.rs_code(),
+				let round = self.curr_round - self.params.log_batch_size();
+				let n_challenges = self.unprocessed_challenges.len();
+				assert_eq!(prev_codeword.len() % (1 << n_challenges), 0);
+				assert!(round >= n_challenges);
+				assert!(round <= self.params.rs_code().log_dim());
+
+				self.backend.fri_fold_codeword(
+					self.params.rs_code().get_ntt(),
 					prev_codeword,
-					self.curr_round - self.params.log_batch_size(),
+					round - n_challenges,
 					&self.unprocessed_challenges,
-				)
+				)?
 			}
 			None => {
 				// Fold the interleaved codeword that was originally committed into a single
 				// codeword with the same or reduced block length, depending on the sequence of
 				// fold rounds.
-				fold_interleaved(
-					self.params.rs_code(),
+				assert!(self.unprocessed_challenges.len() >= self.params.log_batch_size());
+
+				self.backend.fri_fold_interleaved(
+					self.params.rs_code().get_ntt(),
 					self.codeword,
-					&self.unprocessed_challenges,
 					self.params.log_batch_size(),
-				)
+					&self.unprocessed_challenges,
+				)?
 			}
 		};
 		self.unprocessed_challenges.clear();
@@ -403,7 +368,10 @@ where
 	#[allow(clippy::type_complexity)]
 	pub fn finalize(
 		mut self,
-	) -> Result<(TerminateCodeword<F>, FRIQueryProver<'a, F, FA, MerkleProver, VCS>), Error> {
+	) -> Result<
+		(TerminateCodeword<F>, FRIQueryProver<'a, F, FA, MerkleProver, VCS, Backend::Vec<F>>),
+		Error,
+	> {
 		if self.curr_round != self.n_rounds() {
 			bail!(Error::EarlyProverFinish);
 		}
@@ -411,7 +379,7 @@ where
 		let terminate_codeword = self
 			.round_committed
 			.last()
-			.map(|(codeword, _)| codeword.clone())
+			.map(|(codeword, _)| codeword.to_vec())
 			.unwrap_or_else(|| self.codeword.to_vec());
 
 		self.unprocessed_challenges.clear();
@@ -443,6 +411,7 @@ where
 		Challenger_: Challenger,
 	{
 		let (terminate_codeword, query_prover) = self.finalize()?;
+		transcript.phase(labels::FRI_QUERY);
 		let mut advice = transcript.decommitment();
 		advice.write_scalar_slice(&terminate_codeword);
 
@@ -453,17 +422,20 @@ where
 
 		let params = query_prover.params;
 
-		for _ in 0..params.n_test_queries() {
-			let index = transcript.sample_bits(params.index_bits());
-			query_prover.prove_query(index, transcript.decommitment())?;
-		}
+		let indices = (0..params.n_test_queries())
+			.map(|_| transcript.sample_bits(params.index_bits()))
+			.collect::<Vec<_>>();
+		query_prover.prove_queries(&indices, transcript.decommitment())?;
 
 		Ok(())
 	}
 }
 
 /// A prover for the FRI query phase.
-pub struct FRIQueryProver<'a, F, FA, MerkleProver, VCS>
+///
+/// `Codeword` is the buffer holding a folded codeword, which is allocated by the computation
+/// backend.
+pub struct FRIQueryProver<'a, F, FA, MerkleProver, VCS, Codeword = Vec<F>>
 where
 	F: BinaryField,
 	FA: BinaryField,
@@ -473,16 +445,17 @@ where
 	params: &'a FRIParams<F, FA>,
 	codeword: &'a [F],
 	codeword_committed: &'a MerkleProver::Committed,
-	round_committed: Vec<(Vec<F>, MerkleProver::Committed)>,
+	round_committed: Vec<(Codeword, MerkleProver::Committed)>,
 	merkle_prover: &'a MerkleProver,
 }
 
-impl<F, FA, MerkleProver, VCS> FRIQueryProver<'_, F, FA, MerkleProver, VCS>
+impl<F, FA, MerkleProver, VCS, Codeword> FRIQueryProver<'_, F, FA, MerkleProver, VCS, Codeword>
 where
 	F: TowerField + ExtensionField<FA>,
 	FA: BinaryField,
 	MerkleProver: MerkleTreeProver<F, Scheme = VCS>,
 	VCS: MerkleTreeScheme<F>,
+	Codeword: Deref<Target = [F]>,
 {
 	/// Number of oracles sent during the fold rounds.
 	pub fn n_oracles(&self) -> usize {
@@ -501,7 +474,7 @@ where
 		mut advice: TranscriptWriter<B>,
 	) -> Result<(), Error>
 	where
-		B: BufMut,
+		B: ScalarBufMut,
 	{
 		let mut arities_and_optimal_layers_depths = self
 			.params
@@ -547,6 +520,70 @@ where
 		Ok(())
 	}
 
+	/// Proves a batch of FRI challenge queries.
+	///
+	/// For each oracle, the coset values of all queries are written first, followed by a single
+	/// batched Merkle opening proof. Branch nodes shared between the queries are only written
+	/// once, which makes the proof considerably smaller than proving the queries one by one
+	/// when the number of queries is large.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - indices into the original codeword domain
+	#[instrument(skip_all, name = "fri::FRIQueryProver::prove_queries", level = "debug")]
+	pub fn prove_queries<B>(
+		&self,
+		indices: &[usize],
+		mut advice: TranscriptWriter<B>,
+	) -> Result<(), Error>
+	where
+		B: ScalarBufMut,
+	{
+		let mut arities_and_optimal_layers_depths = self
+			.params
+			.fold_arities()
+			.iter()
+			.copied()
+			.zip(vcs_optimal_layers_depths_iter(self.params, self.merkle_prover.scheme()));
+
+		let Some((first_fold_arity, first_optimal_layer_depth)) =
+			arities_and_optimal_layers_depths.next()
+		else {
+			// See the comment in `prove_query`.
+			return Ok(());
+		};
+
+		let mut coset_indices = indices.to_vec();
+		prove_coset_multi_opening(
+			self.merkle_prover,
+			self.codeword,
+			self.codeword_committed,
+			&coset_indices,
+			first_fold_arity,
+			first_optimal_layer_depth,
+			&mut advice,
+		)?;
+
+		for ((codeword, committed), (arity, optimal_layer_depth)) in
+			izip!(self.round_committed.iter(), arities_and_optimal_layers_depths)
+		{
+			for index in &mut coset_indices {
+				*index >>= arity;
+			}
+			prove_coset_multi_opening(
+				self.merkle_prover,
+				codeword,
+				committed,
+				&coset_indices,
+				arity,
+				optimal_layer_depth,
+				&mut advice,
+			)?;
+		}
+
+		Ok(())
+	}
+
 	pub fn vcs_optimal_layers(&self) -> Result<Vec<Vec<VCS::Digest>>, Error> {
 		let committed_iter = std::iter::once(self.codeword_committed)
 			.chain(self.round_committed.iter().map(|(_, committed)| committed));
@@ -575,7 +612,7 @@ fn prove_coset_opening<F, MTProver, B>(
 where
 	F: TowerField,
 	MTProver: MerkleTreeProver<F>,
-	B: BufMut,
+	B: ScalarBufMut,
 {
 	let values = &codeword[(coset_index << log_coset_size)..((coset_index + 1) << log_coset_size)];
 	advice.write_scalar_slice(values);
@@ -586,3 +623,30 @@ where
 
 	Ok(())
 }
+
+fn prove_coset_multi_opening<F, MTProver, B>(
+	merkle_prover: &MTProver,
+	codeword: &[F],
+	committed: &MTProver::Committed,
+	coset_indices: &[usize],
+	log_coset_size: usize,
+	optimal_layer_depth: usize,
+	advice: &mut TranscriptWriter<B>,
+) -> Result<(), Error>
+where
+	F: TowerField,
+	MTProver: MerkleTreeProver<F>,
+	B: ScalarBufMut,
+{
+	for &coset_index in coset_indices {
+		let values =
+			&codeword[(coset_index << log_coset_size)..((coset_index + 1) << log_coset_size)];
+		advice.write_scalar_slice(values);
+	}
+
+	merkle_prover
+		.prove_multi_opening(committed, optimal_layer_depth, coset_indices, advice)
+		.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(())
+}
@@ -9,11 +9,11 @@ use binius_field::{
 	BinaryField, BinaryField128b, BinaryField16b, BinaryField32b, ExtensionField,
 	PackedBinaryField16x16b, PackedField, PackedFieldIndexable, TowerField,
 };
-use binius_hal::{make_portable_backend, ComputationBackendExt};
+use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
 use binius_hash::compress::Groestl256ByteCompression;
 use binius_math::MultilinearExtension;
 use binius_maybe_rayon::prelude::ParallelIterator;
-use binius_ntt::NTTOptions;
+use binius_ntt::{NTTOptions, ThreadingSettings};
 use groestl_crypto::Groestl256;
 use rand::prelude::*;
 
@@ -68,11 +68,13 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		.collect::<Vec<_>>();
 
 	// Prover commits the message
+	let backend = make_portable_backend();
 	let CommitOutput {
 		commitment: mut codeword_commitment,
 		committed: codeword_committed,
 		codeword,
-	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg).unwrap();
+	} = fri::commit_interleaved(&committed_rs_code_packed, &params, &merkle_prover, &msg, &backend)
+		.unwrap();
 
 	// Run the prover to generate the proximity proof
 	let mut round_prover = FRIFolder::new(
@@ -80,6 +82,7 @@ fn test_commit_prove_verify_success<U, F, FA>(
 		&merkle_prover,
 		<PackedType<U, F>>::unpack_scalars(&codeword),
 		&codeword_committed,
+		&backend,
 	)
 	.unwrap();
 
@@ -117,7 +120,6 @@ fn test_commit_prove_verify_success<U, F, FA>(
 
 	// check c == t(r'_0, ..., r'_{\ell-1})
 	// note that the prover is claiming that the final_message is [c]
-	let backend = make_portable_backend();
 	let eval_query = backend
 		.multilinear_query::<F>(&verifier_challenges)
 		.unwrap();
@@ -261,3 +263,77 @@ fn test_parallel_iterator_for_commitments() {
 
 	assert_eq!(collected_smaller, collected_bigger);
 }
+
+#[test]
+fn test_backend_fold_interleaved_matches_fold_codeword() {
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(6, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(rs_code.len())
+		.collect::<Vec<_>>();
+	let challenges = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(3)
+		.collect::<Vec<_>>();
+
+	// An interleaved codeword with a batch size of one is a single codeword.
+	let folded = backend
+		.fri_fold_codeword(rs_code.get_ntt(), &codeword, 0, &challenges)
+		.unwrap();
+	let folded_interleaved = backend
+		.fri_fold_interleaved(rs_code.get_ntt(), &codeword, 0, &challenges)
+		.unwrap();
+	assert_eq!(folded.len(), rs_code.len() >> challenges.len());
+	assert_eq!(folded, folded_interleaved);
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_fold_codeword_matches_backend() {
+	let mut rng = StdRng::seed_from_u64(0);
+
+	let rs_code = ReedSolomonCode::<BinaryField16b>::new(6, 2, &NTTOptions::default()).unwrap();
+	let codeword = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(rs_code.len())
+		.collect::<Vec<_>>();
+	let challenges = repeat_with(|| BinaryField128b::random(&mut rng))
+		.take(3)
+		.collect::<Vec<_>>();
+
+	let folded = make_portable_backend()
+		.fri_fold_codeword(rs_code.get_ntt(), &codeword, 1, &challenges)
+		.unwrap();
+	assert_eq!(fri::fold_codeword(&rs_code, &codeword, 4, &challenges), folded);
+}
+
+#[test]
+fn test_single_threaded_encoding_matches_multithreaded() {
+	type P = PackedBinaryField16x16b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let (log_dim, log_inv_rate, log_batch_size) = (6, 2, 1);
+
+	let message = repeat_with(|| P::random(&mut rng))
+		.take((1 << (log_dim + log_batch_size)) / P::WIDTH)
+		.collect::<Vec<_>>();
+	let [single_threaded, multithreaded] = [
+		ThreadingSettings::SingleThreaded,
+		ThreadingSettings::MultithreadedDefault,
+	]
+	.map(|thread_settings| {
+		let ntt_options = NTTOptions {
+			thread_settings,
+			..Default::default()
+		};
+		let rs_code = ReedSolomonCode::<P>::new(log_dim, log_inv_rate, &ntt_options).unwrap();
+		let mut codeword = message.clone();
+		codeword.resize(message.len() << log_inv_rate, P::default());
+		rs_code
+			.encode_ext_batch_inplace(&mut codeword, log_batch_size, &backend)
+			.unwrap();
+		codeword
+	});
+	assert_eq!(single_threaded, multithreaded);
+}
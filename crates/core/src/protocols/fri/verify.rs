@@ -5,7 +5,6 @@ use std::iter;
 use binius_field::{BinaryField, ExtensionField, TowerField};
 use binius_hal::{make_portable_backend, ComputationBackend};
 use binius_utils::{bail, DeserializeBytes};
-use bytes::Buf;
 use itertools::izip;
 use tracing::instrument;
 
@@ -14,7 +13,7 @@ use crate::{
 	fiat_shamir::{CanSampleBits, Challenger},
 	merkle_tree::MerkleTreeScheme,
 	protocols::fri::common::{fold_chunk, fold_interleaved_chunk, FRIParams},
-	transcript::{TranscriptReader, VerifierTranscript},
+	transcript::{labels, ScalarBuf, TranscriptReader, VerifierTranscript},
 };
 
 /// A verifier for the FRI query phase.
@@ -99,6 +98,8 @@ where
 	where
 		Challenger_: Challenger,
 	{
+		transcript.phase(labels::FRI_QUERY)?;
+
 		// Verify that the last oracle sent is a codeword.
 		let terminate_codeword_len =
 			1 << (self.params.n_final_challenges() + self.params.rs_code().log_inv_rate());
@@ -123,18 +124,15 @@ where
 		}
 
 		// Verify the random openings against the decommitted layers.
-
-		let mut scratch_buffer = self.create_scratch_buffer();
-		for _ in 0..self.params.n_test_queries() {
-			let index = transcript.sample_bits(self.params.index_bits());
-			self.verify_query_internal(
-				index,
-				&terminate_codeword,
-				&layers,
-				&mut transcript.decommitment(),
-				&mut scratch_buffer,
-			)?
-		}
+		let indices = (0..self.params.n_test_queries())
+			.map(|_| transcript.sample_bits(self.params.index_bits()))
+			.collect::<Vec<_>>();
+		self.verify_queries(
+			&indices,
+			&terminate_codeword,
+			&layers,
+			&mut transcript.decommitment(),
+		)?;
 
 		Ok(final_value)
 	}
@@ -219,7 +217,7 @@ where
 	///
 	/// * `index` - an index into the original codeword domain
 	/// * `proof` - a query proof
-	pub fn verify_query<B: Buf>(
+	pub fn verify_query<B: ScalarBuf>(
 		&self,
 		index: usize,
 		terminate_codeword: &[F],
@@ -236,7 +234,7 @@ where
 	}
 
 	#[instrument(skip_all, name = "fri::FRIVerifier::verify_query", level = "debug")]
-	fn verify_query_internal<B: Buf>(
+	fn verify_query_internal<B: ScalarBuf>(
 		&self,
 		mut index: usize,
 		terminate_codeword: &[F],
@@ -335,6 +333,131 @@ where
 		Ok(())
 	}
 
+	/// Verifies a batch of FRI challenge queries.
+	///
+	/// The proof must be generated with `FRIQueryProver::prove_queries`, which opens all queried
+	/// cosets of each oracle with a single batched Merkle opening.
+	///
+	/// ## Arguments
+	///
+	/// * `indices` - indices into the original codeword domain
+	/// * `advice` - the batched query proof
+	#[instrument(skip_all, name = "fri::FRIVerifier::verify_queries", level = "debug")]
+	pub fn verify_queries<B: ScalarBuf>(
+		&self,
+		indices: &[usize],
+		terminate_codeword: &[F],
+		layers: &[Vec<VCS::Digest>],
+		advice: &mut TranscriptReader<B>,
+	) -> Result<(), Error> {
+		let mut arities_iter = self.params.fold_arities().iter().copied();
+
+		let mut layer_digest_and_optimal_layer_depth =
+			iter::zip(layers, vcs_optimal_layers_depths_iter(self.params, self.vcs));
+
+		let Some(first_fold_arity) = arities_iter.next() else {
+			// See the comment in `verify_query_internal`.
+			return Ok(());
+		};
+
+		let (first_layer, first_optimal_layer_depth) = layer_digest_and_optimal_layer_depth
+			.next()
+			.expect("The length should be the same as the amount of proofs.");
+
+		let mut scratch_buffer = self.create_scratch_buffer();
+		let mut indices = indices.to_vec();
+
+		// This is the round of the folding phase that the codeword to be folded is committed to.
+		let mut fold_round = 0;
+		let mut log_n_cosets = self.params.index_bits();
+
+		// Check the first fold round before the main loop. It is special because in the first
+		// round we need to fold as an interleaved chunk instead of a regular coset.
+		let log_coset_size = first_fold_arity - self.params.log_batch_size();
+		let values = verify_coset_multi_opening(
+			self.vcs,
+			&indices,
+			first_fold_arity,
+			first_optimal_layer_depth,
+			log_n_cosets,
+			first_layer,
+			advice,
+		)?;
+		let mut next_values = iter::zip(&indices, values.chunks_exact(1 << first_fold_arity))
+			.map(|(&index, values)| {
+				fold_interleaved_chunk(
+					self.params.rs_code(),
+					self.params.log_batch_size(),
+					index,
+					values,
+					&self.interleave_tensor,
+					&self.fold_challenges[fold_round..fold_round + log_coset_size],
+					&mut scratch_buffer,
+				)
+			})
+			.collect::<Vec<_>>();
+		fold_round += log_coset_size;
+
+		for (i, (arity, (layer, optimal_layer_depth))) in
+			izip!(arities_iter, layer_digest_and_optimal_layer_depth).enumerate()
+		{
+			let coset_indices = indices
+				.iter()
+				.map(|&index| index >> arity)
+				.collect::<Vec<_>>();
+
+			log_n_cosets -= arity;
+
+			let values = verify_coset_multi_opening(
+				self.vcs,
+				&coset_indices,
+				arity,
+				optimal_layer_depth,
+				log_n_cosets,
+				layer,
+				advice,
+			)?;
+
+			for (index, coset_index, values, next_value) in izip!(
+				&mut indices,
+				coset_indices,
+				values.chunks_exact(1 << arity),
+				&mut next_values
+			) {
+				if *next_value != values[*index % (1 << arity)] {
+					return Err(VerificationError::IncorrectFold {
+						query_round: i,
+						index: *index,
+					}
+					.into());
+				}
+
+				*next_value = fold_chunk(
+					self.params.rs_code(),
+					fold_round,
+					coset_index,
+					values,
+					&self.fold_challenges[fold_round..fold_round + arity],
+					&mut scratch_buffer,
+				);
+				*index = coset_index;
+			}
+			fold_round += arity;
+		}
+
+		for (&index, &next_value) in iter::zip(&indices, &next_values) {
+			if next_value != terminate_codeword[index] {
+				return Err(VerificationError::IncorrectFold {
+					query_round: self.n_oracles() - 1,
+					index,
+				}
+				.into());
+			}
+		}
+
+		Ok(())
+	}
+
 	// scratch buffer used in `fold_chunk`.
 	fn create_scratch_buffer(&self) -> Vec<F> {
 		let max_arity = self
@@ -363,7 +486,7 @@ fn verify_coset_opening<F, MTScheme, B>(
 where
 	F: TowerField,
 	MTScheme: MerkleTreeScheme<F>,
-	B: Buf,
+	B: ScalarBuf,
 {
 	let values = advice.read_scalar_slice::<F>(1 << log_coset_size)?;
 	vcs.verify_opening(
@@ -378,3 +501,36 @@ where
 
 	Ok(values)
 }
+
+/// Verifies that the coset openings provided in a batched proof are consistent with the VCS
+/// commitment.
+///
+/// Returns the concatenated values of the opened cosets, in the order of `coset_indices`.
+#[allow(clippy::too_many_arguments)]
+fn verify_coset_multi_opening<F, MTScheme, B>(
+	vcs: &MTScheme,
+	coset_indices: &[usize],
+	log_coset_size: usize,
+	optimal_layer_depth: usize,
+	tree_depth: usize,
+	layer_digests: &[MTScheme::Digest],
+	advice: &mut TranscriptReader<B>,
+) -> Result<Vec<F>, Error>
+where
+	F: TowerField,
+	MTScheme: MerkleTreeScheme<F>,
+	B: ScalarBuf,
+{
+	let values = advice.read_scalar_slice::<F>(coset_indices.len() << log_coset_size)?;
+	vcs.verify_multi_opening(
+		coset_indices,
+		&values,
+		optimal_layer_depth,
+		tree_depth,
+		layer_digests,
+		advice,
+	)
+	.map_err(|err| Error::VectorCommit(Box::new(err)))?;
+
+	Ok(values)
+}
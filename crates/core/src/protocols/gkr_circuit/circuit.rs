@@ -0,0 +1,135 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::ops::{Add, Mul};
+
+use binius_field::Field;
+use binius_math::ArithExpr;
+use binius_utils::bail;
+
+use super::Error;
+
+/// A fan-in-2 gate reading two wires of the layer directly below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+	/// Outputs the sum of the two wires.
+	Add(usize, usize),
+	/// Outputs the product of the two wires.
+	Mul(usize, usize),
+}
+
+impl Gate {
+	/// The wires of the layer below that the gate reads.
+	pub const fn inputs(&self) -> [usize; 2] {
+		match *self {
+			Self::Add(a, b) | Self::Mul(a, b) => [a, b],
+		}
+	}
+
+	/// Evaluates the gate on the values of the layer below.
+	pub fn evaluate<T>(&self, wires: &[T]) -> T
+	where
+		T: Copy + Add<Output = T> + Mul<Output = T>,
+	{
+		let [a, b] = self.inputs();
+		self.combine(wires[a], wires[b])
+	}
+
+	/// Applies the gate operation to the values of its two input wires.
+	pub(super) fn combine<T>(&self, a: T, b: T) -> T
+	where
+		T: Add<Output = T> + Mul<Output = T>,
+	{
+		match self {
+			Self::Add(..) => a + b,
+			Self::Mul(..) => a * b,
+		}
+	}
+
+	/// The gate as an arithmetic expression over the wires of the layer below.
+	pub fn expr<F: Field>(&self) -> ArithExpr<F> {
+		match *self {
+			Self::Add(a, b) => ArithExpr::Var(a) + ArithExpr::Var(b),
+			Self::Mul(a, b) => ArithExpr::Var(a) * ArithExpr::Var(b),
+		}
+	}
+}
+
+/// A single copy of a layered arithmetic circuit.
+///
+/// The layers are stored in evaluation order: the gates of the first layer read the input wires,
+/// the gates of every following layer read the outputs of the layer before it, and the gates of
+/// the last layer are the outputs of the circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayeredCircuit {
+	n_inputs: usize,
+	layers: Vec<Vec<Gate>>,
+}
+
+impl LayeredCircuit {
+	pub fn new(n_inputs: usize, layers: Vec<Vec<Gate>>) -> Result<Self, Error> {
+		if n_inputs == 0 {
+			bail!(Error::EmptyInputLayer);
+		}
+
+		let mut width = n_inputs;
+		for (layer, gates) in layers.iter().enumerate() {
+			if gates.is_empty() {
+				bail!(Error::EmptyLayer { layer });
+			}
+			for (gate_index, gate) in gates.iter().enumerate() {
+				if let Some(&wire) = gate.inputs().iter().find(|&&wire| wire >= width) {
+					bail!(Error::InvalidWireIndex {
+						layer,
+						gate: gate_index,
+						wire,
+						width,
+					});
+				}
+			}
+			width = gates.len();
+		}
+
+		Ok(Self { n_inputs, layers })
+	}
+
+	/// The number of input wires.
+	pub const fn n_inputs(&self) -> usize {
+		self.n_inputs
+	}
+
+	/// The number of output wires.
+	pub fn n_outputs(&self) -> usize {
+		self.layers.last().map_or(self.n_inputs, Vec::len)
+	}
+
+	/// The number of gate layers.
+	pub fn depth(&self) -> usize {
+		self.layers.len()
+	}
+
+	/// The gate layers, in evaluation order.
+	pub fn layers(&self) -> &[Vec<Gate>] {
+		&self.layers
+	}
+
+	/// The number of wires read by the gates of the given layer.
+	pub(super) fn input_width(&self, layer: usize) -> usize {
+		match layer {
+			0 => self.n_inputs,
+			_ => self.layers[layer - 1].len(),
+		}
+	}
+
+	/// Evaluates a single copy of the circuit, returning the values of the output wires.
+	pub fn evaluate<F: Field>(&self, inputs: &[F]) -> Result<Vec<F>, Error> {
+		if inputs.len() != self.n_inputs {
+			bail!(Error::IncorrectNumberOfInputs {
+				expected: self.n_inputs,
+			});
+		}
+
+		Ok(self.layers.iter().fold(inputs.to_vec(), |wires, gates| {
+			gates.iter().map(|gate| gate.evaluate(&wires)).collect()
+		}))
+	}
+}
@@ -0,0 +1,25 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::Field;
+
+/// A claim about the evaluations of all output wire multilinears of a [`LayeredCircuit`] at a
+/// common point.
+///
+/// [`LayeredCircuit`]: super::LayeredCircuit
+#[derive(Debug, Clone)]
+pub struct LayeredCircuitClaim<F: Field> {
+	pub eval_point: Vec<F>,
+	/// The claimed evaluation of each output wire, in gate order.
+	pub output_evals: Vec<F>,
+}
+
+/// The evaluations of all input wire multilinears of a [`LayeredCircuit`] at a common point,
+/// which the layer reductions reduce a [`LayeredCircuitClaim`] to.
+///
+/// [`LayeredCircuit`]: super::LayeredCircuit
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayeredCircuitReductionOutput<F: Field> {
+	pub eval_point: Vec<F>,
+	/// The evaluation of each input wire.
+	pub input_evals: Vec<F>,
+}
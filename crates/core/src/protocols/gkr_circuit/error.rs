@@ -0,0 +1,57 @@
+// Copyright 2025 Irreducible Inc.
+
+use crate::{
+	polynomial::Error as PolynomialError,
+	protocols::{
+		gkr_gpa::gpa_sumcheck::error::Error as GPASumcheckError, sumcheck::Error as SumcheckError,
+	},
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("circuit must have at least one input wire")]
+	EmptyInputLayer,
+	#[error("layer {layer} must have at least one gate")]
+	EmptyLayer { layer: usize },
+	#[error(
+		"gate {gate} of layer {layer} reads wire {wire}, but the layer below has {width} wires"
+	)]
+	InvalidWireIndex {
+		layer: usize,
+		gate: usize,
+		wire: usize,
+		width: usize,
+	},
+	#[error("expected {expected} input multilinears")]
+	IncorrectNumberOfInputs { expected: usize },
+	#[error("all input multilinears must have the same number of variables")]
+	InputNumberOfVariablesMismatch,
+	#[error("expected {expected} output evaluations")]
+	IncorrectNumberOfOutputEvals { expected: usize },
+	#[error("the evaluation point must have {expected} coordinates")]
+	IncorrectEvalPointLength { expected: usize },
+	#[error("metas length does not conform to the provided input evaluations")]
+	MetasInputEvalsMismatch,
+	#[error("sumcheck failure: {0}")]
+	SumcheckError(#[from] SumcheckError),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] crate::transcript::Error),
+	#[error("gpa sumcheck failure: {0}")]
+	GPASumcheckError(#[from] GPASumcheckError),
+	#[error("polynomial error: {0}")]
+	Polynomial(#[from] PolynomialError),
+	#[error("verification failure: {0}")]
+	Verification(#[from] VerificationError),
+	#[error("HAL error: {0}")]
+	HalError(#[from] binius_hal::Error),
+	#[error("math error: {0}")]
+	MathError(#[from] binius_math::Error),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum VerificationError {
+	#[error("the proof contains an incorrect evaluation of the eq indicator")]
+	IncorrectEqIndEvaluation,
+	#[error("the number of final evaluations in the layer sumcheck is incorrect")]
+	NumberOfFinalEvaluations,
+}
@@ -0,0 +1,45 @@
+// Copyright 2025 Irreducible Inc.
+
+//! GKR for data-parallel layered arithmetic circuits.
+//!
+//! A [`LayeredCircuit`] describes a single copy of a circuit with fan-in-2 addition and
+//! multiplication gates, where every gate reads two wires of the layer directly below it. The
+//! circuit is applied in parallel to $2^\ell$ copies of its inputs, so that every wire of every
+//! layer is a $\ell$-variate multilinear polynomial over the copy index. This is the shape of
+//! computations like hashing many independent blocks, where only the inputs need to be committed.
+//!
+//! For a gate $g$ of layer $k$ with inputs $a, b$ in layer $k + 1$, the wire multilinears satisfy
+//!
+//! $$V_{k, g}(X) = \sum_{v \in B_\ell} \tilde{\mathbf{eq}}(v, X) \cdot \left(V_{k+1, a}(v) \circ V_{k+1, b}(v)\right),$$
+//!
+//! where $\circ$ is either addition or multiplication. Given claims on all wires of layer $k$ at a
+//! common point, one batched sumcheck per layer reduces them to claims on all wires of layer
+//! $k + 1$ at a new common point. The layer reductions use the
+//! [`GPAProver`](super::gkr_gpa::gpa_sumcheck::prove::GPAProver), which specializes the sumcheck
+//! prover to compositions multiplied by an equality indicator.
+//!
+//! The protocol ends with evaluation claims on the input wires, which can be turned into
+//! [`EvalcheckMultilinearClaim`](super::evalcheck::EvalcheckMultilinearClaim)s with
+//! [`make_eval_claims`].
+//!
+//! See [Thaler13] Section 5 for background on GKR for data-parallel circuits.
+//!
+//! [Thaler13]: <https://eprint.iacr.org/2013/351>
+
+mod circuit;
+mod common;
+mod error;
+mod oracles;
+mod prove;
+#[cfg(test)]
+mod tests;
+mod verify;
+mod witness;
+
+pub use circuit::{Gate, LayeredCircuit};
+pub use common::{LayeredCircuitClaim, LayeredCircuitReductionOutput};
+pub use error::*;
+pub use oracles::*;
+pub use prove::prove;
+pub use verify::verify;
+pub use witness::LayeredCircuitWitness;
@@ -0,0 +1,35 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter;
+
+use binius_field::TowerField;
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{Error, LayeredCircuitReductionOutput};
+use crate::{
+	oracle::OracleId,
+	protocols::evalcheck::{EvalPoint, EvalcheckMultilinearClaim},
+};
+
+/// Converts the input wire evaluations of a layered circuit reduction into evalcheck claims on the
+/// oracles committed for the input wires.
+#[instrument(skip_all, level = "debug")]
+pub fn make_eval_claims<F: TowerField>(
+	input_ids: impl IntoIterator<Item = OracleId>,
+	output: LayeredCircuitReductionOutput<F>,
+) -> Result<Vec<EvalcheckMultilinearClaim<F>>, Error> {
+	let input_ids = input_ids.into_iter().collect::<Vec<_>>();
+	if input_ids.len() != output.input_evals.len() {
+		bail!(Error::MetasInputEvalsMismatch);
+	}
+
+	let eval_point: EvalPoint<F> = output.eval_point.into();
+	Ok(iter::zip(input_ids, output.input_evals)
+		.map(|(id, eval)| EvalcheckMultilinearClaim {
+			id,
+			eval_point: eval_point.clone(),
+			eval,
+		})
+		.collect())
+}
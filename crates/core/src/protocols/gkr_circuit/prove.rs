@@ -0,0 +1,112 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, PackedExtension, PackedField, TowerField};
+use binius_hal::ComputationBackend;
+use binius_math::{
+	EvaluationDomainFactory, EvaluationOrder, MLEDirectAdapter, MultilinearExtension,
+};
+use binius_utils::bail;
+use itertools::izip;
+use tracing::instrument;
+
+use super::{
+	Error, LayeredCircuit, LayeredCircuitClaim, LayeredCircuitReductionOutput,
+	LayeredCircuitWitness,
+};
+use crate::{
+	fiat_shamir::Challenger,
+	polynomial::ArithCircuitPoly,
+	protocols::{
+		gkr_gpa::gpa_sumcheck::prove::GPAProver,
+		sumcheck::{self, CompositeSumClaim},
+	},
+	transcript::{labels, ProverTranscript},
+};
+
+/// Proves a claim on the output wires of a layered circuit, reducing it to evaluations of the
+/// input wires at a common point.
+///
+/// REQUIRES:
+/// * the witness was computed for `circuit`
+/// * the claim is consistent with the witness
+#[instrument(skip_all, name = "gkr_circuit::prove", level = "debug")]
+pub fn prove<F, P, FDomain, Challenger_, Backend>(
+	evaluation_order: EvaluationOrder,
+	circuit: &LayeredCircuit,
+	witness: &LayeredCircuitWitness<P>,
+	claim: &LayeredCircuitClaim<F>,
+	evaluation_domain_factory: impl EvaluationDomainFactory<FDomain>,
+	transcript: &mut ProverTranscript<Challenger_>,
+	backend: &Backend,
+) -> Result<LayeredCircuitReductionOutput<F>, Error>
+where
+	F: TowerField,
+	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
+	FDomain: Field,
+	Challenger_: Challenger,
+	Backend: ComputationBackend,
+{
+	transcript.phase(labels::GKR_CIRCUIT);
+
+	let n_vars = witness.n_vars();
+	if claim.eval_point.len() != n_vars {
+		bail!(Error::IncorrectEvalPointLength { expected: n_vars });
+	}
+	if claim.output_evals.len() != circuit.n_outputs() {
+		bail!(Error::IncorrectNumberOfOutputEvals {
+			expected: circuit.n_outputs(),
+		});
+	}
+
+	let mut eval_point = claim.eval_point.clone();
+	let mut evals = claim.output_evals.clone();
+
+	// Reduce the claims layer by layer, from the outputs down to the inputs.
+	for (layer, gates) in circuit.layers().iter().enumerate().rev() {
+		let width = circuit.input_width(layer);
+
+		let multilinears = witness
+			.layer_input_evals(layer)
+			.iter()
+			.map(|wire_evals| {
+				let mle = MultilinearExtension::new(n_vars, wire_evals.as_slice())?;
+				Ok(MLEDirectAdapter::from(mle))
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let composite_claims = izip!(gates, &evals)
+			.map(|(gate, &sum)| {
+				let composition = ArithCircuitPoly::<F>::with_n_vars(width, gate.expr())?;
+				Ok(CompositeSumClaim { composition, sum })
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let prover = GPAProver::<FDomain, _, _, _, _>::new(
+			evaluation_order,
+			multilinears,
+			None,
+			composite_claims,
+			evaluation_domain_factory.clone(),
+			&eval_point,
+			backend,
+		)?;
+
+		let sumcheck_output = sumcheck::batch_prove(vec![prover], transcript)?;
+
+		let mut multilinear_evals = sumcheck_output
+			.multilinear_evals
+			.into_iter()
+			.next()
+			.expect("batch_prove was called with a single prover");
+		// The GPA prover appends the evaluation of the eq indicator.
+		multilinear_evals.pop();
+
+		eval_point = sumcheck_output.challenges;
+		evals = multilinear_evals;
+	}
+
+	Ok(LayeredCircuitReductionOutput {
+		eval_point,
+		input_evals: evals,
+	})
+}
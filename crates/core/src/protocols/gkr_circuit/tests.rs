@@ -0,0 +1,186 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter::repeat_with;
+
+use binius_field::{
+	arch::OptimalUnderlier128b, as_packed_field::PackedType, packed::get_packed_slice,
+	BinaryField128b, BinaryField8b, Field, PackedField,
+};
+use binius_hal::{make_portable_backend, ComputationBackendExt};
+use binius_math::{EvaluationOrder, IsomorphicEvaluationDomainFactory, MultilinearExtension};
+use groestl_crypto::Groestl256;
+use rand::{rngs::StdRng, SeedableRng};
+
+use super::{prove, verify, Error, Gate, LayeredCircuit, LayeredCircuitWitness, VerificationError};
+use crate::{
+	fiat_shamir::{CanSample, HasherChallenger},
+	protocols::sumcheck,
+	transcript::ProverTranscript,
+};
+
+type F = BinaryField128b;
+type P = PackedType<OptimalUnderlier128b, F>;
+type FDomain = BinaryField8b;
+
+/// A circuit with a mix of addition and multiplication gates that computes
+/// `((x0 * x1) + x2) * (x2 * x2)` and `(x0 * x1) + (x0 + x3)`.
+fn test_circuit() -> LayeredCircuit {
+	LayeredCircuit::new(
+		4,
+		vec![
+			vec![
+				Gate::Mul(0, 1),
+				Gate::Mul(2, 2),
+				Gate::Add(0, 3),
+				Gate::Add(2, 2),
+			],
+			vec![Gate::Add(0, 3), Gate::Add(0, 2), Gate::Mul(1, 1)],
+			vec![Gate::Mul(0, 2), Gate::Add(1, 1)],
+		],
+	)
+	.unwrap()
+}
+
+fn random_inputs(rng: &mut StdRng, n_vars: usize, n_inputs: usize) -> Vec<MultilinearExtension<P>> {
+	repeat_with(|| {
+		let values = repeat_with(|| P::random(&mut *rng))
+			.take(1 << n_vars.saturating_sub(P::LOG_WIDTH))
+			.collect();
+		MultilinearExtension::new(n_vars, values).unwrap()
+	})
+	.take(n_inputs)
+	.collect()
+}
+
+fn test_prove_verify_helper(evaluation_order: EvaluationOrder, n_vars: usize) {
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let circuit = test_circuit();
+	let inputs = random_inputs(&mut rng, n_vars, circuit.n_inputs());
+	let witness = LayeredCircuitWitness::new(
+		&circuit,
+		inputs
+			.iter()
+			.map(|input| input.to_ref().specialize_arc_dyn::<P>()),
+	)
+	.unwrap();
+
+	// The witness agrees with evaluating every copy of the circuit separately.
+	for i in 0..1 << n_vars {
+		let copy_inputs = inputs
+			.iter()
+			.map(|input| input.evaluate_on_hypercube(i).unwrap())
+			.collect::<Vec<_>>();
+		let copy_outputs = witness
+			.output_evals()
+			.iter()
+			.map(|evals| get_packed_slice(evals, i))
+			.collect::<Vec<_>>();
+		assert_eq!(circuit.evaluate(&copy_inputs).unwrap(), copy_outputs);
+	}
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let eval_point: Vec<F> = prover_transcript.sample_vec(n_vars);
+	let claim = witness.make_claim(&eval_point, &backend).unwrap();
+
+	let prove_output = prove::<_, _, FDomain, _, _>(
+		evaluation_order,
+		&circuit,
+		&witness,
+		&claim,
+		&domain_factory,
+		&mut prover_transcript,
+		&backend,
+	)
+	.unwrap();
+
+	let query = backend
+		.multilinear_query::<P>(&prove_output.eval_point)
+		.unwrap();
+	for (input, &eval) in inputs.iter().zip(&prove_output.input_evals) {
+		assert_eq!(input.evaluate(query.to_ref()).unwrap(), eval);
+	}
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let _: Vec<F> = verifier_transcript.sample_vec(n_vars);
+	let verify_output =
+		verify(evaluation_order, &circuit, claim, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+
+	assert_eq!(verify_output, prove_output);
+}
+
+#[test]
+fn test_prove_verify_low_to_high() {
+	for n_vars in [1, 4, 7] {
+		test_prove_verify_helper(EvaluationOrder::LowToHigh, n_vars);
+	}
+}
+
+#[test]
+fn test_prove_verify_high_to_low() {
+	for n_vars in [1, 4, 7] {
+		test_prove_verify_helper(EvaluationOrder::HighToLow, n_vars);
+	}
+}
+
+#[test]
+fn test_verify_rejects_incorrect_output_eval() {
+	let n_vars = 5;
+	let mut rng = StdRng::seed_from_u64(0);
+	let backend = make_portable_backend();
+	let domain_factory = IsomorphicEvaluationDomainFactory::<FDomain>::default();
+
+	let circuit = test_circuit();
+	let inputs = random_inputs(&mut rng, n_vars, circuit.n_inputs());
+	let witness = LayeredCircuitWitness::new(
+		&circuit,
+		inputs
+			.iter()
+			.map(|input| input.to_ref().specialize_arc_dyn::<P>()),
+	)
+	.unwrap();
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let eval_point: Vec<F> = prover_transcript.sample_vec(n_vars);
+	let claim = witness.make_claim(&eval_point, &backend).unwrap();
+
+	prove::<_, _, FDomain, _, _>(
+		EvaluationOrder::LowToHigh,
+		&circuit,
+		&witness,
+		&claim,
+		&domain_factory,
+		&mut prover_transcript,
+		&backend,
+	)
+	.unwrap();
+
+	let mut bad_claim = claim;
+	bad_claim.output_evals[0] += F::ONE;
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let _: Vec<F> = verifier_transcript.sample_vec(n_vars);
+	let result = verify(EvaluationOrder::LowToHigh, &circuit, bad_claim, &mut verifier_transcript);
+	assert!(matches!(
+		result,
+		Err(Error::SumcheckError(sumcheck::Error::Verification(_))
+			| Error::Verification(VerificationError::IncorrectEqIndEvaluation))
+	));
+}
+
+#[test]
+fn test_circuit_rejects_invalid_wire() {
+	let result = LayeredCircuit::new(2, vec![vec![Gate::Add(0, 1)], vec![Gate::Mul(0, 1)]]);
+	assert!(matches!(
+		result,
+		Err(Error::InvalidWireIndex {
+			layer: 1,
+			gate: 0,
+			wire: 1,
+			width: 1,
+		})
+	));
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::iter;
+
+use binius_field::{util::eq, TowerField};
+use binius_math::{ArithExpr, EvaluationOrder};
+use binius_utils::bail;
+use itertools::izip;
+use tracing::instrument;
+
+use super::{
+	Error, LayeredCircuit, LayeredCircuitClaim, LayeredCircuitReductionOutput, VerificationError,
+};
+use crate::{
+	fiat_shamir::Challenger,
+	polynomial::ArithCircuitPoly,
+	protocols::sumcheck::{self, BatchSumcheckOutput, CompositeSumClaim, SumcheckClaim},
+	transcript::{labels, VerifierTranscript},
+};
+
+/// Verifies the reduction of a claim on the output wires of a layered circuit to evaluations of
+/// the input wires at a common point.
+#[instrument(skip_all, name = "gkr_circuit::verify", level = "debug")]
+pub fn verify<F, Challenger_>(
+	evaluation_order: EvaluationOrder,
+	circuit: &LayeredCircuit,
+	claim: LayeredCircuitClaim<F>,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<LayeredCircuitReductionOutput<F>, Error>
+where
+	F: TowerField,
+	Challenger_: Challenger,
+{
+	transcript.phase(labels::GKR_CIRCUIT)?;
+
+	if claim.output_evals.len() != circuit.n_outputs() {
+		bail!(Error::IncorrectNumberOfOutputEvals {
+			expected: circuit.n_outputs(),
+		});
+	}
+
+	let LayeredCircuitClaim {
+		mut eval_point,
+		output_evals: mut evals,
+	} = claim;
+	let n_vars = eval_point.len();
+
+	for (layer, gates) in circuit.layers().iter().enumerate().rev() {
+		let width = circuit.input_width(layer);
+
+		// The last multilinear is the eq indicator at the current evaluation point.
+		let n_multilinears = width + 1;
+		let composite_sums = izip!(gates, &evals)
+			.map(|(gate, &sum)| {
+				let composition = ArithCircuitPoly::with_n_vars(
+					n_multilinears,
+					gate.expr() * ArithExpr::Var(width),
+				)?;
+				Ok(CompositeSumClaim { composition, sum })
+			})
+			.collect::<Result<Vec<_>, Error>>()?;
+
+		let sumcheck_claim = SumcheckClaim::new(n_vars, n_multilinears, composite_sums)?;
+		let BatchSumcheckOutput {
+			challenges,
+			multilinear_evals,
+		} = sumcheck::batch_verify(evaluation_order, &[sumcheck_claim], transcript)?;
+
+		let Ok([mut multilinear_evals]) = <[_; 1]>::try_from(multilinear_evals) else {
+			bail!(VerificationError::NumberOfFinalEvaluations);
+		};
+		if multilinear_evals.len() != n_multilinears {
+			bail!(VerificationError::NumberOfFinalEvaluations);
+		}
+
+		let eq_ind_eval = iter::zip(&eval_point, &challenges)
+			.map(|(&eval_point_i, &challenge)| eq(eval_point_i, challenge))
+			.product::<F>();
+		if multilinear_evals.pop() != Some(eq_ind_eval) {
+			bail!(VerificationError::IncorrectEqIndEvaluation);
+		}
+
+		eval_point = challenges;
+		evals = multilinear_evals;
+	}
+
+	Ok(LayeredCircuitReductionOutput {
+		eval_point,
+		input_evals: evals,
+	})
+}
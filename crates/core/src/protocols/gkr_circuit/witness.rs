@@ -0,0 +1,129 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{Field, PackedField};
+use binius_hal::{ComputationBackend, ComputationBackendExt};
+use binius_math::{MultilinearExtension, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+use tracing::{debug_span, instrument};
+
+use super::{Error, LayeredCircuit, LayeredCircuitClaim};
+use crate::witness::MultilinearWitness;
+
+/// The values of every wire of a [`LayeredCircuit`] across all copies of the circuit.
+#[derive(Debug, Clone)]
+pub struct LayeredCircuitWitness<P: PackedField> {
+	n_vars: usize,
+	// The first element holds the input wires and the (k+1)th element holds the output wires of
+	// the kth gate layer. Each wire is stored as the packed hypercube evaluations of its
+	// multilinear.
+	layer_evals: Vec<Vec<Vec<P>>>,
+}
+
+impl<P: PackedField> LayeredCircuitWitness<P> {
+	/// Evaluates the circuit on every copy of the inputs, one multilinear per input wire.
+	#[instrument(skip_all, level = "debug", name = "LayeredCircuitWitness::new")]
+	pub fn new<'a>(
+		circuit: &LayeredCircuit,
+		inputs: impl IntoIterator<Item = MultilinearWitness<'a, P>>,
+	) -> Result<Self, Error>
+	where
+		P: 'a,
+	{
+		let inputs = inputs.into_iter().collect::<Vec<_>>();
+		if inputs.len() != circuit.n_inputs() {
+			bail!(Error::IncorrectNumberOfInputs {
+				expected: circuit.n_inputs(),
+			});
+		}
+
+		let n_vars = inputs[0].n_vars();
+		if inputs.iter().any(|input| input.n_vars() != n_vars) {
+			bail!(Error::InputNumberOfVariablesMismatch);
+		}
+
+		let input_evals = inputs
+			.par_iter()
+			.map(|input| {
+				let mut evals = zeroed_vec(1 << n_vars.saturating_sub(P::LOG_WIDTH));
+				input.subcube_evals(n_vars, 0, 0, &mut evals)?;
+				Ok(evals)
+			})
+			.collect::<Result<Vec<_>, binius_math::Error>>()?;
+
+		let mut layer_evals = vec![input_evals];
+		debug_span!("constructing_layers").in_scope(|| {
+			for gates in circuit.layers() {
+				let layer_below = layer_evals
+					.last()
+					.expect("layers is not empty by invariant");
+				let layer = gates
+					.par_iter()
+					.map(|gate| {
+						let [a, b] = gate.inputs().map(|wire| &layer_below[wire]);
+						a.par_iter()
+							.zip(b.par_iter())
+							.map(|(&a_i, &b_i)| gate.combine(a_i, b_i))
+							.collect()
+					})
+					.collect();
+				layer_evals.push(layer);
+			}
+		});
+
+		Ok(Self {
+			n_vars,
+			layer_evals,
+		})
+	}
+
+	/// Returns the base-two log of the number of copies of the circuit.
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// Returns the packed hypercube evaluations of the output wires.
+	pub fn output_evals(&self) -> &[Vec<P>] {
+		self.layer_evals
+			.last()
+			.expect("layers is not empty by invariant")
+	}
+
+	/// Returns the packed hypercube evaluations of the wires read by the given gate layer.
+	pub(super) fn layer_input_evals(&self, layer: usize) -> &[Vec<P>] {
+		&self.layer_evals[layer]
+	}
+
+	/// Evaluates every output wire multilinear at the given point.
+	pub fn make_claim<F, Backend>(
+		&self,
+		eval_point: &[F],
+		backend: &Backend,
+	) -> Result<LayeredCircuitClaim<F>, Error>
+	where
+		F: Field,
+		P: PackedField<Scalar = F>,
+		Backend: ComputationBackend,
+	{
+		if eval_point.len() != self.n_vars {
+			bail!(Error::IncorrectEvalPointLength {
+				expected: self.n_vars,
+			});
+		}
+
+		let query = backend.multilinear_query::<P>(eval_point)?;
+		let output_evals = self
+			.output_evals()
+			.iter()
+			.map(|evals| {
+				MultilinearExtension::new(self.n_vars, evals.as_slice())?.evaluate(query.to_ref())
+			})
+			.collect::<Result<Vec<_>, _>>()?;
+
+		Ok(LayeredCircuitClaim {
+			eval_point: eval_point.to_vec(),
+			output_evals,
+		})
+	}
+}
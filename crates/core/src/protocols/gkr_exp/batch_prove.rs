@@ -22,7 +22,7 @@ use super::{
 use crate::{
 	fiat_shamir::Challenger,
 	protocols::sumcheck::{self, BatchSumcheckOutput, CompositeSumClaim},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 	witness::MultilinearWitness,
 };
 
@@ -60,6 +60,8 @@ where
 	Backend: ComputationBackend,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::GKR_EXP);
+
 	let witnesses = witnesses.into_iter().collect::<Vec<_>>();
 
 	if witnesses.len() != claims.len() {
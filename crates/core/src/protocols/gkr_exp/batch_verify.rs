@@ -14,7 +14,7 @@ use crate::{
 	fiat_shamir::Challenger,
 	polynomial::MultivariatePoly,
 	protocols::sumcheck::{self, BatchSumcheckOutput, SumcheckClaim},
-	transcript::VerifierTranscript,
+	transcript::{labels, VerifierTranscript},
 	transparent::eq_ind::EqIndPartialEval,
 };
 
@@ -38,6 +38,8 @@ where
 	F: TowerField + ExtensionField<FBase>,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::GKR_EXP)?;
+
 	let mut layers_claims = Vec::new();
 
 	if claims.is_empty() {
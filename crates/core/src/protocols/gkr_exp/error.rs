@@ -26,6 +26,8 @@ pub enum Error {
 	GKRError(#[from] GKRError),
 	#[error("sumcheck failure: {0}")]
 	SumcheckError(#[from] SumcheckError),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] crate::transcript::Error),
 	#[error("polynomial error: {0}")]
 	Polynomial(#[from] PolynomialError),
 	#[error("verification failure: {0}")]
@@ -40,6 +40,8 @@ pub enum Error {
 	GPASumcheckError(#[from] GPASumcheckError),
 	#[error("sumcheck failure: {0}")]
 	SumcheckError(#[from] SumcheckError),
+	#[error("transcript error: {0}")]
+	TranscriptError(#[from] crate::transcript::Error),
 	#[error("witness failure: {0}")]
 	WitnessErrror(#[from] WitnessErrror),
 	#[error("HAL error: {0}")]
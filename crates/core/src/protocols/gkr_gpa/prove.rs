@@ -22,7 +22,7 @@ use crate::{
 	composition::{BivariateProduct, IndexComposition},
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck::{self, CompositeSumClaim},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 };
 
 /// Proves batch reduction turning each GrandProductClaim into an EvalcheckMultilinearClaim
@@ -46,6 +46,8 @@ where
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
+	transcript.phase(labels::GKR_GPA);
+
 	//  Ensure witnesses and claims are of the same length, zip them together
 	// 	For each witness-claim pair, create GrandProductProver
 	let witness_vec = witnesses.into_iter().collect::<Vec<_>>();
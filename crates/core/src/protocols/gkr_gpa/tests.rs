@@ -19,8 +19,8 @@ use super::{GrandProductClaim, GrandProductWitness};
 use crate::{
 	fiat_shamir::HasherChallenger,
 	oracle::MultilinearOracleSet,
-	protocols::gkr_gpa::{batch_prove, batch_verify, GrandProductBatchProveOutput},
-	transcript::ProverTranscript,
+	protocols::gkr_gpa::{batch_prove, batch_verify, Error, GrandProductBatchProveOutput},
+	transcript::{self, labels, ProverTranscript},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -239,3 +239,58 @@ fn test_prove_verify_batch_512b() {
 
 	run_prove_verify_batch_test::<U, F, FS, P>();
 }
+
+#[test]
+fn test_label_mismatch_fails_verification() {
+	type F = BinaryField128b;
+	type U = <F as WithUnderlier>::Underlier;
+	type P = PackedType<U, F>;
+	type FS = BinaryField32b;
+
+	for debug in [false, true] {
+		let CreateClaimsWitnessesOutput {
+			new_claims: claims,
+			new_witnesses: witnesses,
+			..
+		} = create_claims_witnesses_helper::<U, P, F>(
+			StdRng::seed_from_u64(0),
+			MultilinearOracleSet::new(),
+			MultilinearExtensionIndex::new(),
+			4,
+			2,
+		);
+
+		// The prover absorbs a phase label that the verifier does not expect.
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		prover_transcript.set_debug(debug);
+		prover_transcript.phase(labels::GKR_EXP);
+		batch_prove::<_, _, FS, _, _>(
+			EvaluationOrder::LowToHigh,
+			witnesses,
+			&claims,
+			IsomorphicEvaluationDomainFactory::<FS>::default(),
+			&mut prover_transcript,
+			&binius_hal::make_portable_backend(),
+		)
+		.unwrap();
+
+		let mut verify_transcript = prover_transcript.into_verifier();
+		verify_transcript.set_debug(debug);
+		let result = batch_verify(EvaluationOrder::LowToHigh, claims, &mut verify_transcript);
+		if debug {
+			match result {
+				Err(Error::TranscriptError(transcript::Error::LabelMismatch {
+					expected,
+					found,
+					..
+				})) => {
+					assert_eq!(expected, labels::GKR_GPA);
+					assert_eq!(found, labels::GKR_EXP);
+				}
+				result => panic!("expected a label mismatch, got {result:?}"),
+			}
+		} else {
+			assert!(result.is_err());
+		}
+	}
+}
@@ -16,7 +16,7 @@ use super::{
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
 	protocols::sumcheck,
-	transcript::VerifierTranscript,
+	transcript::{labels, VerifierTranscript},
 };
 
 /// Verifies batch reduction turning each GrandProductClaim into an EvalcheckMultilinearClaim
@@ -30,6 +30,8 @@ where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::GKR_GPA)?;
+
 	let (original_indices, mut sorted_claims) = stable_sort(claims, |claim| claim.n_vars, true);
 	let max_n_vars = sorted_claims.first().map(|claim| claim.n_vars).unwrap_or(0);
 
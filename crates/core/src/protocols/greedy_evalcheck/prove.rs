@@ -17,7 +17,7 @@ use crate::{
 		serialize_evalcheck_proof, subclaims::prove_bivariate_sumchecks_with_switchover,
 		EvalcheckMultilinearClaim, EvalcheckProver,
 	},
-	transcript::{write_u64, ProverTranscript},
+	transcript::{labels, write_u64, ProverTranscript},
 	witness::MultilinearExtensionIndex,
 };
 
@@ -40,6 +40,8 @@ where
 	Challenger_: Challenger,
 	Backend: ComputationBackend,
 {
+	transcript.phase(labels::EVALCHECK);
+
 	let mut evalcheck_prover =
 		EvalcheckProver::<U, F, Backend>::new(oracles, witness_index, backend);
 
@@ -12,7 +12,7 @@ use crate::{
 		evalcheck::{deserialize_evalcheck_proof, EvalcheckMultilinearClaim, EvalcheckVerifier},
 		sumcheck::{self, batch_verify, constraint_set_sumcheck_claims, SumcheckClaimsWithMeta},
 	},
-	transcript::{read_u64, VerifierTranscript},
+	transcript::{labels, read_u64, VerifierTranscript},
 };
 
 pub fn verify<F, Challenger_>(
@@ -24,6 +24,8 @@ where
 	F: TowerField,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::EVALCHECK)?;
+
 	let mut evalcheck_verifier = EvalcheckVerifier::new(oracles);
 
 	// Verify the initial evalcheck claims
@@ -242,6 +242,40 @@ pub const fn immediate_switchover_heuristic(_extension_degree: usize) -> usize {
 	0
 }
 
+/// A strategy for choosing the switchover round of each multilinear in a sumcheck instance.
+///
+/// Before its switchover round, a multilinear is kept in its original (possibly small) field and
+/// the round evaluations are computed with a tensor expansion of the past challenges; after the
+/// switchover it is folded into the extension field after every round. Switching over late saves
+/// memory for multilinears over small fields, while switching over early is cheaper when the
+/// round evaluations are dominated by evaluating the composition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchoverPolicy {
+	/// Uses [`standard_switchover_heuristic`] with the given `k` for every instance.
+	Standard(isize),
+	/// Folds every multilinear starting from the first round.
+	Immediate,
+}
+
+impl Default for SwitchoverPolicy {
+	fn default() -> Self {
+		Self::Standard(-2)
+	}
+}
+
+impl SwitchoverPolicy {
+	/// Returns the switchover function of this policy.
+	///
+	/// The returned function maps the extension degree of a multilinear over its field of
+	/// definition to its switchover round, as expected by the sumcheck prover constructors.
+	pub fn switchover_fn(self) -> impl Fn(usize) -> usize + Copy {
+		move |extension_degree: usize| match self {
+			Self::Standard(k) => standard_switchover_heuristic(k)(extension_degree),
+			Self::Immediate => immediate_switchover_heuristic(extension_degree),
+		}
+	}
+}
+
 /// Determine switchover rounds for a slice of multilinears.
 #[instrument(skip_all, level = "debug")]
 pub fn determine_switchovers<P, M>(
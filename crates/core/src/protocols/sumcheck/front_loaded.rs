@@ -5,7 +5,6 @@ use std::{cmp, cmp::Ordering, collections::VecDeque, iter};
 use binius_field::{Field, TowerField};
 use binius_math::{evaluate_univariate, CompositionPoly};
 use binius_utils::sorting::is_sorted_ascending;
-use bytes::Buf;
 
 use super::{
 	common::batch_weighted_value,
@@ -14,7 +13,9 @@ use super::{
 	RoundCoeffs, RoundProof,
 };
 use crate::{
-	fiat_shamir::CanSample, protocols::sumcheck::SumcheckClaim, transcript::TranscriptReader,
+	fiat_shamir::CanSample,
+	protocols::sumcheck::SumcheckClaim,
+	transcript::{ScalarBuf, TranscriptReader},
 };
 
 #[derive(Debug)]
@@ -136,7 +137,7 @@ where
 		transcript: &mut TranscriptReader<B>,
 	) -> Result<Option<Vec<F>>, Error>
 	where
-		B: Buf,
+		B: ScalarBuf,
 	{
 		let Some(SumcheckClaimWithContext { claim, .. }) = self.claims.front() else {
 			return Ok(None);
@@ -185,7 +186,7 @@ where
 		transcript: &mut TranscriptReader<B>,
 	) -> Result<(), Error>
 	where
-		B: Buf,
+		B: ScalarBuf,
 	{
 		match self.last_coeffs_or_sum {
 			CoeffsOrSums::Coeffs(_) => Err(Error::ExpectedFinishRound),
@@ -11,6 +11,7 @@ mod error;
 pub mod front_loaded;
 mod oracles;
 pub mod prove;
+mod standalone;
 #[cfg(test)]
 mod tests;
 pub mod univariate;
@@ -22,6 +23,7 @@ pub use common::*;
 pub use error::*;
 pub use oracles::*;
 pub use prove::batch_prove;
+pub use standalone::*;
 pub use univariate_zerocheck::batch_verify_zerocheck_univariate_round;
 pub use verify::{batch_verify, batch_verify_with_start};
 pub use zerocheck::ZerocheckClaim;
@@ -13,7 +13,7 @@ use crate::{
 		common::{BatchSumcheckOutput, RoundCoeffs},
 		error::Error,
 	},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 };
 
 /// A sumcheck prover with a round-by-round execution interface.
@@ -141,6 +141,7 @@ where
 		reduction_provers,
 	} = start;
 
+	transcript.phase(labels::SUMCHECK);
 	provers.splice(0..0, reduction_provers);
 
 	let Some(first_prover) = provers.first() else {
@@ -11,7 +11,7 @@ use crate::{
 		univariate::LagrangeRoundEvals,
 		Error,
 	},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 };
 
 /// A univariate zerocheck prover interface.
@@ -111,6 +111,8 @@ where
 	Prover: UnivariateZerocheckProver<'a, F>,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::ZEROCHECK);
+
 	// Check that the provers are in descending order by n_vars
 	if !is_sorted_ascending(provers.iter().map(|prover| prover.n_vars()).rev()) {
 		bail!(Error::ClaimsOutOfOrder);
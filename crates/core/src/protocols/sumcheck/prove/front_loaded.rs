@@ -4,13 +4,12 @@ use std::{collections::VecDeque, iter};
 
 use binius_field::{Field, TowerField};
 use binius_utils::sorting::is_sorted_ascending;
-use bytes::BufMut;
 
 use super::batch_prove::SumcheckProver;
 use crate::{
 	fiat_shamir::CanSample,
 	protocols::sumcheck::{Error, RoundCoeffs},
-	transcript::TranscriptWriter,
+	transcript::{ScalarBufMut, TranscriptWriter},
 };
 
 /// Prover for a front-loaded batch sumcheck protocol execution.
@@ -73,7 +72,7 @@ where
 
 	fn finish_claim_provers<B>(&mut self, transcript: &mut TranscriptWriter<B>) -> Result<(), Error>
 	where
-		B: BufMut,
+		B: ScalarBufMut,
 	{
 		while let Some((prover, _)) = self.provers.front() {
 			if prover.n_vars() != self.round {
@@ -89,7 +88,7 @@ where
 	/// Computes the round message and writes it to the proof transcript.
 	pub fn send_round_proof<B>(&mut self, transcript: &mut TranscriptWriter<B>) -> Result<(), Error>
 	where
-		B: BufMut,
+		B: ScalarBufMut,
 	{
 		self.finish_claim_provers(transcript)?;
 
@@ -116,7 +115,7 @@ where
 	/// Finishes the remaining instance provers and checks that all rounds are completed.
 	pub fn finish<B>(mut self, transcript: &mut TranscriptWriter<B>) -> Result<(), Error>
 	where
-		B: BufMut,
+		B: ScalarBufMut,
 	{
 		self.finish_claim_provers(transcript)?;
 		if !self.provers.is_empty() {
@@ -65,6 +65,7 @@ where
 		composition,
 		predicate,
 		name,
+		evaluator,
 	} in constraints
 	{
 		let composition_base = composition
@@ -75,8 +76,10 @@ where
 			ConstraintPredicate::Zero => {
 				zeros.push((
 					name,
-					ArithCircuitPoly::with_n_vars(multilinears.len(), composition_base)?,
-					ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?,
+					ArithCircuitPoly::with_n_vars(multilinears.len(), composition_base)?
+						.with_evaluator(evaluator),
+					ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?
+						.with_evaluator(evaluator),
 				));
 			}
 			_ => bail!(Error::MixedBatchingNotSupported),
@@ -117,12 +120,14 @@ where
 	for Constraint {
 		composition,
 		predicate,
+		evaluator,
 		..
 	} in constraints
 	{
 		match predicate {
 			ConstraintPredicate::Sum(sum) => sums.push(CompositeSumClaim {
-				composition: ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?,
+				composition: ArithCircuitPoly::with_n_vars(multilinears.len(), composition)?
+					.with_evaluator(evaluator),
 				sum,
 			}),
 			_ => bail!(Error::MixedBatchingNotSupported),
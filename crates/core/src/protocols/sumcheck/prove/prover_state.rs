@@ -6,14 +6,14 @@ use std::{
 };
 
 use binius_field::{util::powers, Field, PackedExtension, PackedField};
-use binius_hal::{ComputationBackend, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use binius_hal::{
+	ComputationBackend, RoundEvals, SumcheckEvaluator, SumcheckMultilinear, ZeroSubcubeMask,
+};
 use binius_math::{
-	evaluate_univariate, fold_left_lerp_inplace, fold_right_lerp, CompositionPoly, EvaluationOrder,
-	MultilinearPoly, MultilinearQuery,
+	evaluate_univariate, CompositionPoly, EvaluationOrder, MultilinearPoly, MultilinearQuery,
 };
 use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
-use bytemuck::zeroed_vec;
 use getset::CopyGetters;
 use itertools::izip;
 use tracing::instrument;
@@ -64,7 +64,7 @@ where
 	n_vars: usize,
 	#[getset(get_copy = "pub")]
 	evaluation_order: EvaluationOrder,
-	multilinears: Vec<SumcheckMultilinear<P, M>>,
+	multilinears: Vec<SumcheckMultilinear<P, M, Backend>>,
 	nontrivial_evaluation_points: Vec<FDomain>,
 	challenges: Vec<P::Scalar>,
 	tensor_query: Option<MultilinearQuery<P>>,
@@ -196,17 +196,21 @@ where
 							);
 
 							// At switchover we partially evaluate the multilinear at an expanded tensor query.
-							let large_field_folded_evals = match self.evaluation_order {
-								EvaluationOrder::LowToHigh => inner_multilinear
-									.evaluate_partial_low(tensor_query.to_ref())?
-									.into_evals(),
-								EvaluationOrder::HighToLow => inner_multilinear
-									.evaluate_partial_high(tensor_query.to_ref())?
-									.into_evals(),
-							};
+							let large_field_folded_evals = self.backend.sumcheck_switchover(
+								self.evaluation_order,
+								inner_multilinear,
+								tensor_query.to_ref(),
+							)?;
+
+							let zero_subcubes = ZeroSubcubeMask::from_switchover(
+								self.evaluation_order,
+								inner_multilinear,
+								tensor_query.n_vars(),
+							);
 
 							*multilinear = SumcheckMultilinear::Folded {
 								large_field_folded_evals,
+								zero_subcubes,
 							};
 						} else {
 							*switchover_round -= 1;
@@ -215,37 +219,16 @@ where
 					}
 					SumcheckMultilinear::Folded {
 						ref mut large_field_folded_evals,
+						ref mut zero_subcubes,
 					} => {
 						// Post-switchover, we perform single variable folding (linear interpolation).
-
-						match self.evaluation_order {
-							// Lerp folding in low-to-high evaluation order can be made inplace, but not
-							// easily so if multithreading is desired.
-							EvaluationOrder::LowToHigh => {
-								let mut new_large_field_folded_evals =
-									zeroed_vec(1 << self.n_vars.saturating_sub(1 + P::LOG_WIDTH));
-
-								fold_right_lerp(
-									&*large_field_folded_evals,
-									self.n_vars,
-									challenge,
-									&mut new_large_field_folded_evals,
-								)?;
-
-								*large_field_folded_evals = new_large_field_folded_evals;
-							}
-
-							// High-to-low evaluation order allows trivial inplace multithreaded folding.
-							EvaluationOrder::HighToLow => {
-								// REVIEW: note that this method is currently _not_ multithreaded, as
-								//         traces are usually sufficiently wide
-								fold_left_lerp_inplace(
-									large_field_folded_evals,
-									self.n_vars,
-									challenge,
-								)?;
-							}
-						}
+						self.backend.sumcheck_fold_evals(
+							self.evaluation_order,
+							self.n_vars,
+							challenge,
+							large_field_folded_evals,
+						)?;
+						zero_subcubes.fold(self.evaluation_order);
 					}
 				};
 				Ok::<(), Error>(())
@@ -286,6 +269,7 @@ where
 					}
 					SumcheckMultilinear::Folded {
 						large_field_folded_evals,
+						..
 					} => Ok(large_field_folded_evals
 						.first()
 						.expect("exactly one packed field element left after folding")
@@ -0,0 +1,230 @@
+// Copyright 2025 Irreducible Inc.
+
+//! High-level entry points for proving and verifying a single sumcheck or zerocheck claim.
+//!
+//! The functions in this module assemble the claims, provers and domains needed by
+//! [`batch_prove`] and [`batch_verify`] with defaults that are suitable for most standalone uses:
+//! evaluations are computed with the portable CPU backend, composition evaluation domains are
+//! over [`BinaryField8b`], variables are bound from low to high, and the switchover rounds are
+//! chosen with [`standard_switchover_heuristic`].
+//!
+//! Protocols that batch several claims, need a different backend or evaluation order should use
+//! the lower-level interfaces in [`super::prove`] and [`super::verify`].
+
+use binius_field::{
+	BinaryField8b, ExtensionField, Field, PackedExtension, PackedField, PackedFieldIndexable,
+	TowerField,
+};
+use binius_hal::make_portable_backend;
+use binius_math::{
+	CompositionPoly, EvaluationOrder, IsomorphicEvaluationDomainFactory, MultilinearPoly,
+};
+use tracing::instrument;
+
+use super::{
+	batch_verify,
+	prove::{batch_prove, RegularSumcheckProver, UnivariateZerocheck},
+	standard_switchover_heuristic,
+	zerocheck::{self, ZerocheckClaim},
+	BatchSumcheckOutput, CompositeSumClaim, Error, SumcheckClaim,
+};
+use crate::{
+	fiat_shamir::{CanSample, Challenger},
+	transcript::{ProverTranscript, VerifierTranscript},
+};
+
+/// The reduced claims output by a standalone sumcheck or zerocheck.
+///
+/// The protocol reduces the claim to the evaluations of each multilinear at a single point, which
+/// the caller is responsible for checking, for example with a polynomial commitment scheme.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SumcheckOutput<F: Field> {
+	/// The evaluation point, one coordinate per variable.
+	pub challenges: Vec<F>,
+	/// The evaluations of the multilinears at `challenges`, in the order they were given.
+	pub multilinear_evals: Vec<F>,
+}
+
+impl<F: Field> SumcheckOutput<F> {
+	fn from_batch_output(output: BatchSumcheckOutput<F>) -> Self {
+		let BatchSumcheckOutput {
+			challenges,
+			mut multilinear_evals,
+		} = output;
+		debug_assert_eq!(multilinear_evals.len(), 1);
+		Self {
+			challenges,
+			multilinear_evals: multilinear_evals.pop().unwrap_or_default(),
+		}
+	}
+}
+
+/// Proves that the sum of `composition` over the multilinears on the boolean hypercube equals
+/// `claimed_sum`.
+///
+/// The claimed sum is observed by the transcript before the sumcheck rounds, so the caller does
+/// not need to bind it separately.
+///
+/// ## Arguments
+///
+/// * `multilinears` - the multilinears the composition is applied to; they must all have the same
+///   number of variables.
+/// * `composition` - the composition polynomial, with one variable per multilinear.
+/// * `claimed_sum` - the sum of the composite polynomial over the hypercube.
+/// * `transcript` - the prover transcript.
+#[instrument(skip_all, name = "sumcheck::prove_sum", level = "debug")]
+pub fn prove_sum<F, P, M, Composition, Challenger_>(
+	multilinears: Vec<M>,
+	composition: Composition,
+	claimed_sum: F,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<SumcheckOutput<F>, Error>
+where
+	F: TowerField + ExtensionField<BinaryField8b>,
+	P: PackedField<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<BinaryField8b>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Composition: CompositionPoly<P>,
+	Challenger_: Challenger,
+{
+	transcript.observe().write_scalar(claimed_sum);
+
+	let backend = make_portable_backend();
+	let prover = RegularSumcheckProver::<BinaryField8b, _, _, _, _>::new(
+		EvaluationOrder::LowToHigh,
+		multilinears,
+		[CompositeSumClaim {
+			composition,
+			sum: claimed_sum,
+		}],
+		IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+		standard_switchover_heuristic(-2),
+		&backend,
+	)?;
+
+	let output = batch_prove(vec![prover], transcript)?;
+	Ok(SumcheckOutput::from_batch_output(output))
+}
+
+/// Verifies a proof created by [`prove_sum`].
+///
+/// On success, returns the claimed evaluations of the multilinears, which the caller must check.
+/// The claimed sum is observed by the transcript, mirroring [`prove_sum`].
+///
+/// ## Arguments
+///
+/// * `n_vars` - the number of variables of the multilinears.
+/// * `composition` - the composition polynomial, with one variable per multilinear.
+/// * `claimed_sum` - the sum of the composite polynomial over the hypercube.
+/// * `transcript` - the verifier transcript.
+#[instrument(skip_all, name = "sumcheck::verify_sum", level = "debug")]
+pub fn verify_sum<F, Composition, Challenger_>(
+	n_vars: usize,
+	composition: Composition,
+	claimed_sum: F,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<SumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	transcript.observe().write_scalar(claimed_sum);
+
+	let n_multilinears = composition.n_vars();
+	let claim = SumcheckClaim::new(
+		n_vars,
+		n_multilinears,
+		vec![CompositeSumClaim {
+			composition,
+			sum: claimed_sum,
+		}],
+	)?;
+
+	let output = batch_verify(EvaluationOrder::LowToHigh, &[claim], transcript)?;
+	Ok(SumcheckOutput::from_batch_output(output))
+}
+
+/// Proves that `composition` over the multilinears vanishes everywhere on the boolean hypercube.
+///
+/// The zerocheck challenges are sampled from the transcript before the sumcheck rounds.
+///
+/// ## Arguments
+///
+/// * `multilinears` - the multilinears the composition is applied to; they must all have the same
+///   number of variables.
+/// * `composition` - the composition polynomial, with one variable per multilinear.
+/// * `transcript` - the prover transcript.
+#[instrument(skip_all, name = "sumcheck::prove_zero", level = "debug")]
+pub fn prove_zero<F, P, M, Composition, Challenger_>(
+	multilinears: Vec<M>,
+	composition: Composition,
+	transcript: &mut ProverTranscript<Challenger_>,
+) -> Result<SumcheckOutput<F>, Error>
+where
+	F: TowerField + ExtensionField<BinaryField8b>,
+	P: PackedFieldIndexable<Scalar = F>
+		+ PackedExtension<F, PackedSubfield = P>
+		+ PackedExtension<BinaryField8b>,
+	M: MultilinearPoly<P> + Send + Sync,
+	Composition: CompositionPoly<P> + Clone,
+	Challenger_: Challenger,
+{
+	let n_vars = multilinears
+		.first()
+		.map(|multilinear| multilinear.n_vars())
+		.unwrap_or_default();
+	let zerocheck_challenges = transcript.sample_vec(n_vars);
+
+	let backend = make_portable_backend();
+	let prover = UnivariateZerocheck::<BinaryField8b, F, P, _, _, _, _>::new(
+		multilinears,
+		[("zerocheck".to_string(), composition.clone(), composition)],
+		&zerocheck_challenges,
+		IsomorphicEvaluationDomainFactory::<BinaryField8b>::default(),
+		standard_switchover_heuristic(-2),
+		&backend,
+	)?
+	.into_regular_zerocheck()?;
+
+	let mut output = SumcheckOutput::from_batch_output(batch_prove(vec![prover], transcript)?);
+	// The reduced sumcheck has the equality indicator as an extra multilinear, which the verifier
+	// evaluates itself.
+	output.multilinear_evals.pop();
+	Ok(output)
+}
+
+/// Verifies a proof created by [`prove_zero`].
+///
+/// On success, returns the claimed evaluations of the multilinears, which the caller must check.
+///
+/// ## Arguments
+///
+/// * `n_vars` - the number of variables of the multilinears.
+/// * `composition` - the composition polynomial, with one variable per multilinear.
+/// * `transcript` - the verifier transcript.
+#[instrument(skip_all, name = "sumcheck::verify_zero", level = "debug")]
+pub fn verify_zero<F, Composition, Challenger_>(
+	n_vars: usize,
+	composition: Composition,
+	transcript: &mut VerifierTranscript<Challenger_>,
+) -> Result<SumcheckOutput<F>, Error>
+where
+	F: TowerField,
+	Composition: CompositionPoly<F>,
+	Challenger_: Challenger,
+{
+	let zerocheck_challenges = transcript.sample_vec(n_vars);
+
+	let n_multilinears = composition.n_vars();
+	let claims = [ZerocheckClaim::new(
+		n_vars,
+		n_multilinears,
+		vec![composition],
+	)?];
+	let sumcheck_claims = zerocheck::reduce_to_sumchecks(&claims)?;
+	let output = batch_verify(EvaluationOrder::LowToHigh, &sumcheck_claims, transcript)?;
+	let output = zerocheck::verify_sumcheck_outputs(&claims, &zerocheck_challenges, output)?;
+	Ok(SumcheckOutput::from_batch_output(output))
+}
@@ -17,8 +17,8 @@ use binius_field::{
 use binius_hal::{make_portable_backend, ComputationBackend, ComputationBackendExt};
 use binius_math::{
 	ArithExpr, CompositionPoly, EvaluationDomainFactory, EvaluationOrder,
-	IsomorphicEvaluationDomainFactory, MLEEmbeddingAdapter, MultilinearExtension, MultilinearPoly,
-	MultilinearQuery,
+	IsomorphicEvaluationDomainFactory, MLEDirectAdapter, MLEEmbeddingAdapter, MultilinearExtension,
+	MultilinearPoly, MultilinearQuery, SparseMultilinearExtension,
 };
 use binius_maybe_rayon::{current_num_threads, prelude::*};
 use binius_utils::checked_arithmetics::log2_ceil_usize;
@@ -41,7 +41,9 @@ use crate::{
 	polynomial::{IdentityCompositionPoly, MultilinearComposite},
 	protocols::{
 		sumcheck::prove::SumcheckProver,
-		test_utils::{AddOneComposition, TestProductComposition},
+		test_utils::{
+			generate_zero_product_multilinears, AddOneComposition, TestProductComposition,
+		},
 	},
 	transcript::ProverTranscript,
 };
@@ -275,6 +277,85 @@ fn test_sumcheck_prove_verify_with_nontrivial_packing() {
 	>(n_vars, n_multilinears, switchover_rd);
 }
 
+#[test]
+fn test_sumcheck_prove_verify_sparse_multilinears() {
+	type FExt = BinaryField128b;
+	type P = PackedType<OptimalUnderlier128b, FExt>;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 12;
+	let n_multilinears = 3;
+
+	// Each multilinear is supported on a small number of hypercube vertices where the second
+	// highest variable is zero, so that the prover skips whole subcubes both before and after
+	// the switchover, in either evaluation order.
+	let multilins = repeat_with(|| {
+		let entries = repeat_with(|| {
+			let index = rng.gen_range(0..1 << (n_vars - 2)) | rng.gen_range(0..2) << (n_vars - 1);
+			(index, <FExt as Field>::random(&mut rng))
+		})
+		.take(24)
+		.collect::<std::collections::BTreeMap<_, _>>();
+		SparseMultilinearExtension::new(n_vars, entries).unwrap()
+	})
+	.take(n_multilinears)
+	.collect::<Vec<_>>();
+	let dense_multilins = multilins
+		.iter()
+		.map(|multilin| MLEDirectAdapter::from(multilin.to_dense::<P>().unwrap()))
+		.collect::<Vec<_>>();
+
+	let composition = TestProductComposition::new(n_multilinears);
+	let sum = compute_composite_sum(&dense_multilins, &composition);
+
+	let backend = make_portable_backend();
+	for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+		let claim = SumcheckClaim::new(
+			n_vars,
+			n_multilinears,
+			vec![CompositeSumClaim {
+				composition: &composition,
+				sum,
+			}],
+		)
+		.unwrap();
+
+		let domain_factory = IsomorphicEvaluationDomainFactory::<BinaryField8b>::default();
+		let prover = RegularSumcheckProver::<BinaryField8b, P, _, _, _>::new(
+			evaluation_order,
+			multilins.iter().collect(),
+			[CompositeSumClaim {
+				composition: &composition,
+				sum,
+			}],
+			domain_factory,
+			|_| 1,
+			&backend,
+		)
+		.unwrap();
+
+		let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let prover_reduced_claims =
+			batch_prove(vec![prover], &mut prover_transcript).expect("failed to prove sumcheck");
+
+		let mut verifier_transcript = prover_transcript.into_verifier();
+		let verifier_reduced_claims =
+			batch_verify(evaluation_order, &[claim], &mut verifier_transcript).unwrap();
+		verifier_transcript.finalize().unwrap();
+
+		assert_eq!(verifier_reduced_claims, prover_reduced_claims);
+
+		let BatchSumcheckOutput {
+			challenges,
+			multilinear_evals,
+		} = verifier_reduced_claims;
+		let multilin_query = backend.multilinear_query::<P>(&challenges).unwrap();
+		for (multilinear, &expected) in iter::zip(&dense_multilins, multilinear_evals[0].iter()) {
+			assert_eq!(multilinear.evaluate(multilin_query.to_ref()).unwrap(), expected);
+		}
+	}
+}
+
 #[derive(Clone)]
 struct TestSumcheckClaimShape {
 	n_vars: usize,
@@ -572,3 +653,87 @@ fn test_prove_verify_batch_front_loaded() {
 		},
 	]);
 }
+
+#[test]
+fn test_standalone_prove_verify_sum() {
+	type P = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 6;
+	let n_multilinears = 3;
+	let multilins = generate_random_multilinears::<P>(&mut rng, n_vars, n_multilinears)
+		.into_iter()
+		.map(MLEDirectAdapter::from)
+		.collect::<Vec<_>>();
+	let composition = TestProductComposition::new(n_multilinears);
+	let sum = compute_composite_sum(&multilins, &composition);
+
+	let prove = || {
+		let mut transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+		let output =
+			super::prove_sum(multilins.iter().collect(), &composition, sum, &mut transcript)
+				.unwrap();
+		(output, transcript)
+	};
+
+	let (prover_output, prover_transcript) = prove();
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let verifier_output =
+		super::verify_sum(n_vars, &composition, sum, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(prover_output, verifier_output);
+
+	let query = MultilinearQuery::<P>::expand(&verifier_output.challenges);
+	for (multilinear, &expected) in iter::zip(&multilins, &verifier_output.multilinear_evals) {
+		assert_eq!(multilinear.evaluate(query.to_ref()).unwrap(), expected);
+	}
+
+	let (_, prover_transcript) = prove();
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	assert!(super::verify_sum(
+		n_vars,
+		&composition,
+		sum + BinaryField128b::ONE,
+		&mut verifier_transcript
+	)
+	.is_err());
+}
+
+#[test]
+fn test_standalone_prove_verify_zero() {
+	type P = PackedBinaryField1x128b;
+
+	let mut rng = StdRng::seed_from_u64(0);
+	let n_vars = 6;
+	let n_multilinears = 3;
+	let multilins = generate_zero_product_multilinears::<P, P>(&mut rng, n_vars, n_multilinears);
+	let composition = TestProductComposition::new(n_multilinears);
+
+	let mut prover_transcript = ProverTranscript::<HasherChallenger<Groestl256>>::new();
+	let prover_output =
+		super::prove_zero(multilins.clone(), composition.clone(), &mut prover_transcript).unwrap();
+
+	let mut verifier_transcript = prover_transcript.into_verifier();
+	let verifier_output =
+		super::verify_zero(n_vars, composition, &mut verifier_transcript).unwrap();
+	verifier_transcript.finalize().unwrap();
+	assert_eq!(prover_output, verifier_output);
+	assert_eq!(verifier_output.multilinear_evals.len(), n_multilinears);
+
+	let query = MultilinearQuery::<P>::expand(&verifier_output.challenges);
+	for (multilinear, &expected) in iter::zip(&multilins, &verifier_output.multilinear_evals) {
+		assert_eq!(multilinear.evaluate(query.to_ref()).unwrap(), expected);
+	}
+}
+
+#[test]
+fn test_switchover_policies() {
+	let standard = super::standard_switchover_heuristic(-2);
+	for extension_degree in [1, 2, 4, 8, 16, 32, 64, 128] {
+		assert_eq!(
+			super::SwitchoverPolicy::Standard(-2).switchover_fn()(extension_degree),
+			standard(extension_degree)
+		);
+		assert_eq!(super::SwitchoverPolicy::Immediate.switchover_fn()(extension_degree), 0);
+	}
+}
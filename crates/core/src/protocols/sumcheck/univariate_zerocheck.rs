@@ -12,7 +12,7 @@ use super::{
 };
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
-	transcript::VerifierTranscript,
+	transcript::{labels, VerifierTranscript},
 };
 
 #[derive(Debug)]
@@ -53,6 +53,8 @@ where
 	Composition: CompositionPoly<F>,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::ZEROCHECK)?;
+
 	// Check that the claims are in descending order by n_vars
 	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
 		bail!(Error::ClaimsOutOfOrder);
@@ -13,7 +13,7 @@ use super::{
 };
 use crate::{
 	fiat_shamir::{CanSample, Challenger},
-	transcript::VerifierTranscript,
+	transcript::{labels, VerifierTranscript},
 };
 
 /// Verify a batched sumcheck protocol execution.
@@ -81,6 +81,8 @@ where
 		skip_rounds,
 	} = start;
 
+	transcript.phase(labels::SUMCHECK)?;
+
 	// Check that the claims are in descending order by n_vars
 	if !is_sorted_ascending(claims.iter().map(|claim| claim.n_vars()).rev()) {
 		bail!(Error::ClaimsOutOfOrder);
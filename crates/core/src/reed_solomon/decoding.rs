@@ -0,0 +1,467 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Erasure and error decoding for [`ReedSolomonCode`].
+//!
+//! The codeword symbols are the evaluations of the message polynomial over the additive NTT
+//! domain in its natural order, so decoding is performed with univariate polynomial arithmetic
+//! over that domain. The message polynomial is converted back to the novel polynomial basis
+//! representation taken by the encoder.
+
+use binius_field::{BinaryField, PackedField};
+use binius_ntt::{AdditiveNTT, PolynomialNTT, SingleThreadedNTT};
+use binius_utils::bail;
+use tracing::instrument;
+
+use super::{error::Error, reed_solomon::ReedSolomonCode};
+
+/// Below this number of roots, the vanishing polynomial is expanded by schoolbook multiplication.
+const VANISHING_POLY_NAIVE_THRESHOLD: usize = 64;
+
+impl<P> ReedSolomonCode<P>
+where
+	P: PackedField<Scalar: BinaryField>,
+{
+	/// Recovers the message from a codeword in which some symbols are erased.
+	///
+	/// `received[i]` is `None` if the `i`-th symbol is erased. Up to `len() - dim()` erasures can
+	/// be recovered, assuming that all remaining symbols are correct. The symbols may lie in an
+	/// extension field of the code's field, in which case the message is in the same extension.
+	///
+	/// With the erasure locator $Z(X)$, the polynomial $P(X) Z(X)$ is known on the whole domain
+	/// and, at every erasure $x$, $P(x) = (P Z)'(x) / Z'(x)$. The decoder thus runs in
+	/// $\mathcal{O}(n \log^3 n)$ time for code length $n$.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::TooManyErasures`] if fewer than `dim()` symbols are given.
+	/// * [`Error::DecodingFailure`] if the given symbols are not consistent with a codeword.
+	#[instrument(skip_all, level = "debug")]
+	pub fn decode_erasures<FE>(&self, received: &[Option<FE>]) -> Result<Vec<FE>, Error>
+	where
+		FE: BinaryField + From<P::Scalar>,
+	{
+		self.check_erasures(received)?;
+
+		let poly_ntt = self.poly_ntt::<FE>()?;
+		let mut codeword = received
+			.iter()
+			.map(|symbol| symbol.unwrap_or(FE::ZERO))
+			.collect::<Vec<_>>();
+
+		let erased_points = erased_points(&poly_ntt, received);
+		if !erased_points.is_empty() {
+			let log_len = self.log_len();
+			let locator = vanishing_poly(&poly_ntt, &erased_points)?;
+			let locator_evals = poly_ntt.evaluate(&locator, 0, log_len)?;
+			let locator_derivative_evals = poly_ntt.evaluate(&derivative(&locator), 0, log_len)?;
+
+			// Erased symbols are zero, which is the value of $P Z$ at the erasures.
+			let product_evals = codeword
+				.iter()
+				.zip(&locator_evals)
+				.map(|(&symbol, &locator_eval)| symbol * locator_eval)
+				.collect::<Vec<_>>();
+			let product = poly_ntt.interpolate(&product_evals, 0)?;
+			let product_derivative_evals = poly_ntt.evaluate(&derivative(&product), 0, log_len)?;
+
+			for (i, symbol) in received.iter().enumerate() {
+				if symbol.is_none() {
+					let locator_derivative_inv = locator_derivative_evals[i]
+						.invert()
+						.expect("the erasure locator has simple roots");
+					codeword[i] = product_derivative_evals[i] * locator_derivative_inv;
+				}
+			}
+		}
+
+		// Interpolate over the whole domain to check that the recovered word is a codeword.
+		poly_ntt
+			.ntt()
+			.inverse_transform(&mut codeword, 0, 0, self.log_len())?;
+		let (message, excess) = codeword.split_at(self.dim());
+		if excess.iter().any(|&coeff| coeff != FE::ZERO) {
+			bail!(Error::DecodingFailure);
+		}
+		Ok(message.to_vec())
+	}
+
+	/// Decodes a received word with errors and erasures using Gao's algorithm.
+	///
+	/// `received[i]` is `None` if the `i`-th symbol is erased. Decoding succeeds if the number of
+	/// erroneous symbols $e$ and the number of erasures $s$ satisfy $2e + s \le n - k$ for the
+	/// code length $n$ and dimension $k$. The symbols may lie in an extension field of the code's
+	/// field, in which case the message is in the same extension.
+	///
+	/// The extended Euclidean algorithm is performed with schoolbook polynomial arithmetic, so the
+	/// decoder runs in $\mathcal{O}(n (e + s))$ time. It is intended for moderate code lengths,
+	/// such as testing protocols against adversarially corrupted codewords.
+	///
+	/// See [Gao02] for the algorithm.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::TooManyErasures`] if fewer than `dim()` symbols are given.
+	/// * [`Error::DecodingFailure`] if the received word is not within the unique decoding radius
+	///   of a codeword.
+	///
+	/// [Gao02]: <https://doi.org/10.1007/978-1-4757-3789-9_5>
+	#[instrument(skip_all, level = "debug")]
+	pub fn decode<FE>(&self, received: &[Option<FE>]) -> Result<Vec<FE>, Error>
+	where
+		FE: BinaryField + From<P::Scalar>,
+	{
+		self.check_erasures(received)?;
+
+		let poly_ntt = self.poly_ntt::<FE>()?;
+		let log_len = self.log_len();
+		let received_values = received
+			.iter()
+			.map(|symbol| symbol.unwrap_or(FE::ZERO))
+			.collect::<Vec<_>>();
+
+		// $G_0$ vanishes on the points of the given symbols and $G_1$ interpolates them.
+		let erased_points = erased_points(&poly_ntt, received);
+		let domain_poly = domain_vanishing_poly(&poly_ntt, log_len)?;
+		let (g0, g1) = if erased_points.is_empty() {
+			(domain_poly, poly_ntt.interpolate(&received_values, 0)?)
+		} else {
+			// The product of the interpolant with the erasure locator is known on the whole
+			// domain, since it vanishes at the erasures.
+			let locator = vanishing_poly(&poly_ntt, &erased_points)?;
+			let locator_evals = poly_ntt.evaluate(&locator, 0, log_len)?;
+			let product_evals = received_values
+				.iter()
+				.zip(&locator_evals)
+				.map(|(&value, &locator_eval)| value * locator_eval)
+				.collect::<Vec<_>>();
+			let product = poly_ntt.interpolate(&product_evals, 0)?;
+			(div_rem(&domain_poly, &locator).0, div_rem(&product, &locator).0)
+		};
+
+		// Run the extended Euclidean algorithm on $G_0, G_1$ until the remainder has degree less
+		// than $(n' + k) / 2$, where $n'$ is the number of given symbols.
+		let n_given = self.len() - erased_points.len();
+		let mut r_prev = g0;
+		let mut r = trim(g1);
+		let mut v_prev = Vec::new();
+		let mut v = vec![FE::ONE];
+		while !r.is_empty() && 2 * (r.len() - 1) >= n_given + self.dim() {
+			let (quotient, remainder) = div_rem(&r_prev, &r);
+			let v_next = add(&v_prev, &mul(&quotient, &v));
+			r_prev = std::mem::replace(&mut r, remainder);
+			v_prev = std::mem::replace(&mut v, v_next);
+		}
+
+		let (mut message, remainder) = div_rem(&r, &v);
+		if !remainder.is_empty() || message.len() > self.dim() {
+			bail!(Error::DecodingFailure);
+		}
+		message.resize(self.dim(), FE::ZERO);
+
+		// The algorithm only guarantees the result if a codeword within the unique decoding radius
+		// exists, so check the distance to the received word explicitly.
+		let codeword = poly_ntt.evaluate(&message, 0, log_len)?;
+		let n_errors = received
+			.iter()
+			.zip(&codeword)
+			.filter(|(symbol, &value)| symbol.is_some_and(|symbol| symbol != value))
+			.count();
+		if 2 * n_errors > n_given - self.dim() {
+			bail!(Error::DecodingFailure);
+		}
+
+		poly_ntt.monomial_to_novel(&mut message)?;
+		Ok(message)
+	}
+
+	fn check_erasures<FE>(&self, received: &[Option<FE>]) -> Result<(), Error> {
+		if received.len() != self.len() {
+			bail!(Error::IncorrectLength {
+				expected: self.len()
+			});
+		}
+
+		let n_erasures = received.iter().filter(|symbol| symbol.is_none()).count();
+		let max_erasures = self.len() - self.dim();
+		if n_erasures > max_erasures {
+			bail!(Error::TooManyErasures {
+				n_erasures,
+				max_erasures,
+			});
+		}
+		Ok(())
+	}
+
+	/// Returns the polynomial arithmetic over the code's evaluation domain, embedded in `FE`.
+	fn poly_ntt<FE>(&self) -> Result<PolynomialNTT<FE>, Error>
+	where
+		FE: BinaryField + From<P::Scalar>,
+	{
+		let ntt = SingleThreadedNTT::<FE>::with_domain_field::<P::Scalar>(self.log_len())?;
+		Ok(PolynomialNTT::new(ntt))
+	}
+}
+
+fn erased_points<F: BinaryField>(poly_ntt: &PolynomialNTT<F>, received: &[Option<F>]) -> Vec<F> {
+	let subspace = poly_ntt.ntt().subspace(0);
+	received
+		.iter()
+		.enumerate()
+		.filter(|(_, symbol)| symbol.is_none())
+		.map(|(i, _)| subspace.get(i))
+		.collect()
+}
+
+/// Returns the polynomial vanishing on the first `2^log_len` points of the NTT domain.
+fn domain_vanishing_poly<F: BinaryField>(
+	poly_ntt: &PolynomialNTT<F>,
+	log_len: usize,
+) -> Result<Vec<F>, Error> {
+	if log_len == 0 {
+		// The domain is $\{0\}$.
+		return Ok(vec![F::ZERO, F::ONE]);
+	}
+
+	// $\hat{W}_{\ell-1}$ maps the domain onto $\{0, 1\}$, so the domain is the root set of
+	// $\hat{W}_{\ell-1}(X) (\hat{W}_{\ell-1}(X) + 1)$. The novel basis polynomial
+	// $X_{2^{\ell-1}}$ is $\hat{W}_{\ell-1}$.
+	let mut subspace_poly = vec![F::ZERO; 1 << log_len];
+	subspace_poly[1 << (log_len - 1)] = F::ONE;
+	poly_ntt.novel_to_monomial(&mut subspace_poly)?;
+
+	let mut domain_poly = vec![F::ZERO; (1 << log_len) + 1];
+	let subspace_poly_len = (1 << (log_len - 1)) + 1;
+	for (i, &coeff) in subspace_poly[..subspace_poly_len].iter().enumerate() {
+		domain_poly[i] += coeff;
+		domain_poly[2 * i] += coeff.square();
+	}
+	Ok(domain_poly)
+}
+
+/// Returns the monic polynomial whose roots are `points`.
+fn vanishing_poly<F: BinaryField>(
+	poly_ntt: &PolynomialNTT<F>,
+	points: &[F],
+) -> Result<Vec<F>, Error> {
+	if points.len() <= VANISHING_POLY_NAIVE_THRESHOLD {
+		let poly = points.iter().fold(vec![F::ONE], |poly, &point| {
+			let mut next = vec![F::ZERO; poly.len() + 1];
+			for (i, &coeff) in poly.iter().enumerate() {
+				next[i] += coeff * point;
+				next[i + 1] += coeff;
+			}
+			next
+		});
+		return Ok(poly);
+	}
+
+	let (lhs, rhs) = points.split_at(points.len() / 2);
+	let product = poly_ntt.mul(&vanishing_poly(poly_ntt, lhs)?, &vanishing_poly(poly_ntt, rhs)?)?;
+	Ok(product)
+}
+
+/// Formal derivative of a polynomial in the monomial basis over a field of characteristic 2.
+fn derivative<F: BinaryField>(poly: &[F]) -> Vec<F> {
+	poly.iter()
+		.enumerate()
+		.skip(1)
+		.map(|(i, &coeff)| if i % 2 == 1 { coeff } else { F::ZERO })
+		.collect()
+}
+
+fn trim<F: BinaryField>(mut poly: Vec<F>) -> Vec<F> {
+	while poly.last() == Some(&F::ZERO) {
+		poly.pop();
+	}
+	poly
+}
+
+fn add<F: BinaryField>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	let (long, short) = if lhs.len() >= rhs.len() {
+		(lhs, rhs)
+	} else {
+		(rhs, lhs)
+	};
+	let mut sum = long.to_vec();
+	for (sum_coeff, &coeff) in sum.iter_mut().zip(short) {
+		*sum_coeff += coeff;
+	}
+	trim(sum)
+}
+
+fn mul<F: BinaryField>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	if lhs.is_empty() || rhs.is_empty() {
+		return Vec::new();
+	}
+
+	let mut product = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+	for (i, &lhs_coeff) in lhs.iter().enumerate() {
+		for (j, &rhs_coeff) in rhs.iter().enumerate() {
+			product[i + j] += lhs_coeff * rhs_coeff;
+		}
+	}
+	trim(product)
+}
+
+/// Divides `dividend` by the nonzero polynomial `divisor`, returning the trimmed quotient and
+/// remainder.
+fn div_rem<F: BinaryField>(dividend: &[F], divisor: &[F]) -> (Vec<F>, Vec<F>) {
+	let divisor = trim(divisor.to_vec());
+	let (&lead, _) = divisor.split_last().expect("divisor is nonzero");
+	let lead_inv = lead.invert().expect("leading coefficient is nonzero");
+
+	let mut remainder = trim(dividend.to_vec());
+	if remainder.len() < divisor.len() {
+		return (Vec::new(), remainder);
+	}
+
+	let mut quotient = vec![F::ZERO; remainder.len() - divisor.len() + 1];
+	for i in (0..quotient.len()).rev() {
+		let coeff = remainder[i + divisor.len() - 1] * lead_inv;
+		quotient[i] = coeff;
+		for (j, &divisor_coeff) in divisor.iter().enumerate() {
+			remainder[i + j] += coeff * divisor_coeff;
+		}
+	}
+	remainder.truncate(divisor.len() - 1);
+	(trim(quotient), trim(remainder))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{
+		BinaryField128b, BinaryField32b, Field, PackedBinaryField1x128b, PackedBinaryField4x32b,
+		RepackedExtension,
+	};
+	use binius_hal::make_portable_backend;
+	use binius_ntt::NTTOptions;
+	use rand::{rngs::StdRng, seq::index::sample, SeedableRng};
+
+	use super::*;
+
+	const LOG_DIM: usize = 4;
+	const LOG_INV_RATE: usize = 2;
+
+	type P = PackedBinaryField4x32b;
+
+	fn encode<PE>(code: &ReedSolomonCode<P>, message: &[PE::Scalar]) -> Vec<Option<PE::Scalar>>
+	where
+		PE: RepackedExtension<P>,
+	{
+		let mut buffer = vec![PE::zero(); code.len() / PE::WIDTH];
+		for (i, &symbol) in message.iter().enumerate() {
+			buffer[i / PE::WIDTH].set(i % PE::WIDTH, symbol);
+		}
+		code.encode_ext_batch_inplace(&mut buffer, 0, &make_portable_backend())
+			.unwrap();
+		PE::iter_slice(&buffer).map(Some).collect()
+	}
+
+	fn setup<F: Field>(rng: &mut StdRng) -> (ReedSolomonCode<P>, Vec<F>) {
+		let code = ReedSolomonCode::new(LOG_DIM, LOG_INV_RATE, &NTTOptions::default()).unwrap();
+		let message = repeat_with(|| F::random(&mut *rng))
+			.take(code.dim())
+			.collect();
+		(code, message)
+	}
+
+	fn corrupt<F: Field>(
+		rng: &mut StdRng,
+		received: &mut [Option<F>],
+		n_errors: usize,
+		n_erasures: usize,
+	) {
+		let positions = sample(rng, received.len(), n_errors + n_erasures).into_vec();
+		let (error_positions, erasure_positions) = positions.split_at(n_errors);
+		for &i in error_positions {
+			let error = repeat_with(|| F::random(&mut *rng))
+				.find(|error| *error != F::ZERO)
+				.expect("iterator is infinite");
+			*received[i].as_mut().expect("symbol is not erased") += error;
+		}
+		for &i in erasure_positions {
+			received[i] = None;
+		}
+	}
+
+	#[test]
+	fn test_decode_erasures() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (code, message) = setup::<BinaryField32b>(&mut rng);
+		let codeword = encode::<P>(&code, &message);
+		let max_erasures = code.len() - code.dim();
+
+		for n_erasures in [0, 1, max_erasures / 2, max_erasures] {
+			let mut received = codeword.clone();
+			corrupt(&mut rng, &mut received, 0, n_erasures);
+			assert_eq!(code.decode_erasures(&received).unwrap(), message);
+		}
+
+		let mut received = codeword;
+		corrupt(&mut rng, &mut received, 0, max_erasures + 1);
+		assert_matches!(
+			code.decode_erasures(&received),
+			Err(Error::TooManyErasures {
+				max_erasures: 48,
+				..
+			})
+		);
+	}
+
+	#[test]
+	fn test_decode_erasures_inconsistent() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (code, message) = setup::<BinaryField32b>(&mut rng);
+		let mut received = encode::<P>(&code, &message);
+		corrupt(&mut rng, &mut received, 1, 10);
+		assert_matches!(code.decode_erasures(&received), Err(Error::DecodingFailure));
+	}
+
+	#[test]
+	fn test_decode_errors_and_erasures() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (code, message) = setup::<BinaryField32b>(&mut rng);
+		let codeword = encode::<P>(&code, &message);
+		let redundancy = code.len() - code.dim();
+
+		for (n_errors, n_erasures) in [
+			(0, 0),
+			(1, 0),
+			(redundancy / 2, 0),
+			(10, 28),
+			(0, redundancy),
+		] {
+			let mut received = codeword.clone();
+			corrupt(&mut rng, &mut received, n_errors, n_erasures);
+			assert_eq!(code.decode(&received).unwrap(), message);
+		}
+	}
+
+	#[test]
+	fn test_decode_extension_field() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (code, message) = setup::<BinaryField128b>(&mut rng);
+		let mut received = encode::<PackedBinaryField1x128b>(&code, &message);
+		corrupt(&mut rng, &mut received, 12, 20);
+		assert_eq!(code.decode(&received).unwrap(), message);
+
+		let mut received = encode::<PackedBinaryField1x128b>(&code, &message);
+		corrupt(&mut rng, &mut received, 0, 40);
+		assert_eq!(code.decode_erasures(&received).unwrap(), message);
+	}
+
+	#[test]
+	fn test_decode_beyond_radius() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (code, _) = setup::<BinaryField32b>(&mut rng);
+		let received = repeat_with(|| Some(<BinaryField32b as Field>::random(&mut rng)))
+			.take(code.len())
+			.collect::<Vec<_>>();
+		assert_matches!(code.decode(&received), Err(Error::DecodingFailure));
+
+		assert_matches!(code.decode(&received[1..]), Err(Error::IncorrectLength { expected: 64 }));
+	}
+}
@@ -0,0 +1,16 @@
+// Copyright 2025 Irreducible Inc.
+
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+	#[error("the received word must have exactly {expected} symbols")]
+	IncorrectLength { expected: usize },
+	#[error("{n_erasures} erasures exceed the maximum of {max_erasures} recoverable erasures")]
+	TooManyErasures {
+		n_erasures: usize,
+		max_erasures: usize,
+	},
+	#[error("the received word is not within the unique decoding radius of any codeword")]
+	DecodingFailure,
+	#[error("NTT error: {0}")]
+	NttError(#[from] binius_ntt::Error),
+}
@@ -13,7 +13,7 @@
 use std::marker::PhantomData;
 
 use binius_field::{BinaryField, ExtensionField, PackedField, RepackedExtension};
-use binius_maybe_rayon::prelude::*;
+use binius_hal::ComputationBackend;
 use binius_ntt::{AdditiveNTT, DynamicDispatchNTT, Error, NTTOptions, ThreadingSettings};
 use binius_utils::bail;
 use getset::CopyGetters;
@@ -29,6 +29,9 @@ where
 	log_dimension: usize,
 	#[getset(get_copy = "pub")]
 	log_inv_rate: usize,
+	/// Whether encoding is multithreaded, as chosen by the [`NTTOptions`] the code was created
+	/// with.
+	#[getset(get_copy = "pub")]
 	multithreaded: bool,
 	_p_marker: PhantomData<P>,
 }
@@ -54,6 +57,7 @@ where
 					log_threads: ntt_log_threads,
 				},
 				precompute_twiddles: ntt_options.precompute_twiddles,
+				strategy: ntt_options.strategy,
 			},
 		)?;
 
@@ -112,7 +116,15 @@ where
 	///
 	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field
 	///   elements.
-	fn encode_batch_inplace(&self, code: &mut [P], log_batch_size: usize) -> Result<(), Error> {
+	fn encode_batch_inplace<Backend>(
+		&self,
+		code: &mut [P],
+		log_batch_size: usize,
+		backend: &Backend,
+	) -> Result<(), binius_hal::Error>
+	where
+		Backend: ComputationBackend,
+	{
 		let _scope = tracing::trace_span!(
 			"Reed–Solomon encode",
 			log_len = self.log_len(),
@@ -120,7 +132,7 @@ where
 			symbol_bits = P::Scalar::N_BITS,
 		)
 		.entered();
-		if (code.len() << log_batch_size) < self.len() {
+		if code.len() * P::WIDTH < self.len() << log_batch_size {
 			bail!(Error::BufferTooSmall {
 				log_code_len: self.len(),
 			});
@@ -129,27 +141,14 @@ where
 			bail!(Error::PackingWidthMustDivideDimension);
 		}
 
-		let msgs_len = (self.dim() / P::WIDTH) << log_batch_size;
-		for i in 1..(1 << self.log_inv_rate) {
-			code.copy_within(0..msgs_len, i * msgs_len);
-		}
-
-		if self.multithreaded {
-			(0..(1 << self.log_inv_rate))
-				.into_par_iter()
-				.zip(code.par_chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| {
-					self.ntt
-						.forward_transform(data, i, log_batch_size, self.log_dim())
-				})
-		} else {
-			(0..(1 << self.log_inv_rate))
-				.zip(code.chunks_exact_mut(msgs_len))
-				.try_for_each(|(i, data)| {
-					self.ntt
-						.forward_transform(data, i, log_batch_size, self.log_dim())
-				})
-		}
+		backend.reed_solomon_encode_batch(
+			&self.ntt,
+			self.log_dim(),
+			self.log_inv_rate,
+			log_batch_size,
+			self.multithreaded,
+			code,
+		)
 	}
 
 	/// Encode a batch of interleaved messages of extension field elements in-place in a provided
@@ -166,11 +165,50 @@ where
 	///
 	/// * If the `code` buffer does not have capacity for `len() << log_batch_size` field elements.
 	#[instrument(skip_all, level = "debug")]
-	pub fn encode_ext_batch_inplace<PE: RepackedExtension<P>>(
+	pub fn encode_ext_batch_inplace<PE, Backend>(
 		&self,
 		code: &mut [PE],
 		log_batch_size: usize,
-	) -> Result<(), Error> {
-		self.encode_batch_inplace(PE::cast_bases_mut(code), log_batch_size + PE::Scalar::LOG_DEGREE)
+		backend: &Backend,
+	) -> Result<(), binius_hal::Error>
+	where
+		PE: RepackedExtension<P>,
+		Backend: ComputationBackend,
+	{
+		self.encode_batch_inplace(
+			PE::cast_bases_mut(code),
+			log_batch_size + PE::Scalar::LOG_DEGREE,
+			backend,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use assert_matches::assert_matches;
+	use binius_field::PackedBinaryField4x32b;
+	use binius_hal::make_portable_backend;
+
+	use super::*;
+
+	type P = PackedBinaryField4x32b;
+
+	#[test]
+	fn test_encode_batch_buffer_length_counts_scalars() {
+		let backend = make_portable_backend();
+		let code = ReedSolomonCode::<P>::new(4, 2, &NTTOptions::default()).unwrap();
+
+		// A buffer of exactly `len() << log_batch_size` scalars is accepted, even though it holds
+		// fewer packed elements than that.
+		let mut buffer = vec![P::default(); code.len() / P::WIDTH];
+		code.encode_ext_batch_inplace(&mut buffer, 0, &backend)
+			.unwrap();
+
+		// A buffer holding `len()` scalars is too small for a batch of four messages.
+		let mut buffer = vec![P::default(); code.len() / P::WIDTH];
+		assert_matches!(
+			code.encode_ext_batch_inplace(&mut buffer, 2, &backend),
+			Err(binius_hal::Error::NttError(Error::BufferTooSmall { .. }))
+		);
 	}
 }
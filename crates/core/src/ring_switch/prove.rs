@@ -20,7 +20,7 @@ use crate::{
 	piop::PIOPSumcheckClaim,
 	ring_switch::{common::EvalClaimSuffixDesc, eq_ind::RingSwitchEqInd},
 	tower::{PackedTop, TowerFamily},
-	transcript::ProverTranscript,
+	transcript::{labels, ProverTranscript},
 	witness::MultilinearWitness,
 };
 
@@ -54,6 +54,8 @@ where
 		));
 	}
 
+	transcript.phase(labels::RING_SWITCH);
+
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
 	// evaluation point prefix.
 	let n_mixing_challenges = log2_ceil_usize(system.sumcheck_claim_descs.len());
@@ -294,11 +294,12 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	)
 	.unwrap();
 
+	let backend = make_portable_backend();
 	let CommitOutput {
 		commitment,
 		committed,
 		codeword,
-	} = piop::commit(&fri_params, merkle_prover, &committed_multilins).unwrap();
+	} = piop::commit(&fri_params, merkle_prover, &committed_multilins, &backend).unwrap();
 
 	let eval_claims = setup_test_eval_claims(&mut rng, oracles, &witness_index);
 
@@ -310,7 +311,6 @@ fn commit_prove_verify_piop<U, Tower, MTScheme, MTProver>(
 	let mut proof = ProverTranscript::<HasherChallenger<Groestl256>>::new();
 	proof.message().write(&commitment);
 
-	let backend = make_portable_backend();
 	let ReducedWitness {
 		transparents: transparent_multilins,
 		sumcheck_claims,
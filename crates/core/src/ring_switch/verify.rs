@@ -5,7 +5,6 @@ use std::{iter, sync::Arc};
 use binius_field::{Field, TowerField};
 use binius_math::{MultilinearExtension, MultilinearQuery};
 use binius_utils::checked_arithmetics::log2_ceil_usize;
-use bytes::Buf;
 use itertools::izip;
 
 use super::eq_ind::RowBatchCoeffs;
@@ -18,7 +17,7 @@ use crate::{
 		EvalClaimSuffixDesc, EvalClaimSystem, PIOPSumcheckClaimDesc, VerificationError,
 	},
 	tower::{PackedTop, TowerFamily},
-	transcript::{TranscriptReader, VerifierTranscript},
+	transcript::{labels, ScalarBuf, TranscriptReader, VerifierTranscript},
 };
 
 type FExt<Tower> = <Tower as TowerFamily>::B128;
@@ -38,6 +37,8 @@ where
 	Tower: TowerFamily<B128 = F>,
 	Challenger_: Challenger,
 {
+	transcript.phase(labels::RING_SWITCH)?;
+
 	// Sample enough randomness to batch tensor elements corresponding to claims that share an
 	// evaluation point prefix.
 	let n_mixing_challenges = log2_ceil_usize(system.sumcheck_claim_descs.len());
@@ -107,7 +108,7 @@ fn verify_receive_tensor_elems<F, Tower, B>(
 where
 	F: TowerField + PackedTop<Tower>,
 	Tower: TowerFamily<B128 = F>,
-	B: Buf,
+	B: ScalarBuf,
 {
 	let expected_tensor_elem_evals = compute_mixed_evaluations(
 		system
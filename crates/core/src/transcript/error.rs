@@ -6,6 +6,12 @@ pub enum Error {
 	TranscriptNotEmpty { remaining: usize },
 	#[error("Not enough bytes in the buffer")]
 	NotEnoughBytes,
+	#[error("Transcript label mismatch: expected {expected:?}, found {found:?} after {history:?}")]
+	LabelMismatch {
+		expected: String,
+		found: String,
+		history: Vec<String>,
+	},
 	#[error("Serialization error: {0}")]
 	Serialization(#[from] binius_utils::SerializationError),
 }
@@ -0,0 +1,285 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Labelled transcript messages and protocol domain separation.
+//!
+//! The methods in this module are an optional layer on top of the untyped [`message`] and
+//! [`observe`] buffers. A protocol may start with [`domain_separator`] and tag each phase and
+//! prover message with a static label. The label and the length of each labelled message are
+//! absorbed into the challenger, so two protocols, or two phases of one protocol, that happen to
+//! send the same bytes derive different challenges. The sumcheck, ring-switching, FRI and PIOP
+//! protocols and the FRI-Binius polynomial commitment scheme label each of their phases.
+//!
+//! When the transcript's debug flag is set, every label is also logged with [`tracing`] and written
+//! to the advice tape. The verifier checks the labels it reads against the ones it expects and
+//! returns [`Error::LabelMismatch`] at the first divergence, together with the sequence of labels
+//! that matched before it. The prover and the verifier must agree on the debug flag.
+//!
+//! [`message`]: ProverTranscript::message
+//! [`observe`]: ProverTranscript::observe
+//! [`domain_separator`]: ProverTranscript::domain_separator
+
+use binius_field::TowerField;
+use binius_utils::{DeserializeBytes, SerializeBytes};
+use bytes::{Buf, BufMut};
+
+use super::{Error, ProverTranscript, TranscriptReader, VerifierTranscript};
+use crate::fiat_shamir::Challenger;
+
+const PROTOCOL_TAG: u8 = 0x01;
+const PHASE_TAG: u8 = 0x02;
+const MESSAGE_TAG: u8 = 0x03;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LabelKind {
+	Protocol,
+	Phase,
+	Message { len: u64 },
+}
+
+impl LabelKind {
+	const fn tag(self) -> u8 {
+		match self {
+			Self::Protocol => PROTOCOL_TAG,
+			Self::Phase => PHASE_TAG,
+			Self::Message { .. } => MESSAGE_TAG,
+		}
+	}
+}
+
+/// Absorbs the tag, the length-prefixed label and, for messages, the message length.
+fn observe_label(observer: &mut impl BufMut, kind: LabelKind, label: &str) {
+	observer.put_u8(kind.tag());
+	observer.put_u64_le(label.len() as u64);
+	observer.put_slice(label.as_bytes());
+	if let LabelKind::Message { len } = kind {
+		observer.put_u64_le(len);
+	}
+}
+
+fn log_label(history: &[String], kind: LabelKind, label: &str) {
+	match kind {
+		LabelKind::Protocol => {
+			tracing::debug!(index = history.len(), protocol = label, "transcript domain separator")
+		}
+		LabelKind::Phase => {
+			tracing::debug!(index = history.len(), phase = label, "transcript phase")
+		}
+		LabelKind::Message { len } => {
+			tracing::debug!(index = history.len(), message = label, len, "transcript message")
+		}
+	}
+}
+
+impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
+	/// Absorbs the protocol name into the Fiat-Shamir state.
+	///
+	/// This should be called once at the start of a protocol so that transcripts of different
+	/// protocols are domain-separated.
+	pub fn domain_separator(&mut self, protocol: &str) {
+		self.label(LabelKind::Protocol, protocol);
+	}
+
+	/// Absorbs a phase label into the Fiat-Shamir state.
+	pub fn phase(&mut self, label: &str) {
+		self.label(LabelKind::Phase, label);
+	}
+
+	/// Writes a labelled slice of values to the transcript tape.
+	///
+	/// The label and the number of values are absorbed before the values themselves.
+	pub fn write_labelled<T: SerializeBytes>(&mut self, label: &str, values: &[T]) {
+		self.label(
+			LabelKind::Message {
+				len: values.len() as u64,
+			},
+			label,
+		);
+		self.message().write_slice(values);
+	}
+
+	/// Writes a labelled slice of field elements to the transcript tape.
+	///
+	/// The label and the number of elements are absorbed before the elements themselves.
+	pub fn write_labelled_scalars<F: TowerField>(&mut self, label: &str, elems: &[F]) {
+		self.label(
+			LabelKind::Message {
+				len: elems.len() as u64,
+			},
+			label,
+		);
+		self.message().write_scalar_slice(elems);
+	}
+
+	/// Returns the labels recorded so far.
+	///
+	/// Labels are only recorded when the debug flag is set.
+	pub fn label_history(&self) -> &[String] {
+		&self.label_history
+	}
+
+	fn label(&mut self, kind: LabelKind, label: &str) {
+		observe_label(self.combined.challenger.observer(), kind, label);
+		if self.debug_assertions {
+			log_label(&self.label_history, kind, label);
+			let advice = &mut self.combined.buffer;
+			advice.put_u8(kind.tag());
+			advice.put_u64_le(label.len() as u64);
+			advice.put_slice(label.as_bytes());
+			self.label_history.push(label.to_string());
+		}
+	}
+}
+
+impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
+	/// Absorbs the protocol name into the Fiat-Shamir state.
+	///
+	/// This must mirror [`ProverTranscript::domain_separator`].
+	pub fn domain_separator(&mut self, protocol: &str) -> Result<(), Error> {
+		self.label(LabelKind::Protocol, protocol)
+	}
+
+	/// Absorbs a phase label into the Fiat-Shamir state.
+	///
+	/// This must mirror [`ProverTranscript::phase`].
+	pub fn phase(&mut self, label: &str) -> Result<(), Error> {
+		self.label(LabelKind::Phase, label)
+	}
+
+	/// Reads a labelled slice of `n` values from the transcript tape.
+	///
+	/// This must mirror [`ProverTranscript::write_labelled`].
+	pub fn read_labelled<T: DeserializeBytes>(
+		&mut self,
+		label: &str,
+		n: usize,
+	) -> Result<Vec<T>, Error> {
+		self.label(LabelKind::Message { len: n as u64 }, label)?;
+		self.message().read_vec(n)
+	}
+
+	/// Reads a labelled slice of `n` field elements from the transcript tape.
+	///
+	/// This must mirror [`ProverTranscript::write_labelled_scalars`].
+	pub fn read_labelled_scalars<F: TowerField>(
+		&mut self,
+		label: &str,
+		n: usize,
+	) -> Result<Vec<F>, Error> {
+		self.label(LabelKind::Message { len: n as u64 }, label)?;
+		self.message().read_scalar_slice(n)
+	}
+
+	/// Returns the labels recorded so far.
+	///
+	/// Labels are only recorded when the debug flag is set.
+	pub fn label_history(&self) -> &[String] {
+		&self.label_history
+	}
+
+	fn label(&mut self, kind: LabelKind, label: &str) -> Result<(), Error> {
+		observe_label(self.combined.challenger.observer(), kind, label);
+		if self.debug_assertions {
+			log_label(&self.label_history, kind, label);
+			let (found_tag, found_label) = read_advice_label(&mut self.decommitment())?;
+			if found_tag != kind.tag() || found_label != label.as_bytes() {
+				return Err(Error::LabelMismatch {
+					expected: label.to_string(),
+					found: String::from_utf8_lossy(&found_label).into_owned(),
+					history: self.label_history.clone(),
+				});
+			}
+			self.label_history.push(label.to_string());
+		}
+		Ok(())
+	}
+}
+
+fn read_advice_label<B: Buf>(reader: &mut TranscriptReader<B>) -> Result<(u8, Vec<u8>), Error> {
+	let mut tag = [0u8];
+	reader.read_bytes(&mut tag)?;
+	let mut len = [0u8; size_of::<u64>()];
+	reader.read_bytes(&mut len)?;
+	let len = u64::from_le_bytes(len) as usize;
+	if reader.buffer().remaining() < len {
+		return Err(Error::NotEnoughBytes);
+	}
+	let mut label = vec![0u8; len];
+	reader.read_bytes(&mut label)?;
+	Ok((tag[0], label))
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField128b, BinaryField32b};
+	use groestl_crypto::Groestl256;
+
+	use super::*;
+	use crate::fiat_shamir::{CanSample, HasherChallenger};
+
+	type Transcript = ProverTranscript<HasherChallenger<Groestl256>>;
+
+	fn run_prover(debug: bool, protocol: &str, phase: &str) -> (Vec<u8>, BinaryField128b) {
+		let mut transcript = Transcript::new();
+		transcript.set_debug(debug);
+		transcript.domain_separator(protocol);
+		transcript.phase(phase);
+		transcript
+			.write_labelled_scalars("evals", &[BinaryField32b::new(1), BinaryField32b::new(2)]);
+		let challenge = transcript.sample();
+		transcript.write_labelled("round", &[BinaryField128b::new(3)]);
+		(transcript.finalize(), challenge)
+	}
+
+	#[test]
+	fn test_labelled_prover_verifier_agree() {
+		for debug in [false, true] {
+			let (proof, prover_challenge) = run_prover(debug, "test", "commit");
+
+			let mut verifier = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+			verifier.set_debug(debug);
+			verifier.domain_separator("test").unwrap();
+			verifier.phase("commit").unwrap();
+			let evals: Vec<BinaryField32b> = verifier.read_labelled_scalars("evals", 2).unwrap();
+			assert_eq!(evals, [BinaryField32b::new(1), BinaryField32b::new(2)]);
+			let challenge: BinaryField128b = verifier.sample();
+			assert_eq!(challenge, prover_challenge);
+			let round: Vec<BinaryField128b> = verifier.read_labelled("round", 1).unwrap();
+			assert_eq!(round, [BinaryField128b::new(3)]);
+			if debug {
+				assert_eq!(verifier.label_history(), ["test", "commit", "evals", "round"]);
+			} else {
+				assert!(verifier.label_history().is_empty());
+			}
+			verifier.finalize().unwrap();
+		}
+	}
+
+	#[test]
+	fn test_labels_separate_challenges() {
+		let (_, challenge) = run_prover(false, "test", "commit");
+		assert_ne!(challenge, run_prover(false, "other", "commit").1);
+		assert_ne!(challenge, run_prover(false, "test", "query").1);
+		assert_ne!(challenge, run_prover(false, "testcommit", "").1);
+	}
+
+	#[test]
+	fn test_label_mismatch_is_reported() {
+		let (proof, _) = run_prover(true, "test", "commit");
+
+		let mut verifier = VerifierTranscript::<HasherChallenger<Groestl256>>::new(proof);
+		verifier.set_debug(true);
+		verifier.domain_separator("test").unwrap();
+		match verifier.phase("query") {
+			Err(Error::LabelMismatch {
+				expected,
+				found,
+				history,
+			}) => {
+				assert_eq!(expected, "query");
+				assert_eq!(found, "commit");
+				assert_eq!(history, ["test"]);
+			}
+			result => panic!("expected a label mismatch, got {result:?}"),
+		}
+	}
+}
@@ -0,0 +1,24 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Labels that the protocols of this crate absorb into the transcript.
+//!
+//! The constraint system proof starts with [`CONSTRAINT_SYSTEM`] as its domain separator, and each
+//! sub-protocol marks its start with a [`ProverTranscript::phase`] label. Labels bind the proof
+//! structure to the Fiat-Shamir state, and with debug assertions enabled the verifier checks that
+//! it reads the phases in the order the prover wrote them. Every label must be unique.
+//!
+//! [`ProverTranscript::phase`]: super::ProverTranscript::phase
+
+/// Domain separator of the constraint system protocol.
+pub const CONSTRAINT_SYSTEM: &str = "binius::constraint_system";
+
+pub const EVALCHECK: &str = "evalcheck";
+pub const FRI_BINIUS_EVALUATION: &str = "fri_binius_pcs::evaluation";
+pub const FRI_QUERY: &str = "fri::query";
+pub const FRI_SUMCHECK: &str = "piop::fri_sumcheck";
+pub const GKR_CIRCUIT: &str = "gkr_circuit";
+pub const GKR_EXP: &str = "gkr_exp";
+pub const GKR_GPA: &str = "gkr_gpa";
+pub const RING_SWITCH: &str = "ring_switch";
+pub const SUMCHECK: &str = "sumcheck";
+pub const ZEROCHECK: &str = "zerocheck";
@@ -13,8 +13,14 @@
 //! the Merkle leaf opening at that index in the advice tape.
 
 mod error;
+mod labelled;
+pub(crate) mod labels;
 
-use std::{iter::repeat_with, slice};
+use std::{
+	iter::repeat_with,
+	ops::{Deref, DerefMut},
+	slice,
+};
 
 use binius_field::{PackedField, TowerField};
 use binius_utils::{DeserializeBytes, SerializationMode, SerializeBytes};
@@ -32,6 +38,7 @@ use crate::fiat_shamir::{CanSample, CanSampleBits, Challenger};
 pub struct ProverTranscript<Challenger> {
 	combined: FiatShamirBuf<BytesMut, Challenger>,
 	debug_assertions: bool,
+	label_history: Vec<String>,
 }
 
 /// Verifier transcript over some Challenger that reads from the internal tape and `CanSample<F: TowerField>`
@@ -42,12 +49,13 @@ pub struct ProverTranscript<Challenger> {
 pub struct VerifierTranscript<Challenger> {
 	combined: FiatShamirBuf<Bytes, Challenger>,
 	debug_assertions: bool,
+	label_history: Vec<String>,
 }
 
 #[derive(Debug, Default)]
 struct FiatShamirBuf<Inner, Challenger> {
 	buffer: Inner,
-	challenger: Challenger,
+	challenger: ObserverBuf<Challenger>,
 }
 
 impl<Inner: Buf, Challenger_: Challenger> Buf for FiatShamirBuf<Inner, Challenger_> {
@@ -70,6 +78,14 @@ impl<Inner: Buf, Challenger_: Challenger> Buf for FiatShamirBuf<Inner, Challenge
 	}
 }
 
+impl<Inner: ScalarBuf, Challenger_: Challenger> ScalarBuf for FiatShamirBuf<Inner, Challenger_> {
+	fn get_scalars<F: TowerField>(&mut self, elems: &mut [F]) -> Result<(), Error> {
+		self.buffer.get_scalars(elems)?;
+		self.challenger.observe_scalars(elems);
+		Ok(())
+	}
+}
+
 unsafe impl<Inner: BufMut, Challenger_: Challenger> BufMut for FiatShamirBuf<Inner, Challenger_> {
 	fn remaining_mut(&self) -> usize {
 		self.buffer.remaining_mut()
@@ -94,6 +110,92 @@ unsafe impl<Inner: BufMut, Challenger_: Challenger> BufMut for FiatShamirBuf<Inn
 	}
 }
 
+impl<Inner: ScalarBufMut, Challenger_: Challenger> ScalarBufMut
+	for FiatShamirBuf<Inner, Challenger_>
+{
+	fn put_scalars<F: TowerField>(&mut self, elems: &[F]) {
+		self.buffer.put_scalars(elems);
+		self.challenger.observe_scalars(elems);
+	}
+}
+
+/// A buffer that writes to the observer of a challenger.
+///
+/// Field elements written to this buffer are observed with [`Challenger::observe_scalars`].
+#[derive(Debug, Default)]
+struct ObserverBuf<Challenger> {
+	challenger: Challenger,
+}
+
+impl<Challenger_> Deref for ObserverBuf<Challenger_> {
+	type Target = Challenger_;
+
+	fn deref(&self) -> &Self::Target {
+		&self.challenger
+	}
+}
+
+impl<Challenger_> DerefMut for ObserverBuf<Challenger_> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		&mut self.challenger
+	}
+}
+
+unsafe impl<Challenger_: Challenger> BufMut for ObserverBuf<Challenger_> {
+	fn remaining_mut(&self) -> usize {
+		usize::MAX
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		self.challenger.observer().advance_mut(cnt);
+	}
+
+	fn chunk_mut(&mut self) -> &mut UninitSlice {
+		self.challenger.observer().chunk_mut()
+	}
+}
+
+impl<Challenger_: Challenger> ScalarBufMut for ObserverBuf<Challenger_> {
+	fn put_scalars<F: TowerField>(&mut self, elems: &[F]) {
+		self.challenger.observe_scalars(elems);
+	}
+}
+
+/// A transcript buffer that field elements can be written to.
+///
+/// Buffers that are observed by a challenger pass the elements to
+/// [`Challenger::observe_scalars`], so that the challenger can absorb them natively.
+pub trait ScalarBufMut: BufMut {
+	/// Writes the canonical tower serialization of the elements.
+	fn put_scalars<F: TowerField>(&mut self, elems: &[F]);
+}
+
+impl ScalarBufMut for BytesMut {
+	fn put_scalars<F: TowerField>(&mut self, elems: &[F]) {
+		for elem in elems {
+			SerializeBytes::serialize(elem, &mut *self, SerializationMode::CanonicalTower)
+				.expect("TODO: propagate error");
+		}
+	}
+}
+
+/// A transcript buffer that field elements can be read from.
+///
+/// This mirrors [`ScalarBufMut`] on the verifier side.
+pub trait ScalarBuf: Buf {
+	/// Reads the canonical tower serialization of the elements.
+	fn get_scalars<F: TowerField>(&mut self, elems: &mut [F]) -> Result<(), Error>;
+}
+
+impl ScalarBuf for Bytes {
+	fn get_scalars<F: TowerField>(&mut self, elems: &mut [F]) -> Result<(), Error> {
+		for elem in elems {
+			*elem = DeserializeBytes::deserialize(&mut *self, SerializationMode::CanonicalTower)?;
+		}
+		Ok(())
+	}
+}
+
 impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 	/// Creates a new prover transcript.
 	///
@@ -103,6 +205,7 @@ impl<Challenger_: Default + Challenger> ProverTranscript<Challenger_> {
 		Self {
 			combined: Default::default(),
 			debug_assertions: cfg!(debug_assertions),
+			label_history: Vec::new(),
 		}
 	}
 
@@ -125,7 +228,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// Sets the debug flag.
 	///
 	/// This flag is used to enable debug assertions in the [`TranscriptReader`] and
-	/// [`TranscriptWriter`] methods.
+	/// [`TranscriptWriter`] methods, and to check and log the labels of labelled messages.
 	pub const fn set_debug(&mut self, debug: bool) {
 		self.debug_assertions = debug;
 	}
@@ -134,12 +237,12 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// proof tape.
 	///
 	/// This method should be used to observe the input statement.
-	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut + 'b>
+	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl ScalarBufMut + 'b>
 	where
 		'a: 'b,
 	{
 		TranscriptWriter {
-			buffer: self.combined.challenger.observer(),
+			buffer: &mut self.combined.challenger,
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -151,7 +254,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// a Merkle tree root as a commitment, and later sends leaf openings. The leaf openings should
 	/// be written using [`Self::decommitment`] because they are verified with respect to the
 	/// previously sent Merkle root.
-	pub fn decommitment(&mut self) -> TranscriptWriter<impl BufMut> {
+	pub fn decommitment(&mut self) -> TranscriptWriter<impl ScalarBufMut> {
 		TranscriptWriter {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
@@ -161,7 +264,7 @@ impl<Challenger_: Challenger> ProverTranscript<Challenger_> {
 	/// Returns a writeable buffer that observes the data written and writes it to the proof tape.
 	///
 	/// This method should be used by default to write prover messages in an interactive protocol.
-	pub fn message<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut>
+	pub fn message<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl ScalarBufMut>
 	where
 		'a: 'b,
 	{
@@ -176,10 +279,11 @@ impl<Challenger_: Default + Challenger> VerifierTranscript<Challenger_> {
 	pub fn new(vec: Vec<u8>) -> Self {
 		Self {
 			combined: FiatShamirBuf {
-				challenger: Challenger_::default(),
+				challenger: ObserverBuf::default(),
 				buffer: Bytes::from(vec),
 			},
 			debug_assertions: cfg!(debug_assertions),
+			label_history: Vec::new(),
 		}
 	}
 }
@@ -202,12 +306,12 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// proof tape.
 	///
 	/// This method should be used to observe the input statement.
-	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl BufMut + 'b>
+	pub fn observe<'a, 'b>(&'a mut self) -> TranscriptWriter<'b, impl ScalarBufMut + 'b>
 	where
 		'a: 'b,
 	{
 		TranscriptWriter {
-			buffer: self.combined.challenger.observer(),
+			buffer: &mut self.combined.challenger,
 			debug_assertions: self.debug_assertions,
 		}
 	}
@@ -215,7 +319,7 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// Returns a readable buffer that only reads the data from the proof tape, without observing it.
 	///
 	/// This method should only be used to read advice that was previously written to the transcript as an observed message.
-	pub fn decommitment(&mut self) -> TranscriptReader<impl Buf + '_> {
+	pub fn decommitment(&mut self) -> TranscriptReader<impl ScalarBuf + '_> {
 		TranscriptReader {
 			buffer: &mut self.combined.buffer,
 			debug_assertions: self.debug_assertions,
@@ -225,7 +329,7 @@ impl<Challenger_: Challenger> VerifierTranscript<Challenger_> {
 	/// Returns a readable buffer that observes the data read.
 	///
 	/// This method should be used by default to read verifier messages in an interactive protocol.
-	pub fn message<'a, 'b>(&'a mut self) -> TranscriptReader<'b, impl Buf>
+	pub fn message<'a, 'b>(&'a mut self) -> TranscriptReader<'b, impl ScalarBuf>
 	where
 		'a: 'b,
 	{
@@ -280,6 +384,17 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		Ok(())
 	}
 
+	pub fn read_debug(&mut self, msg: &str) {
+		if self.debug_assertions {
+			let msg_bytes = msg.as_bytes();
+			let mut buffer = vec![0; msg_bytes.len()];
+			assert!(self.read_bytes(&mut buffer).is_ok());
+			assert_eq!(msg_bytes, buffer);
+		}
+	}
+}
+
+impl<B: ScalarBuf> TranscriptReader<'_, B> {
 	pub fn read_scalar<F: TowerField>(&mut self) -> Result<F, Error> {
 		let mut out = F::default();
 		self.read_scalar_slice_into(slice::from_mut(&mut out))?;
@@ -287,12 +402,7 @@ impl<B: Buf> TranscriptReader<'_, B> {
 	}
 
 	pub fn read_scalar_slice_into<F: TowerField>(&mut self, buf: &mut [F]) -> Result<(), Error> {
-		let mut buffer = self.buffer();
-		for elem in buf {
-			let mode = SerializationMode::CanonicalTower;
-			*elem = DeserializeBytes::deserialize(&mut buffer, mode)?;
-		}
-		Ok(())
+		self.buffer().get_scalars(buf)
 	}
 
 	pub fn read_scalar_slice<F: TowerField>(&mut self, len: usize) -> Result<Vec<F>, Error> {
@@ -315,15 +425,6 @@ impl<B: Buf> TranscriptReader<'_, B> {
 		}
 		Ok(packed)
 	}
-
-	pub fn read_debug(&mut self, msg: &str) {
-		if self.debug_assertions {
-			let msg_bytes = msg.as_bytes();
-			let mut buffer = vec![0; msg_bytes.len()];
-			assert!(self.read_bytes(&mut buffer).is_ok());
-			assert_eq!(msg_bytes, buffer);
-		}
-	}
 }
 
 pub struct TranscriptWriter<'a, B: BufMut> {
@@ -360,17 +461,28 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 		});
 	}
 
+	pub fn write_debug(&mut self, msg: &str) {
+		if self.debug_assertions {
+			self.write_bytes(msg.as_bytes())
+		}
+	}
+
+	fn proof_size_event_wrapper<F: Fn(&mut B)>(&mut self, f: F) {
+		let buffer = self.buffer();
+		let start_bytes = buffer.remaining_mut();
+		f(buffer);
+		let end_bytes = buffer.remaining_mut();
+		tracing::event!(name: "proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
+	}
+}
+
+impl<B: ScalarBufMut> TranscriptWriter<'_, B> {
 	pub fn write_scalar<F: TowerField>(&mut self, f: F) {
 		self.write_scalar_slice(slice::from_ref(&f));
 	}
 
 	pub fn write_scalar_slice<F: TowerField>(&mut self, elems: &[F]) {
-		self.proof_size_event_wrapper(|buffer| {
-			for elem in elems {
-				SerializeBytes::serialize(elem, &mut *buffer, SerializationMode::CanonicalTower)
-					.expect("TODO: propagate error");
-			}
-		});
+		self.proof_size_event_wrapper(|buffer| buffer.put_scalars(elems));
 	}
 
 	pub fn write_packed<P: PackedField<Scalar: TowerField>>(&mut self, packed: P) {
@@ -384,20 +496,6 @@ impl<B: BufMut> TranscriptWriter<'_, B> {
 			self.write_packed(packed)
 		}
 	}
-
-	pub fn write_debug(&mut self, msg: &str) {
-		if self.debug_assertions {
-			self.write_bytes(msg.as_bytes())
-		}
-	}
-
-	fn proof_size_event_wrapper<F: Fn(&mut B)>(&mut self, f: F) {
-		let buffer = self.buffer();
-		let start_bytes = buffer.remaining_mut();
-		f(buffer);
-		let end_bytes = buffer.remaining_mut();
-		tracing::event!(name: "proof_size", tracing::Level::INFO, counter=true, incremental=true, value=start_bytes - end_bytes);
-	}
 }
 
 impl<F, Challenger_> CanSample<F> for VerifierTranscript<Challenger_>
@@ -406,9 +504,7 @@ where
 	Challenger_: Challenger,
 {
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		self.combined.challenger.sample_scalar()
 	}
 }
 
@@ -418,9 +514,7 @@ where
 	Challenger_: Challenger,
 {
 	fn sample(&mut self) -> F {
-		let mode = SerializationMode::CanonicalTower;
-		DeserializeBytes::deserialize(self.combined.challenger.sampler(), mode)
-			.expect("challenger has infinite buffer")
+		self.combined.challenger.sample_scalar()
 	}
 }
 
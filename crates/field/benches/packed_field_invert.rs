@@ -9,10 +9,15 @@ use binius_field::{
 		packed_aes_32::*, packed_aes_512::*, packed_aes_64::*, packed_aes_8::*,
 		packed_polyval_128::*, packed_polyval_256::*, packed_polyval_512::*,
 	},
+	batch_invert_or_zero, batch_invert_or_zero_par,
+	underlier::{UnderlierWithBitOps, WithUnderlier},
 	PackedField,
 };
 use cfg_if::cfg_if;
-use criterion::criterion_main;
+use criterion::{
+	criterion_group, criterion_main, measurement::WallTime, BenchmarkGroup, BenchmarkId, Criterion,
+	Throughput,
+};
 use packed_field_utils::benchmark_packed_operation;
 
 fn invert_main<T: PackedField>(val: T) -> T {
@@ -75,4 +80,49 @@ cfg_if! {
 	}
 }
 
-criterion_main!(invert);
+const BATCH_SIZE: usize = 1 << 12;
+
+fn benchmark_batch_invert<P>(group: &mut BenchmarkGroup<'_, WallTime>, label: &str)
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	let mut rng = rand::thread_rng();
+	let mut values = (0..BATCH_SIZE)
+		.map(|_| P::random(&mut rng))
+		.collect::<Vec<_>>();
+
+	group.throughput(Throughput::Elements((BATCH_SIZE * P::WIDTH) as u64));
+	group.bench_function(BenchmarkId::new(label, "element-wise"), |b| {
+		b.iter(|| {
+			for value in &mut values {
+				*value = value.invert_or_zero();
+			}
+		})
+	});
+	group.bench_function(BenchmarkId::new(label, "batch"), |b| {
+		b.iter(|| batch_invert_or_zero(&mut values))
+	});
+	group.bench_function(BenchmarkId::new(label, "batch_par"), |b| {
+		b.iter(|| batch_invert_or_zero_par(&mut values))
+	});
+}
+
+fn batch_invert_main(c: &mut Criterion) {
+	let mut group = c.benchmark_group("batch_invert");
+
+	benchmark_batch_invert::<PackedBinaryField16x8b>(&mut group, "PackedBinaryField16x8b");
+	benchmark_batch_invert::<PackedBinaryField8x16b>(&mut group, "PackedBinaryField8x16b");
+	benchmark_batch_invert::<PackedBinaryField4x32b>(&mut group, "PackedBinaryField4x32b");
+	benchmark_batch_invert::<PackedBinaryField2x64b>(&mut group, "PackedBinaryField2x64b");
+	benchmark_batch_invert::<PackedBinaryField1x128b>(&mut group, "PackedBinaryField1x128b");
+	benchmark_batch_invert::<PackedBinaryField4x128b>(&mut group, "PackedBinaryField4x128b");
+	benchmark_batch_invert::<PackedAESBinaryField16x8b>(&mut group, "PackedAESBinaryField16x8b");
+	benchmark_batch_invert::<PackedAESBinaryField4x32b>(&mut group, "PackedAESBinaryField4x32b");
+	benchmark_batch_invert::<PackedBinaryPolyval1x128b>(&mut group, "PackedBinaryPolyval1x128b");
+	benchmark_batch_invert::<PackedBinaryPolyval4x128b>(&mut group, "PackedBinaryPolyval4x128b");
+
+	group.finish();
+}
+
+criterion_group!(batch_invert, batch_invert_main);
+criterion_main!(invert, batch_invert);
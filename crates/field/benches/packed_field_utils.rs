@@ -293,6 +293,25 @@ macro_rules! benchmark_packed_operation {
 				ByteSlicedAES64x32b
 				ByteSlicedAES64x64b
 				ByteSlicedAES64x128b
+
+				// Byte sliced canonical tower fields
+				ByteSliced16x8b
+				ByteSliced16x16b
+				ByteSliced16x32b
+				ByteSliced16x64b
+				ByteSliced16x128b
+
+				ByteSliced32x8b
+				ByteSliced32x16b
+				ByteSliced32x32b
+				ByteSliced32x64b
+				ByteSliced32x128b
+
+				ByteSliced64x8b
+				ByteSliced64x16b
+				ByteSliced64x32b
+				ByteSliced64x64b
+				ByteSliced64x128b
 			]);
 	};
 }
@@ -0,0 +1,389 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runtime dispatch of packed field kernels based on the features of the executing CPU.
+//!
+//! The packed field types in [`crate::arch`] select their arithmetic strategies at compile time
+//! from the enabled target features, so a binary built for a baseline target does not use GFNI on
+//! the machine it runs on. [`Dispatcher`] detects the CPU features once and runs GFNI kernels for
+//! element-wise multiplication, squaring, inversion and additive NTT butterflies over packed 8-bit
+//! fields. Other packed fields use their compile-time arithmetic.
+//!
+//! There are no runtime-dispatched PCLMULQDQ/VPCLMULQDQ kernels for 128-bit fields, nor for linear
+//! transformations; those keep the strategies selected at build time. As the kernels only pay off
+//! for packed 8-bit fields, the dispatcher is meant for callers working over such fields rather
+//! than for generic code that mostly runs over wider fields.
+
+use std::sync::OnceLock;
+
+use cfg_if::cfg_if;
+
+use crate::{Error, PackedField};
+
+/// Instruction set extensions used by the dispatched kernels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CpuFeatures {
+	pub avx2: bool,
+	pub gfni: bool,
+	pub avx512f: bool,
+	pub avx512bw: bool,
+}
+
+impl CpuFeatures {
+	/// Queries the features of the executing CPU.
+	pub fn detect() -> Self {
+		cfg_if! {
+			if #[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))] {
+				Self {
+					avx2: is_x86_feature_detected!("avx2"),
+					gfni: is_x86_feature_detected!("gfni"),
+					avx512f: is_x86_feature_detected!("avx512f"),
+					avx512bw: is_x86_feature_detected!("avx512bw"),
+				}
+			} else {
+				Self::default()
+			}
+		}
+	}
+
+	/// Returns the features of the executing CPU, detecting them on the first call.
+	pub fn get() -> &'static Self {
+		static FEATURES: OnceLock<CpuFeatures> = OnceLock::new();
+		FEATURES.get_or_init(Self::detect)
+	}
+}
+
+/// Feature set a [`Dispatcher`] runs its kernels with, in increasing order of preference.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DispatchTarget {
+	/// The packed field arithmetic selected at build time.
+	Baseline,
+	/// 256-bit GFNI kernels.
+	Avx2Gfni,
+	/// 512-bit GFNI kernels.
+	Avx512Gfni,
+}
+
+impl DispatchTarget {
+	pub const ALL: [Self; 3] = [Self::Baseline, Self::Avx2Gfni, Self::Avx512Gfni];
+
+	/// Whether kernels for this target are compiled in and can run on a CPU with `features`.
+	pub const fn is_supported_by(self, features: &CpuFeatures) -> bool {
+		let has_gfni_kernels = cfg!(all(target_arch = "x86_64", not(feature = "stable_only")));
+		match self {
+			Self::Baseline => true,
+			Self::Avx2Gfni => has_gfni_kernels && features.avx2 && features.gfni,
+			Self::Avx512Gfni => {
+				has_gfni_kernels && features.avx512f && features.avx512bw && features.gfni
+			}
+		}
+	}
+
+	/// The most preferred target supported by a CPU with `features`.
+	pub fn best_for(features: &CpuFeatures) -> Self {
+		Self::ALL
+			.into_iter()
+			.rev()
+			.find(|target| target.is_supported_by(features))
+			.unwrap_or(Self::Baseline)
+	}
+}
+
+/// Runs packed field kernels for a [`DispatchTarget`] supported by the executing CPU.
+///
+/// All kernels produce the same results as the corresponding element-wise [`PackedField`]
+/// operations, whichever target is selected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dispatcher {
+	target: DispatchTarget,
+}
+
+impl Default for Dispatcher {
+	fn default() -> Self {
+		Self::detected()
+	}
+}
+
+impl Dispatcher {
+	/// Creates a dispatcher for the given target, failing if the executing CPU does not support it.
+	pub fn new(target: DispatchTarget) -> Result<Self, Error> {
+		if !target.is_supported_by(CpuFeatures::get()) {
+			return Err(Error::UnsupportedDispatchTarget(target));
+		}
+		Ok(Self { target })
+	}
+
+	/// Creates a dispatcher using the packed field arithmetic selected at build time.
+	pub const fn baseline() -> Self {
+		Self {
+			target: DispatchTarget::Baseline,
+		}
+	}
+
+	/// Returns the dispatcher for the best target supported by the executing CPU.
+	pub fn detected() -> Self {
+		static DETECTED: OnceLock<Dispatcher> = OnceLock::new();
+		*DETECTED.get_or_init(|| Self {
+			target: DispatchTarget::best_for(CpuFeatures::get()),
+		})
+	}
+
+	pub const fn target(&self) -> DispatchTarget {
+		self.target
+	}
+
+	/// Multiplies `lhs` by `rhs` element-wise in place.
+	pub fn mul_assign<P: PackedField>(&self, lhs: &mut [P], rhs: &[P]) -> Result<(), Error> {
+		if lhs.len() != rhs.len() {
+			return Err(Error::MismatchedLengths);
+		}
+
+		#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+		if self.apply_gfni_byte_kernel(x86_64::ByteOp::Mul, lhs, rhs) {
+			return Ok(());
+		}
+
+		for (lhs, rhs) in lhs.iter_mut().zip(rhs) {
+			*lhs *= *rhs;
+		}
+		Ok(())
+	}
+
+	/// Squares every element of `values` in place.
+	pub fn square_assign<P: PackedField>(&self, values: &mut [P]) {
+		#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+		if self.apply_gfni_byte_kernel(x86_64::ByteOp::Square, values, &[]) {
+			return;
+		}
+
+		for value in values {
+			*value = value.square();
+		}
+	}
+
+	/// Replaces every element of `values` with its inverse, mapping zero to zero.
+	pub fn invert_or_zero_assign<P: PackedField>(&self, values: &mut [P]) {
+		#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+		if self.apply_gfni_byte_kernel(x86_64::ByteOp::InvertOrZero, values, &[]) {
+			return;
+		}
+
+		for value in values {
+			*value = value.invert_or_zero();
+		}
+	}
+
+	/// Applies the forward additive NTT butterfly `u += v * twiddle; v += u` element-wise.
+	pub fn forward_butterfly<P: PackedField>(
+		&self,
+		u: &mut [P],
+		v: &mut [P],
+		twiddle: P::Scalar,
+	) -> Result<(), Error> {
+		if u.len() != v.len() {
+			return Err(Error::MismatchedLengths);
+		}
+
+		let twiddle = P::broadcast(twiddle);
+
+		#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+		if self.apply_gfni_butterfly(true, u, v, twiddle) {
+			return Ok(());
+		}
+
+		for (u, v) in u.iter_mut().zip(v) {
+			*u += *v * twiddle;
+			*v += *u;
+		}
+		Ok(())
+	}
+
+	/// Applies the inverse additive NTT butterfly `v += u; u += v * twiddle` element-wise.
+	pub fn inverse_butterfly<P: PackedField>(
+		&self,
+		u: &mut [P],
+		v: &mut [P],
+		twiddle: P::Scalar,
+	) -> Result<(), Error> {
+		if u.len() != v.len() {
+			return Err(Error::MismatchedLengths);
+		}
+
+		let twiddle = P::broadcast(twiddle);
+
+		#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+		if self.apply_gfni_butterfly(false, u, v, twiddle) {
+			return Ok(());
+		}
+
+		for (u, v) in u.iter_mut().zip(v) {
+			*v += *u;
+			*u += *v * twiddle;
+		}
+		Ok(())
+	}
+
+	/// Runs the GFNI kernel for `op` over the bytes of `values` when the target supports GFNI and
+	/// `P` packs 8-bit fields. Returns whether the kernel was applied.
+	#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+	fn apply_gfni_byte_kernel<P: PackedField>(
+		&self,
+		op: x86_64::ByteOp,
+		values: &mut [P],
+		rhs: &[P],
+	) -> bool {
+		let Some(tower) = x86_64::byte_packed_basis::<P>() else {
+			return false;
+		};
+
+		// Safety: `byte_packed_basis` only accepts packed fields with one element per byte.
+		let (values, rhs) = unsafe { (x86_64::as_bytes_mut(values), x86_64::as_bytes(rhs)) };
+		match self.target {
+			// Safety: the dispatcher is only constructed for targets supported by the CPU.
+			DispatchTarget::Avx2Gfni => unsafe {
+				x86_64::avx2_gfni_bytes::apply(op, values, rhs, tower)
+			},
+			// Safety: the dispatcher is only constructed for targets supported by the CPU.
+			DispatchTarget::Avx512Gfni => unsafe {
+				x86_64::avx512_gfni_bytes::apply(op, values, rhs, tower)
+			},
+			DispatchTarget::Baseline => return false,
+		}
+		true
+	}
+
+	/// Runs the GFNI butterfly kernel over the bytes of `u` and `v` when the target supports GFNI
+	/// and `P` packs 8-bit fields. Returns whether the kernel was applied.
+	#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+	fn apply_gfni_butterfly<P: PackedField>(
+		&self,
+		forward: bool,
+		u: &mut [P],
+		v: &mut [P],
+		twiddle: P,
+	) -> bool {
+		let Some(tower) = x86_64::byte_packed_basis::<P>() else {
+			return false;
+		};
+
+		// Safety: `byte_packed_basis` only accepts packed fields with one element per byte.
+		let (u, v, twiddle) = unsafe {
+			(x86_64::as_bytes_mut(u), x86_64::as_bytes_mut(v), x86_64::as_bytes(&[twiddle])[0])
+		};
+		match self.target {
+			// Safety: the dispatcher is only constructed for targets supported by the CPU, and
+			// `u` and `v` have the same length.
+			DispatchTarget::Avx2Gfni => unsafe {
+				x86_64::avx2_gfni_bytes::butterfly(forward, u, v, twiddle, tower)
+			},
+			// Safety: the dispatcher is only constructed for targets supported by the CPU, and
+			// `u` and `v` have the same length.
+			DispatchTarget::Avx512Gfni => unsafe {
+				x86_64::avx512_gfni_bytes::butterfly(forward, u, v, twiddle, tower)
+			},
+			DispatchTarget::Baseline => return false,
+		}
+		true
+	}
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "stable_only")))]
+use super::x86_64::dispatch as x86_64;
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		BinaryField8b, Field, PackedAESBinaryField16x8b, PackedAESBinaryField32x8b,
+		PackedBinaryField128x1b, PackedBinaryField16x8b, PackedBinaryField1x128b,
+		PackedBinaryField4x32b, PackedBinaryField64x8b, PackedBinaryField8x8b,
+	};
+
+	/// Dispatchers for every target supported by the executing CPU.
+	fn supported_dispatchers() -> Vec<Dispatcher> {
+		DispatchTarget::ALL
+			.into_iter()
+			.filter_map(|target| Dispatcher::new(target).ok())
+			.collect()
+	}
+
+	fn check_arithmetic_kernels<P: PackedField>(dispatcher: Dispatcher) {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Odd lengths exercise the partial vectors at the end of the byte kernels.
+		for len in [0, 1, 7, 33] {
+			let lhs = (0..len).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+			let mut rhs = (0..len).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+			if let Some(first) = rhs.first_mut() {
+				*first = P::zero();
+			}
+
+			let mut product = lhs.clone();
+			dispatcher.mul_assign(&mut product, &rhs).unwrap();
+			let expected = lhs.iter().zip(&rhs).map(|(&x, &y)| x * y);
+			assert!(product.iter().copied().eq(expected));
+
+			let mut square = lhs.clone();
+			dispatcher.square_assign(&mut square);
+			assert!(square.iter().copied().eq(lhs.iter().map(|x| x.square())));
+
+			let mut inverse = rhs.clone();
+			dispatcher.invert_or_zero_assign(&mut inverse);
+			assert!(inverse
+				.iter()
+				.copied()
+				.eq(rhs.iter().map(|x| x.invert_or_zero())));
+
+			let (mut u, mut v) = (lhs.clone(), rhs.clone());
+			let twiddle = <P::Scalar as Field>::random(&mut rng);
+			dispatcher
+				.forward_butterfly(&mut u, &mut v, twiddle)
+				.unwrap();
+			for i in 0..len {
+				let expected_u = lhs[i] + rhs[i] * twiddle;
+				assert_eq!(u[i], expected_u);
+				assert_eq!(v[i], rhs[i] + expected_u);
+			}
+			dispatcher
+				.inverse_butterfly(&mut u, &mut v, twiddle)
+				.unwrap();
+			assert_eq!((u, v), (lhs, rhs));
+		}
+	}
+
+	#[test]
+	fn test_supported_targets() {
+		let features = CpuFeatures::get();
+		for target in DispatchTarget::ALL {
+			assert_eq!(Dispatcher::new(target).is_ok(), target.is_supported_by(features));
+		}
+		assert!(DispatchTarget::Baseline.is_supported_by(&CpuFeatures::default()));
+		assert_eq!(Dispatcher::detected().target(), DispatchTarget::best_for(features));
+	}
+
+	#[test]
+	fn test_arithmetic_kernels() {
+		for dispatcher in supported_dispatchers() {
+			check_arithmetic_kernels::<PackedBinaryField16x8b>(dispatcher);
+			check_arithmetic_kernels::<PackedBinaryField8x8b>(dispatcher);
+			check_arithmetic_kernels::<PackedBinaryField64x8b>(dispatcher);
+			check_arithmetic_kernels::<PackedAESBinaryField16x8b>(dispatcher);
+			check_arithmetic_kernels::<PackedAESBinaryField32x8b>(dispatcher);
+			check_arithmetic_kernels::<PackedBinaryField128x1b>(dispatcher);
+			check_arithmetic_kernels::<PackedBinaryField4x32b>(dispatcher);
+			check_arithmetic_kernels::<PackedBinaryField1x128b>(dispatcher);
+		}
+	}
+
+	#[test]
+	fn test_mismatched_lengths() {
+		let dispatcher = Dispatcher::baseline();
+		let mut lhs = vec![PackedBinaryField16x8b::zero(); 2];
+		let mut rhs = vec![PackedBinaryField16x8b::zero(); 3];
+		assert!(matches!(dispatcher.mul_assign(&mut lhs, &rhs), Err(Error::MismatchedLengths)));
+		assert!(matches!(
+			dispatcher.forward_butterfly(&mut lhs, &mut rhs, BinaryField8b::ONE),
+			Err(Error::MismatchedLengths)
+		));
+	}
+}
@@ -4,6 +4,7 @@ use cfg_if::cfg_if;
 
 mod arch_optimal;
 mod binary_utils;
+pub mod dispatch;
 mod strategies;
 
 cfg_if! {
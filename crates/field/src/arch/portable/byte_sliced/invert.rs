@@ -1,26 +1,25 @@
 // Copyright 2024-2025 Irreducible Inc.
 use super::{
-	multiply::{mul_alpha, mul_main},
+	multiply::{mul_alpha, mul_main, ByteSlicedBaseField},
 	square::square_main,
 };
 use crate::{
 	tower_levels::{TowerLevel, TowerLevelWithArithOps},
-	underlier::WithUnderlier,
-	AESTowerField8b, PackedField,
+	PackedField,
 };
 
 #[inline(always)]
-pub fn invert_or_zero<P: PackedField<Scalar = AESTowerField8b>, Level: TowerLevel>(
+pub fn invert_or_zero<P: PackedField<Scalar: ByteSlicedBaseField>, Level: TowerLevel>(
 	field_element: &Level::Data<P>,
 	destination: &mut Level::Data<P>,
 ) {
-	let base_alpha = P::broadcast(AESTowerField8b::from_underlier(0xd3));
+	let base_alpha = P::broadcast(P::Scalar::ALPHA);
 
 	inv_main::<P, Level>(field_element, destination, base_alpha);
 }
 
 #[inline(always)]
-fn inv_main<P: PackedField<Scalar = AESTowerField8b>, Level: TowerLevel>(
+fn inv_main<P: PackedField<Scalar: ByteSlicedBaseField>, Level: TowerLevel>(
 	field_element: &Level::Data<P>,
 	destination: &mut Level::Data<P>,
 	base_alpha: P,
@@ -191,4 +191,55 @@ pub mod tests {
 	define_byte_sliced_test!(tests_8x64x8b, ByteSlicedAES8x64x8b, AESTowerField8b);
 	define_byte_sliced_test!(tests_4x64x8b, ByteSlicedAES4x64x8b, AESTowerField8b);
 	define_byte_sliced_test!(tests_2x64x8b, ByteSlicedAES2x64x8b, AESTowerField8b);
+
+	// 128-bit byte-sliced canonical tower
+	define_byte_sliced_test!(tests_canonical_16x128, ByteSliced16x128b, BinaryField128b);
+	define_byte_sliced_test!(tests_canonical_16x64, ByteSliced16x64b, BinaryField64b);
+	define_byte_sliced_test!(tests_canonical_16x32, ByteSliced16x32b, BinaryField32b);
+	define_byte_sliced_test!(tests_canonical_16x16, ByteSliced16x16b, BinaryField16b);
+	define_byte_sliced_test!(tests_canonical_16x8, ByteSliced16x8b, BinaryField8b);
+
+	define_byte_sliced_test!(tests_canonical_16x16x8b, ByteSliced16x16x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_8x16x8b, ByteSliced8x16x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_4x16x8b, ByteSliced4x16x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_2x16x8b, ByteSliced2x16x8b, BinaryField8b);
+
+	// 256-bit byte-sliced canonical tower
+	define_byte_sliced_test!(tests_canonical_32x128, ByteSliced32x128b, BinaryField128b);
+	define_byte_sliced_test!(tests_canonical_32x64, ByteSliced32x64b, BinaryField64b);
+	define_byte_sliced_test!(tests_canonical_32x32, ByteSliced32x32b, BinaryField32b);
+	define_byte_sliced_test!(tests_canonical_32x16, ByteSliced32x16b, BinaryField16b);
+	define_byte_sliced_test!(tests_canonical_32x8, ByteSliced32x8b, BinaryField8b);
+
+	define_byte_sliced_test!(tests_canonical_16x32x8b, ByteSliced16x32x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_8x32x8b, ByteSliced8x32x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_4x32x8b, ByteSliced4x32x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_2x32x8b, ByteSliced2x32x8b, BinaryField8b);
+
+	// 512-bit byte-sliced canonical tower
+	define_byte_sliced_test!(tests_canonical_64x128, ByteSliced64x128b, BinaryField128b);
+	define_byte_sliced_test!(tests_canonical_64x64, ByteSliced64x64b, BinaryField64b);
+	define_byte_sliced_test!(tests_canonical_64x32, ByteSliced64x32b, BinaryField32b);
+	define_byte_sliced_test!(tests_canonical_64x16, ByteSliced64x16b, BinaryField16b);
+	define_byte_sliced_test!(tests_canonical_64x8, ByteSliced64x8b, BinaryField8b);
+
+	define_byte_sliced_test!(tests_canonical_16x64x8b, ByteSliced16x64x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_8x64x8b, ByteSliced8x64x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_4x64x8b, ByteSliced4x64x8b, BinaryField8b);
+	define_byte_sliced_test!(tests_canonical_2x64x8b, ByteSliced2x64x8b, BinaryField8b);
+
+	mod tests_alpha {
+		use super::super::multiply::ByteSlicedBaseField;
+		use crate::{AESTowerField8b, BinaryField8b};
+
+		fn check_alpha<F: ByteSlicedBaseField>() {
+			assert_eq!(F::ALPHA, F::ONE.mul_primitive(2).unwrap());
+		}
+
+		#[test]
+		fn test_alpha() {
+			check_alpha::<BinaryField8b>();
+			check_alpha::<AESTowerField8b>();
+		}
+	}
 }
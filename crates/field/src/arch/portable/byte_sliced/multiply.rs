@@ -1,24 +1,38 @@
 // Copyright 2024-2025 Irreducible Inc.
 use crate::{
 	tower_levels::{TowerLevel, TowerLevelWithArithOps},
-	underlier::WithUnderlier,
-	AESTowerField8b, PackedField,
+	AESTowerField8b, BinaryField8b, PackedField, TowerField,
 };
 
+/// An 8-bit tower field that byte-sliced representations are built on.
+pub trait ByteSlicedBaseField: TowerField {
+	/// The element multiplied by the top limb when reducing a product of 16-bit tower elements,
+	/// i.e. the top tower generator of the 8-bit field.
+	const ALPHA: Self;
+}
+
+impl ByteSlicedBaseField for BinaryField8b {
+	const ALPHA: Self = Self(0x10);
+}
+
+impl ByteSlicedBaseField for AESTowerField8b {
+	const ALPHA: Self = Self(0xd3);
+}
+
 #[inline(always)]
-pub fn mul<P: PackedField<Scalar = AESTowerField8b>, Level: TowerLevel>(
+pub fn mul<P: PackedField<Scalar: ByteSlicedBaseField>, Level: TowerLevel>(
 	field_element_a: &Level::Data<P>,
 	field_element_b: &Level::Data<P>,
 	destination: &mut Level::Data<P>,
 ) {
-	let base_alpha = P::broadcast(AESTowerField8b::from_underlier(0xd3));
+	let base_alpha = P::broadcast(P::Scalar::ALPHA);
 	mul_main::<true, P, Level>(field_element_a, field_element_b, destination, base_alpha);
 }
 
 #[inline(always)]
 pub fn mul_alpha<
 	const WRITING_TO_ZEROS: bool,
-	P: PackedField<Scalar = AESTowerField8b>,
+	P: PackedField<Scalar: ByteSlicedBaseField>,
 	Level: TowerLevel,
 >(
 	field_element: &Level::Data<P>,
@@ -58,7 +72,7 @@ pub fn mul_alpha<
 #[inline(always)]
 pub fn mul_main<
 	const WRITING_TO_ZEROS: bool,
-	P: PackedField<Scalar = AESTowerField8b>,
+	P: PackedField<Scalar: ByteSlicedBaseField>,
 	Level: TowerLevel,
 >(
 	field_element_a: &Level::Data<P>,
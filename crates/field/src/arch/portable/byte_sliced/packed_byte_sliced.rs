@@ -20,14 +20,15 @@ use crate::{
 	tower_levels::*,
 	underlier::{UnderlierWithBitOps, WithUnderlier},
 	AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
-	ExtensionField, PackedAESBinaryField16x8b, PackedAESBinaryField64x8b, PackedExtension,
-	PackedField,
+	BinaryField128b, BinaryField16b, BinaryField32b, BinaryField64b, BinaryField8b, ExtensionField,
+	PackedAESBinaryField16x8b, PackedAESBinaryField64x8b, PackedBinaryField16x8b,
+	PackedBinaryField32x8b, PackedBinaryField64x8b, PackedExtension, PackedField,
 };
 
-/// Represents AES Tower Field elements in byte-sliced form.
+/// Represents binary tower field elements in byte-sliced form.
 ///
-/// The data layout is backed by Packed Nx8b AES fields where N is the number of bytes `$packed_storage`
-/// can hold, usually 16, 32, or 64 to fit into SIMD registers.
+/// The data layout is backed by Packed Nx8b fields of the same tower (canonical or AES) where N is
+/// the number of bytes `$packed_storage` can hold, usually 16, 32, or 64 to fit into SIMD registers.
 macro_rules! define_byte_sliced {
 	($name:ident, $scalar_type:ty, $packed_storage:ty, $tower_level: ty) => {
 		#[derive(Default, Clone, Copy, PartialEq, Eq, Pod, Zeroable)]
@@ -78,7 +79,7 @@ macro_rules! define_byte_sliced {
 				for byte_index in 0..<$tower_level as TowerLevel>::WIDTH {
 					self.data[byte_index].set_unchecked(
 						i,
-						AESTowerField8b::from_underlier(underlier.get_subvalue(byte_index)),
+						<$packed_storage as PackedField>::Scalar::from_underlier(underlier.get_subvalue(byte_index)),
 					);
 				}
 			}
@@ -91,7 +92,7 @@ macro_rules! define_byte_sliced {
 			fn broadcast(scalar: Self::Scalar) -> Self {
 				Self {
 					data: array::from_fn(|byte_index| {
-						<$packed_storage>::broadcast(AESTowerField8b::from_underlier(unsafe {
+						<$packed_storage>::broadcast(<$packed_storage as PackedField>::Scalar::from_underlier(unsafe {
 							scalar.to_underlier().get_subvalue(byte_index)
 						}))
 					}),
@@ -287,7 +288,7 @@ macro_rules! define_byte_sliced {
 		}
 
 		impl PackedTransformationFactory<$name> for $name {
-			type PackedTransformation<Data: AsRef<[<$name as PackedField>::Scalar]> + Sync> = TransformationWrapperNxN<<$packed_storage as  PackedTransformationFactory<$packed_storage>>::PackedTransformation::<[AESTowerField8b; 8]>, {<$tower_level as TowerLevel>::WIDTH}>;
+			type PackedTransformation<Data: AsRef<[<$name as PackedField>::Scalar]> + Sync> = TransformationWrapperNxN<<$packed_storage as  PackedTransformationFactory<$packed_storage>>::PackedTransformation::<[<$packed_storage as PackedField>::Scalar; 8]>, {<$tower_level as TowerLevel>::WIDTH}>;
 
 			fn make_packed_transformation<Data: AsRef<[<$name as PackedField>::Scalar]> + Sync>(
 				transformation: FieldLinearTransformation<<$name as PackedField>::Scalar, Data>,
@@ -296,7 +297,7 @@ macro_rules! define_byte_sliced {
 					array::from_fn(|col| {
 						let row = row * 8;
 						let linear_transformation_8b = array::from_fn::<_, 8, _>(|row_8b| {
-							<<$name as PackedField>::Scalar as ExtensionField<AESTowerField8b>>::get_base(&transformation.bases()[row + row_8b], col)
+							<<$name as PackedField>::Scalar as ExtensionField<<$packed_storage as PackedField>::Scalar>>::get_base(&transformation.bases()[row + row_8b], col)
 						});
 
 						<$packed_storage as PackedTransformationFactory<$packed_storage
@@ -310,7 +311,7 @@ macro_rules! define_byte_sliced {
 	};
 }
 
-/// Implements common operations both for byte-sliced AES fields and 8b base fields.
+/// Implements common operations both for byte-sliced tower fields and 8b base fields.
 macro_rules! common_byte_sliced_impls {
 	($name:ident, $scalar_type:ty) => {
 		impl Debug for $name {
@@ -439,10 +440,31 @@ define_byte_sliced!(ByteSlicedAES64x32b, AESTowerField32b, PackedAESBinaryField6
 define_byte_sliced!(ByteSlicedAES64x16b, AESTowerField16b, PackedAESBinaryField64x8b, TowerLevel2);
 define_byte_sliced!(ByteSlicedAES64x8b, AESTowerField8b, PackedAESBinaryField64x8b, TowerLevel1);
 
-/// This macro is used to define 8b packed fields that can be used as repacked base fields for byte-sliced AES fields.
+// 128 bit canonical tower
+define_byte_sliced!(ByteSliced16x128b, BinaryField128b, PackedBinaryField16x8b, TowerLevel16);
+define_byte_sliced!(ByteSliced16x64b, BinaryField64b, PackedBinaryField16x8b, TowerLevel8);
+define_byte_sliced!(ByteSliced16x32b, BinaryField32b, PackedBinaryField16x8b, TowerLevel4);
+define_byte_sliced!(ByteSliced16x16b, BinaryField16b, PackedBinaryField16x8b, TowerLevel2);
+define_byte_sliced!(ByteSliced16x8b, BinaryField8b, PackedBinaryField16x8b, TowerLevel1);
+
+// 256 bit canonical tower
+define_byte_sliced!(ByteSliced32x128b, BinaryField128b, PackedBinaryField32x8b, TowerLevel16);
+define_byte_sliced!(ByteSliced32x64b, BinaryField64b, PackedBinaryField32x8b, TowerLevel8);
+define_byte_sliced!(ByteSliced32x32b, BinaryField32b, PackedBinaryField32x8b, TowerLevel4);
+define_byte_sliced!(ByteSliced32x16b, BinaryField16b, PackedBinaryField32x8b, TowerLevel2);
+define_byte_sliced!(ByteSliced32x8b, BinaryField8b, PackedBinaryField32x8b, TowerLevel1);
+
+// 512 bit canonical tower
+define_byte_sliced!(ByteSliced64x128b, BinaryField128b, PackedBinaryField64x8b, TowerLevel16);
+define_byte_sliced!(ByteSliced64x64b, BinaryField64b, PackedBinaryField64x8b, TowerLevel8);
+define_byte_sliced!(ByteSliced64x32b, BinaryField32b, PackedBinaryField64x8b, TowerLevel4);
+define_byte_sliced!(ByteSliced64x16b, BinaryField16b, PackedBinaryField64x8b, TowerLevel2);
+define_byte_sliced!(ByteSliced64x8b, BinaryField8b, PackedBinaryField64x8b, TowerLevel1);
+
+/// This macro is used to define 8b packed fields that can be used as repacked base fields for byte-sliced tower fields.
 macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 	($name:ident, $packed_storage:ty, $original_byte_sliced:ty) => {
-		#[doc = concat!("This is a PackedFields helper that is used like a PackedSubfield of [`PackedExtension<AESTowerField8b>`] for [`", stringify!($original_byte_sliced), "`]")]
+		#[doc = concat!("This is a PackedFields helper that is used like a PackedSubfield of [`PackedExtension`] over the 8b field for [`", stringify!($original_byte_sliced), "`]")]
 		/// and has no particular meaning outside of this purpose.
 		#[derive(Default, Clone, Copy, PartialEq, Eq, Zeroable, Pod)]
 		#[repr(transparent)]
@@ -468,7 +490,7 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 		}
 
 		impl PackedField for $name {
-			type Scalar = AESTowerField8b;
+			type Scalar = <$packed_storage as PackedField>::Scalar;
 
 			const LOG_WIDTH: usize =
 				<$packed_storage>::LOG_WIDTH + checked_log_2(Self::ARRAY_LEN);
@@ -560,7 +582,7 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		common_byte_sliced_impls!($name, AESTowerField8b);
+		common_byte_sliced_impls!($name, <$packed_storage as PackedField>::Scalar);
 
 		impl Mul for $name {
 			type Output = Self;
@@ -574,11 +596,11 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl Add<AESTowerField8b> for $name {
+		impl Add<<$packed_storage as PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
-			fn add(self, rhs: AESTowerField8b) -> $name {
+			fn add(self, rhs: <$packed_storage as PackedField>::Scalar) -> $name {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				Self {
@@ -587,9 +609,9 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl AddAssign<AESTowerField8b> for $name {
+		impl AddAssign<<$packed_storage as PackedField>::Scalar> for $name {
 			#[inline]
-			fn add_assign(&mut self, rhs: AESTowerField8b) {
+			fn add_assign(&mut self, rhs: <$packed_storage as PackedField>::Scalar) {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				for column in &mut self.data {
@@ -598,11 +620,11 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-			impl Sub<AESTowerField8b> for $name {
+			impl Sub<<$packed_storage as PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
-			fn sub(self, rhs: AESTowerField8b) -> $name {
+			fn sub(self, rhs: <$packed_storage as PackedField>::Scalar) -> $name {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				Self {
@@ -611,9 +633,9 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl SubAssign<AESTowerField8b> for $name {
+		impl SubAssign<<$packed_storage as PackedField>::Scalar> for $name {
 			#[inline]
-			fn sub_assign(&mut self, rhs: AESTowerField8b) {
+			fn sub_assign(&mut self, rhs: <$packed_storage as PackedField>::Scalar) {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				for column in &mut self.data {
@@ -622,11 +644,11 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl Mul<AESTowerField8b> for $name {
+		impl Mul<<$packed_storage as PackedField>::Scalar> for $name {
 			type Output = Self;
 
 			#[inline]
-			fn mul(self, rhs: AESTowerField8b) -> $name {
+			fn mul(self, rhs: <$packed_storage as PackedField>::Scalar) -> $name {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				Self {
@@ -635,9 +657,9 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl MulAssign<AESTowerField8b> for $name {
+		impl MulAssign<<$packed_storage as PackedField>::Scalar> for $name {
 			#[inline]
-			fn mul_assign(&mut self, rhs: AESTowerField8b) {
+			fn mul_assign(&mut self, rhs: <$packed_storage as PackedField>::Scalar) {
 				let broadcasted = <$packed_storage>::broadcast(rhs);
 
 				for column in &mut self.data {
@@ -646,7 +668,7 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 			}
 		}
 
-		impl PackedExtension<AESTowerField8b> for $original_byte_sliced {
+		impl PackedExtension<<$packed_storage as PackedField>::Scalar> for $original_byte_sliced {
 			type PackedSubfield = $name;
 
 			fn cast_bases(packed: &[Self]) -> &[Self::PackedSubfield] {
@@ -699,10 +721,10 @@ macro_rules! define_8b_extension_packed_subfield_for_byte_sliced {
 		}
 
 		impl PackedTransformationFactory<$name> for $name {
-			type PackedTransformation<Data: AsRef<[AESTowerField8b]> + Sync> = TransformationWrapper8b<<$packed_storage as  PackedTransformationFactory<$packed_storage>>::PackedTransformation::<Data>>;
+			type PackedTransformation<Data: AsRef<[<$packed_storage as PackedField>::Scalar]> + Sync> = TransformationWrapper8b<<$packed_storage as  PackedTransformationFactory<$packed_storage>>::PackedTransformation::<Data>>;
 
-			fn make_packed_transformation<Data: AsRef<[AESTowerField8b]> + Sync>(
-				transformation: FieldLinearTransformation<AESTowerField8b, Data>,
+			fn make_packed_transformation<Data: AsRef<[<$packed_storage as PackedField>::Scalar]> + Sync>(
+				transformation: FieldLinearTransformation<<$packed_storage as PackedField>::Scalar, Data>,
 			) -> Self::PackedTransformation<Data> {
 				TransformationWrapper8b(<$packed_storage>::make_packed_transformation(transformation))
 			}
@@ -783,3 +805,69 @@ define_8b_extension_packed_subfield_for_byte_sliced!(
 	PackedAESBinaryField64x8b,
 	ByteSlicedAES64x16b
 );
+
+// 128 bit canonical tower
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced16x16x8b,
+	PackedBinaryField16x8b,
+	ByteSliced16x128b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced8x16x8b,
+	PackedBinaryField16x8b,
+	ByteSliced16x64b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced4x16x8b,
+	PackedBinaryField16x8b,
+	ByteSliced16x32b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced2x16x8b,
+	PackedBinaryField16x8b,
+	ByteSliced16x16b
+);
+
+// 256 bit canonical tower
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced16x32x8b,
+	PackedBinaryField32x8b,
+	ByteSliced32x128b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced8x32x8b,
+	PackedBinaryField32x8b,
+	ByteSliced32x64b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced4x32x8b,
+	PackedBinaryField32x8b,
+	ByteSliced32x32b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced2x32x8b,
+	PackedBinaryField32x8b,
+	ByteSliced32x16b
+);
+
+// 512 bit canonical tower
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced16x64x8b,
+	PackedBinaryField64x8b,
+	ByteSliced64x128b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced8x64x8b,
+	PackedBinaryField64x8b,
+	ByteSliced64x64b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced4x64x8b,
+	PackedBinaryField64x8b,
+	ByteSliced64x32b
+);
+define_8b_extension_packed_subfield_for_byte_sliced!(
+	ByteSliced2x64x8b,
+	PackedBinaryField64x8b,
+	ByteSliced64x16b
+);
@@ -1,24 +1,23 @@
 // Copyright 2024-2025 Irreducible Inc.
-use super::multiply::mul_alpha;
+use super::multiply::{mul_alpha, ByteSlicedBaseField};
 use crate::{
 	tower_levels::{TowerLevel, TowerLevelWithArithOps},
-	underlier::WithUnderlier,
-	AESTowerField8b, PackedField,
+	PackedField,
 };
 
 #[inline(always)]
-pub fn square<P: PackedField<Scalar = AESTowerField8b>, Level: TowerLevel>(
+pub fn square<P: PackedField<Scalar: ByteSlicedBaseField>, Level: TowerLevel>(
 	field_element: &Level::Data<P>,
 	destination: &mut Level::Data<P>,
 ) {
-	let base_alpha = P::broadcast(AESTowerField8b::from_underlier(0xd3));
+	let base_alpha = P::broadcast(P::Scalar::ALPHA);
 	square_main::<true, P, Level>(field_element, destination, base_alpha);
 }
 
 #[inline(always)]
 pub fn square_main<
 	const WRITING_TO_ZEROS: bool,
-	P: PackedField<Scalar = AESTowerField8b>,
+	P: PackedField<Scalar: ByteSlicedBaseField>,
 	Level: TowerLevel,
 >(
 	field_element: &Level::Data<P>,
@@ -0,0 +1,39 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! 8x8 bit matrices for the GFNI affine transformation instructions.
+
+#[rustfmt::skip]
+pub(in crate::arch) const TOWER_TO_AES_MAP: i64 = u64::from_le_bytes([
+	0b00111110,
+	0b10011000,
+	0b01001110,
+	0b10010110,
+	0b11101010,
+	0b01101010,
+	0b01010000,
+	0b00110001,
+]) as i64;
+
+#[rustfmt::skip]
+pub(in crate::arch) const AES_TO_TOWER_MAP: i64 = u64::from_le_bytes([
+	0b00001100,
+	0b01110000,
+	0b10100010,
+	0b01110010,
+	0b00111110,
+	0b10000110,
+	0b11101000,
+	0b11010001,
+]) as i64;
+
+#[rustfmt::skip]
+pub(in crate::arch) const IDENTITY_MAP: i64 = u64::from_le_bytes([
+	0b10000000,
+	0b01000000,
+	0b00100000,
+	0b00010000,
+	0b00001000,
+	0b00000100,
+	0b00000010,
+	0b00000001,
+]) as i64;
@@ -0,0 +1,239 @@
+// Copyright 2025 Irreducible Inc.
+
+//! GFNI kernels over 8-bit fields compiled for specific x86-64 feature sets, selected at runtime
+//! by [`Dispatcher`](crate::arch::dispatch::Dispatcher).
+
+use std::{any::TypeId, slice};
+
+use crate::{
+	PackedAESBinaryField16x8b, PackedAESBinaryField1x8b, PackedAESBinaryField2x8b,
+	PackedAESBinaryField32x8b, PackedAESBinaryField4x8b, PackedAESBinaryField64x8b,
+	PackedAESBinaryField8x8b, PackedBinaryField16x8b, PackedBinaryField1x8b, PackedBinaryField2x8b,
+	PackedBinaryField32x8b, PackedBinaryField4x8b, PackedBinaryField64x8b, PackedBinaryField8x8b,
+	PackedField,
+};
+
+/// Returns whether `P` packs elements of the canonical 8-bit tower field (`true`) or the 8-bit AES
+/// field (`false`) with one element per byte, or `None` for any other packed field.
+pub(in crate::arch) fn byte_packed_basis<P: PackedField>() -> Option<bool> {
+	let type_id = TypeId::of::<P>();
+	if [
+		TypeId::of::<PackedBinaryField1x8b>(),
+		TypeId::of::<PackedBinaryField2x8b>(),
+		TypeId::of::<PackedBinaryField4x8b>(),
+		TypeId::of::<PackedBinaryField8x8b>(),
+		TypeId::of::<PackedBinaryField16x8b>(),
+		TypeId::of::<PackedBinaryField32x8b>(),
+		TypeId::of::<PackedBinaryField64x8b>(),
+	]
+	.contains(&type_id)
+	{
+		Some(true)
+	} else if [
+		TypeId::of::<PackedAESBinaryField1x8b>(),
+		TypeId::of::<PackedAESBinaryField2x8b>(),
+		TypeId::of::<PackedAESBinaryField4x8b>(),
+		TypeId::of::<PackedAESBinaryField8x8b>(),
+		TypeId::of::<PackedAESBinaryField16x8b>(),
+		TypeId::of::<PackedAESBinaryField32x8b>(),
+		TypeId::of::<PackedAESBinaryField64x8b>(),
+	]
+	.contains(&type_id)
+	{
+		Some(false)
+	} else {
+		None
+	}
+}
+
+/// # Safety
+/// `P` must pack one field element per byte, see [`byte_packed_basis`].
+pub(in crate::arch) unsafe fn as_bytes<P: PackedField>(values: &[P]) -> &[u8] {
+	slice::from_raw_parts(values.as_ptr().cast(), size_of_val(values))
+}
+
+/// # Safety
+/// `P` must pack one field element per byte, see [`byte_packed_basis`].
+pub(in crate::arch) unsafe fn as_bytes_mut<P: PackedField>(values: &mut [P]) -> &mut [u8] {
+	slice::from_raw_parts_mut(values.as_mut_ptr().cast(), size_of_val(values))
+}
+
+/// Operation over a slice of 8-bit field elements performed by the GFNI byte kernels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(in crate::arch) enum ByteOp {
+	Mul,
+	Square,
+	InvertOrZero,
+}
+
+/// Defines a module of GFNI kernels over 8-bit field elements for the given vector width.
+///
+/// GFNI instructions operate in the AES field, so elements of the canonical tower field are mapped
+/// to the AES basis and back with affine transformations around each operation.
+macro_rules! define_gfni_byte_kernels {
+	(
+		$name:ident,
+		$features:literal,
+		$vector:ty,
+		$loadu:ident,
+		$storeu:ident,
+		$set1_epi64:ident,
+		$xor:ident,
+		$gf2p8mul:ident,
+		$gf2p8affine:ident,
+		$gf2p8affineinv:ident
+	) => {
+		pub(in crate::arch) mod $name {
+			use core::arch::x86_64::*;
+
+			use super::ByteOp;
+			use crate::arch::x86_64::affine_maps::{
+				AES_TO_TOWER_MAP, IDENTITY_MAP, TOWER_TO_AES_MAP,
+			};
+
+			const LANES: usize = size_of::<$vector>();
+
+			#[inline]
+			#[target_feature(enable = $features)]
+			unsafe fn load(bytes: &[u8]) -> $vector {
+				if bytes.len() == LANES {
+					$loadu(bytes.as_ptr().cast())
+				} else {
+					let mut buffer = [0u8; LANES];
+					buffer[..bytes.len()].copy_from_slice(bytes);
+					$loadu(buffer.as_ptr().cast())
+				}
+			}
+
+			#[inline]
+			#[target_feature(enable = $features)]
+			unsafe fn store(bytes: &mut [u8], value: $vector) {
+				if bytes.len() == LANES {
+					$storeu(bytes.as_mut_ptr().cast(), value)
+				} else {
+					let mut buffer = [0u8; LANES];
+					$storeu(buffer.as_mut_ptr().cast(), value);
+					let len = bytes.len();
+					bytes.copy_from_slice(&buffer[..len]);
+				}
+			}
+
+			#[inline]
+			#[target_feature(enable = $features)]
+			unsafe fn xor(x: $vector, y: $vector) -> $vector {
+				$xor(x, y)
+			}
+
+			#[inline]
+			#[target_feature(enable = $features)]
+			unsafe fn apply_vector(op: ByteOp, x: $vector, y: $vector, tower: bool) -> $vector {
+				let to_aes = |x| {
+					if tower {
+						$gf2p8affine::<0>(x, $set1_epi64(TOWER_TO_AES_MAP))
+					} else {
+						x
+					}
+				};
+				let from_aes = |x| {
+					if tower {
+						$gf2p8affine::<0>(x, $set1_epi64(AES_TO_TOWER_MAP))
+					} else {
+						x
+					}
+				};
+
+				match op {
+					ByteOp::Mul => from_aes($gf2p8mul(to_aes(x), to_aes(y))),
+					ByteOp::Square => {
+						let x = to_aes(x);
+						from_aes($gf2p8mul(x, x))
+					}
+					// The affine inverse instruction applies the output map to the inverse, which
+					// fuses the conversion back to the tower basis.
+					ByteOp::InvertOrZero => {
+						let output_map = if tower {
+							AES_TO_TOWER_MAP
+						} else {
+							IDENTITY_MAP
+						};
+						$gf2p8affineinv::<0>(to_aes(x), $set1_epi64(output_map))
+					}
+				}
+			}
+
+			/// Applies `op` to the bytes of `values`, using the bytes of `rhs` as the second
+			/// operand of binary operations. `tower` selects the canonical tower basis instead of
+			/// the AES basis.
+			///
+			/// # Safety
+			/// The executing CPU must support the target features of this module, and `rhs` must
+			/// be at least as long as `values` for binary operations.
+			#[target_feature(enable = $features)]
+			pub unsafe fn apply(op: ByteOp, values: &mut [u8], rhs: &[u8], tower: bool) {
+				for (i, chunk) in values.chunks_mut(LANES).enumerate() {
+					let x = load(chunk);
+					let y = match op {
+						ByteOp::Mul => load(&rhs[i * LANES..][..chunk.len()]),
+						ByteOp::Square | ByteOp::InvertOrZero => x,
+					};
+					store(chunk, apply_vector(op, x, y, tower));
+				}
+			}
+
+			/// Applies the additive NTT butterfly to the bytes of `u` and `v`: `u += v * twiddle;
+			/// v += u` if `forward`, otherwise `v += u; u += v * twiddle`.
+			///
+			/// # Safety
+			/// The executing CPU must support the target features of this module, and `u` and `v`
+			/// must have the same length.
+			#[target_feature(enable = $features)]
+			pub unsafe fn butterfly(
+				forward: bool,
+				u: &mut [u8],
+				v: &mut [u8],
+				twiddle: u8,
+				tower: bool,
+			) {
+				let twiddle = load(&[twiddle; LANES]);
+				for (u_chunk, v_chunk) in u.chunks_mut(LANES).zip(v.chunks_mut(LANES)) {
+					let mut x = load(u_chunk);
+					let mut y = load(v_chunk);
+					if forward {
+						x = xor(x, apply_vector(ByteOp::Mul, y, twiddle, tower));
+						y = xor(y, x);
+					} else {
+						y = xor(y, x);
+						x = xor(x, apply_vector(ByteOp::Mul, y, twiddle, tower));
+					}
+					store(u_chunk, x);
+					store(v_chunk, y);
+				}
+			}
+		}
+	};
+}
+
+define_gfni_byte_kernels!(
+	avx2_gfni_bytes,
+	"avx2,gfni",
+	__m256i,
+	_mm256_loadu_si256,
+	_mm256_storeu_si256,
+	_mm256_set1_epi64x,
+	_mm256_xor_si256,
+	_mm256_gf2p8mul_epi8,
+	_mm256_gf2p8affine_epi64_epi8,
+	_mm256_gf2p8affineinv_epi64_epi8
+);
+define_gfni_byte_kernels!(
+	avx512_gfni_bytes,
+	"avx512f,avx512bw,gfni",
+	__m512i,
+	_mm512_loadu_si512,
+	_mm512_storeu_si512,
+	_mm512_set1_epi64,
+	_mm512_xor_si512,
+	_mm512_gf2p8mul_epi8,
+	_mm512_gf2p8affine_epi64_epi8,
+	_mm512_gf2p8affineinv_epi64_epi8
+);
@@ -4,6 +4,9 @@ use std::array;
 
 use binius_utils::checked_arithmetics::checked_int_div;
 
+pub(super) use crate::arch::x86_64::affine_maps::{
+	AES_TO_TOWER_MAP, IDENTITY_MAP, TOWER_TO_AES_MAP,
+};
 use crate::{
 	arch::{
 		portable::packed::PackedPrimitiveType,
@@ -18,42 +21,6 @@ use crate::{
 	BinaryField, PackedField, TowerField,
 };
 
-#[rustfmt::skip]
-pub(super) const TOWER_TO_AES_MAP: i64 = u64::from_le_bytes([
-	0b00111110,
-	0b10011000,
-	0b01001110,
-	0b10010110,
-	0b11101010,
-	0b01101010,
-	0b01010000,
-	0b00110001,
-]) as i64;
-
-#[rustfmt::skip]
-pub(super) const AES_TO_TOWER_MAP: i64 = u64::from_le_bytes([
-	0b00001100,
-	0b01110000,
-	0b10100010,
-	0b01110010,
-	0b00111110,
-	0b10000110,
-	0b11101000,
-	0b11010001,
-]) as i64;
-
-#[rustfmt::skip]
-pub const IDENTITY_MAP: i64 = u64::from_le_bytes([
-	0b10000000,
-	0b01000000,
-	0b00100000,
-	0b00010000,
-	0b00001000,
-	0b00000100,
-	0b00000010,
-	0b00000001,
-]) as i64;
-
 pub(super) trait GfniType: Copy + TowerSimdType {
 	fn gf2p8affine_epi64_epi8(x: Self, a: Self) -> Self;
 	fn gf2p8mul_epi8(a: Self, b: Self) -> Self;
@@ -2,6 +2,10 @@
 
 use cfg_if::cfg_if;
 
+#[cfg(any(target_feature = "gfni", not(feature = "stable_only")))]
+mod affine_maps;
+#[cfg(not(feature = "stable_only"))]
+pub(super) mod dispatch;
 #[cfg(target_feature = "gfni")]
 mod gfni;
 
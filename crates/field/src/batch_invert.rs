@@ -0,0 +1,232 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Batch inversion of packed field elements using Montgomery's trick.
+//!
+//! Montgomery's trick replaces $n$ inversions with a single inversion of the product of all
+//! elements and $3(n - 1)$ multiplications. Since packed multiplication and inversion operate
+//! lane-wise, the trick applies to packed elements directly.
+
+use binius_maybe_rayon::prelude::*;
+
+use crate::{
+	underlier::{UnderlierType, UnderlierWithBitOps, WithUnderlier},
+	Field, PackedField,
+};
+
+/// Number of packed elements sharing one inversion.
+///
+/// Larger chunks amortize the inversion better, while smaller ones keep the prefix products on the
+/// stack and limit the work redone for chunks containing zeros.
+const CHUNK_SIZE: usize = 64;
+
+/// Inverts every element of `values` in place, mapping zeros to zero.
+pub fn batch_invert_or_zero<P>(values: &mut [P])
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	for chunk in values.chunks_mut(CHUNK_SIZE) {
+		invert_chunk(chunk);
+	}
+}
+
+/// Parallel version of [`batch_invert_or_zero`].
+pub fn batch_invert_or_zero_par<P>(values: &mut [P])
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	values.par_chunks_mut(CHUNK_SIZE).for_each(invert_chunk);
+}
+
+fn invert_chunk<P>(values: &mut [P])
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	debug_assert!(values.len() <= CHUNK_SIZE);
+
+	let mut prefix_products = [P::one(); CHUNK_SIZE];
+	let product = fill_prefix_products(values, &mut prefix_products, |value| value);
+
+	if !product.iter().any(|scalar| scalar == P::Scalar::ZERO) {
+		invert_with_prefix_products(values, &prefix_products, product, |value| value);
+		return;
+	}
+
+	// A zero lane in any value zeroes that lane of the product. Substituting one for the zero
+	// lanes keeps the product invertible, and the lanes are mapped back to zero afterwards.
+	let lane_low_bits = lane_low_bits::<P>();
+	let substitute = |value| one_for_zero_lanes(value, lane_low_bits);
+	let product = fill_prefix_products(values, &mut prefix_products, substitute);
+	invert_with_prefix_products(values, &prefix_products, product, substitute);
+}
+
+/// Writes the product of the first `i` substituted values to `prefix_products[i]` and returns the
+/// product of all of them.
+fn fill_prefix_products<P: PackedField>(
+	values: &[P],
+	prefix_products: &mut [P; CHUNK_SIZE],
+	substitute: impl Fn(P) -> P,
+) -> P {
+	let mut product = P::one();
+	for (prefix_product, &value) in prefix_products.iter_mut().zip(values) {
+		*prefix_product = product;
+		product *= substitute(value);
+	}
+	product
+}
+
+/// Replaces the values with the inverses of their substitutes, given the prefix products and the
+/// product of the substituted values. Lanes where a value is zero are set to zero.
+fn invert_with_prefix_products<P: PackedField>(
+	values: &mut [P],
+	prefix_products: &[P; CHUNK_SIZE],
+	product: P,
+	substitute: impl Fn(P) -> P,
+) {
+	// Invariant: suffix_inverse is the inverse of the product of the first i + 1 substitutes.
+	let mut suffix_inverse = product.invert_or_zero();
+	let prefix_products = &prefix_products[..values.len()];
+	for (value, &prefix_product) in values.iter_mut().zip(prefix_products).rev() {
+		let substituted = substitute(*value);
+		let inverse = suffix_inverse * prefix_product;
+		suffix_inverse *= substituted;
+		// `substituted - value` is one in the substituted zero lanes, where `inverse` is one too,
+		// and zero elsewhere.
+		*value = inverse - (substituted - *value);
+	}
+}
+
+/// Returns the underlier with the lowest bit of every lane of `P` set.
+fn lane_low_bits<P>() -> P::Underlier
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	let mut low_bits = P::Underlier::ONE;
+	for i in P::Underlier::LOG_BITS - P::LOG_WIDTH..P::Underlier::LOG_BITS {
+		low_bits |= low_bits << (1 << i);
+	}
+	low_bits
+}
+
+/// Replaces the zero lanes of `value` with one, given the [`lane_low_bits`] of `P`.
+///
+/// The zero lanes are found with bit operations on the underlier rather than lane by lane, so the
+/// cost does not grow with the packing width.
+fn one_for_zero_lanes<P>(value: P, lane_low_bits: P::Underlier) -> P
+where
+	P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+{
+	let log_lane_bits = P::Underlier::LOG_BITS - P::LOG_WIDTH;
+
+	// Fold the bits of each lane into its lowest bit, which is then clear exactly in zero lanes.
+	// The shifts add up to less than the lane width, so the next lane never reaches the lowest bit.
+	let mut nonzero = value.to_underlier();
+	for i in 0..log_lane_bits {
+		nonzero |= nonzero.shr_128b_lanes(1 << i);
+	}
+
+	// Spread the lowest bit of each zero lane over the whole lane.
+	let mut zero_lanes = !nonzero & lane_low_bits;
+	for i in 0..log_lane_bits {
+		zero_lanes |= zero_lanes.shl_128b_lanes(1 << i);
+	}
+
+	value + P::from_underlier(P::one().to_underlier() & zero_lanes)
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		BinaryField128b, PackedAESBinaryField16x8b, PackedBinaryField128x1b,
+		PackedBinaryField16x8b, PackedBinaryField1x128b, PackedBinaryField4x32b,
+		PackedBinaryField64x8b, PackedBinaryPolyval2x128b,
+	};
+
+	fn check_batch_invert<P>()
+	where
+		P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		// Lengths around the chunk size exercise partial and multiple chunks.
+		for len in [0, 1, 5, CHUNK_SIZE, CHUNK_SIZE + 3, 3 * CHUNK_SIZE + 17] {
+			let values = (0..len).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+			let expected = values
+				.iter()
+				.map(|value| value.invert_or_zero())
+				.collect::<Vec<_>>();
+
+			let mut result = values.clone();
+			batch_invert_or_zero(&mut result);
+			assert_eq!(result, expected);
+
+			let mut result = values;
+			batch_invert_or_zero_par(&mut result);
+			assert_eq!(result, expected);
+		}
+	}
+
+	#[test]
+	fn test_batch_invert() {
+		check_batch_invert::<PackedBinaryField128x1b>();
+		check_batch_invert::<PackedBinaryField16x8b>();
+		check_batch_invert::<PackedAESBinaryField16x8b>();
+		check_batch_invert::<PackedBinaryField4x32b>();
+		check_batch_invert::<PackedBinaryField1x128b>();
+		check_batch_invert::<BinaryField128b>();
+	}
+
+	#[test]
+	fn test_batch_invert_with_zeros() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut values = (0..2 * CHUNK_SIZE + 1)
+			.map(|_| PackedBinaryField4x32b::random(&mut rng))
+			.collect::<Vec<_>>();
+		values[3] = PackedBinaryField4x32b::zero();
+		values[CHUNK_SIZE + 1].set(2, Field::ZERO);
+		let expected = values
+			.iter()
+			.map(|value| value.invert_or_zero())
+			.collect::<Vec<_>>();
+
+		batch_invert_or_zero(&mut values);
+		assert_eq!(values, expected);
+	}
+
+	fn check_batch_invert_with_sparse_zero_lanes<P>()
+	where
+		P: PackedField + WithUnderlier<Underlier: UnderlierWithBitOps>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		// With 8-bit scalars, nearly every chunk has a zero lane in some value.
+		let mut values = (0..3 * CHUNK_SIZE + 5)
+			.map(|_| P::random(&mut rng))
+			.collect::<Vec<_>>();
+		for (i, value) in values.iter_mut().enumerate().step_by(7) {
+			value.set(i % P::WIDTH, Field::ZERO);
+		}
+		let expected = values
+			.iter()
+			.map(|value| value.invert_or_zero())
+			.collect::<Vec<_>>();
+
+		let mut result = values.clone();
+		batch_invert_or_zero(&mut result);
+		assert_eq!(result, expected);
+
+		batch_invert_or_zero_par(&mut values);
+		assert_eq!(values, expected);
+	}
+
+	#[test]
+	fn test_batch_invert_with_sparse_zero_lanes() {
+		check_batch_invert_with_sparse_zero_lanes::<PackedBinaryField16x8b>();
+		check_batch_invert_with_sparse_zero_lanes::<PackedAESBinaryField16x8b>();
+		check_batch_invert_with_sparse_zero_lanes::<PackedBinaryField4x32b>();
+		// Lanes of wide underliers, single-bit lanes, and a field whose one is not the integer one.
+		check_batch_invert_with_sparse_zero_lanes::<PackedBinaryField64x8b>();
+		check_batch_invert_with_sparse_zero_lanes::<PackedBinaryField128x1b>();
+		check_batch_invert_with_sparse_zero_lanes::<PackedBinaryPolyval2x128b>();
+	}
+}
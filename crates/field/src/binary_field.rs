@@ -79,6 +79,32 @@ where
 	fn mul_primitive(self, iota: usize) -> Result<Self, Error> {
 		Ok(self * <Self as ExtensionField<BinaryField1b>>::basis(1 << iota)?)
 	}
+
+	/// Returns the unique square root of the element, the inverse of the Frobenius map $x \mapsto x^2$.
+	fn sqrt(self) -> Self {
+		crate::frobenius::sqrt(self)
+	}
+
+	/// Returns the absolute trace $\mathrm{Tr}(x) = \sum_{i=0}^{n-1} x^{2^i}$ over $T_0$.
+	fn trace(self) -> BinaryField1b {
+		if crate::frobenius::trace(self) == Self::ZERO {
+			BinaryField1b::ZERO
+		} else {
+			BinaryField1b::ONE
+		}
+	}
+
+	/// Returns the half-trace of the element, see [`crate::frobenius::half_trace`].
+	fn half_trace(self) -> Self {
+		crate::frobenius::half_trace(self)
+	}
+
+	/// Returns a solution of $x^2 + x = c$ for $c$ = `self`, or `None` if there is none.
+	///
+	/// The other solution is $x + 1$.
+	fn solve_quadratic(self) -> Option<Self> {
+		crate::frobenius::solve_quadratic(self)
+	}
 }
 
 pub(super) trait TowerExtensionField:
@@ -15,4 +15,6 @@ pub enum Error {
 	/// in the binary field.
 	#[error("value is not in the field")]
 	NotInField,
+	#[error("dispatch target {0:?} is not supported by the executing CPU")]
+	UnsupportedDispatchTarget(crate::arch::dispatch::DispatchTarget),
 }
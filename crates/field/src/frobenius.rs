@@ -0,0 +1,200 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Operations derived from the Frobenius automorphism $x \mapsto x^2$ of binary fields.
+//!
+//! These are the building blocks for binary elliptic curve arithmetic, such as point
+//! decompression, and for decoding algorithms that solve quadratic equations. The functions are
+//! generic over packed fields and operate lane-wise, so scalars can use them directly as well.
+
+use crate::{BinaryField, Field, PackedField, TowerField};
+
+/// Returns the unique square root of each element, i.e. $x^{2^{n-1}}$ for a field of degree $n$.
+pub fn sqrt<P>(x: P) -> P
+where
+	P: PackedField<Scalar: TowerField>,
+{
+	(1..P::Scalar::N_BITS).fold(x, |acc, _| acc.square())
+}
+
+/// Returns the absolute trace $\mathrm{Tr}(x) = \sum_{i=0}^{n-1} x^{2^i}$ of each element, which is
+/// either zero or one.
+pub fn trace<P>(x: P) -> P
+where
+	P: PackedField<Scalar: TowerField>,
+{
+	let mut power = x;
+	let mut trace = x;
+	for _ in 1..P::Scalar::N_BITS {
+		power = power.square();
+		trace += power;
+	}
+	trace
+}
+
+/// Returns a fixed element with absolute trace one.
+///
+/// For a tower field of level $\iota > 0$, this is the image of the generator $X_{\iota - 1}$ of the
+/// top-level extension of the canonical tower. Its trace over the subfield is $X_{\iota - 2}$, so by
+/// transitivity of the trace its absolute trace is one.
+pub fn trace_one<F: TowerField>() -> F {
+	match F::TOWER_LEVEL {
+		0 => F::ONE,
+		level => F::from(
+			<F::Canonical as TowerField>::basis(level - 1, 1)
+				.expect("level - 1 is less than the tower level"),
+		),
+	}
+}
+
+/// Returns the half-trace of each element with respect to [`trace_one`].
+///
+/// The classical half-trace $\sum_{i=0}^{(n-1)/2} x^{2^{2i}}$ exists only for odd degrees $n$,
+/// while tower fields beyond $T_0$ have even degree. This function computes its generalization
+/// $H(c) = \sum_{i=0}^{n-2} \left( \sum_{j=i+1}^{n-1} \delta^{2^j} \right) c^{2^i}$ with
+/// $\delta$ = [`trace_one`], which satisfies
+///
+/// $$H(c)^2 + H(c) = c + \mathrm{Tr}(c) \delta.$$
+pub fn half_trace<P>(c: P) -> P
+where
+	P: PackedField<Scalar: TowerField>,
+{
+	let delta = trace_one::<P::Scalar>();
+
+	// The coefficient of c^{2^i} is w_i = \sum_{j > i} \delta^{2^j}. Since Tr(\delta) = 1, the
+	// first coefficient is w_0 = 1 + \delta and the following ones are w_{i+1} = w_i + \delta^{2^{i+1}}.
+	let mut coeff = P::Scalar::ONE + delta;
+	let mut delta_power = delta;
+	let mut c_power = c;
+	let mut result = P::zero();
+	for _ in 0..P::Scalar::N_BITS - 1 {
+		result += c_power * coeff;
+		c_power = c_power.square();
+		delta_power = delta_power.square();
+		coeff += delta_power;
+	}
+	result
+}
+
+/// Solves $x^2 + x = c$ for each element.
+///
+/// A solution exists if and only if $\mathrm{Tr}(c) = 0$, in which case the other solution is
+/// $x + 1$. Returns `None` if any element has no solution.
+pub fn solve_quadratic<P>(c: P) -> Option<P>
+where
+	P: PackedField<Scalar: TowerField>,
+{
+	(trace(c) == P::zero()).then(|| half_trace(c))
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
+		BinaryField128b, BinaryField128bPolyval, BinaryField16b, BinaryField1b, BinaryField2b,
+		BinaryField32b, BinaryField4b, BinaryField64b, BinaryField8b, PackedAESBinaryField4x32b,
+		PackedBinaryField16x8b, PackedBinaryField2x64b, PackedBinaryField4x2b,
+	};
+
+	fn check_field<F: TowerField>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let delta = trace_one::<F>();
+		assert_eq!(delta.trace(), BinaryField1b::ONE);
+
+		let mut n_solvable = 0;
+		for _ in 0..100 {
+			let x = <F as Field>::random(&mut rng);
+			let y = <F as Field>::random(&mut rng);
+
+			assert_eq!(x.sqrt().square(), x);
+			assert_eq!(x.square().sqrt(), x);
+
+			assert!(trace(x) == F::ZERO || trace(x) == F::ONE);
+			assert_eq!((x + y).trace(), x.trace() + y.trace());
+			assert_eq!(x.square().trace(), x.trace());
+
+			let h = x.half_trace();
+			let expected = if x.trace() == BinaryField1b::ONE {
+				x + delta
+			} else {
+				x
+			};
+			assert_eq!(h.square() + h, expected);
+
+			match x.solve_quadratic() {
+				Some(root) => {
+					n_solvable += 1;
+					assert_eq!(x.trace(), BinaryField1b::ZERO);
+					assert_eq!(root.square() + root, x);
+				}
+				None => assert_eq!(x.trace(), BinaryField1b::ONE),
+			}
+		}
+
+		// Half of the elements have trace zero; only zero in the one-bit field.
+		if F::N_BITS > 1 {
+			assert!(n_solvable > 20 && n_solvable < 80);
+		}
+	}
+
+	#[test]
+	fn test_canonical_tower() {
+		check_field::<BinaryField1b>();
+		check_field::<BinaryField2b>();
+		check_field::<BinaryField4b>();
+		check_field::<BinaryField8b>();
+		check_field::<BinaryField16b>();
+		check_field::<BinaryField32b>();
+		check_field::<BinaryField64b>();
+		check_field::<BinaryField128b>();
+	}
+
+	#[test]
+	fn test_isomorphic_towers() {
+		check_field::<AESTowerField8b>();
+		check_field::<AESTowerField16b>();
+		check_field::<AESTowerField32b>();
+		check_field::<AESTowerField64b>();
+		check_field::<AESTowerField128b>();
+		check_field::<BinaryField128bPolyval>();
+	}
+
+	#[test]
+	fn test_trace_is_isomorphism_invariant() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..100 {
+			let x = <BinaryField32b as Field>::random(&mut rng);
+			assert_eq!(AESTowerField32b::from(x).trace(), x.trace());
+			assert_eq!(AESTowerField32b::from(x).sqrt(), AESTowerField32b::from(x.sqrt()));
+		}
+	}
+
+	fn check_packed<P: PackedField<Scalar: TowerField>>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..20 {
+			let x = P::random(&mut rng);
+
+			let (sqrt, trace, half_trace) = (sqrt(x), trace(x), half_trace(x));
+			for (i, scalar) in x.iter().enumerate() {
+				assert_eq!(sqrt.get(i), scalar.sqrt());
+				assert_eq!(trace.get(i), super::trace(scalar));
+				assert_eq!(half_trace.get(i), scalar.half_trace());
+			}
+
+			let solvable = x - trace * P::broadcast(trace_one());
+			let roots = solve_quadratic(solvable).unwrap();
+			assert_eq!(roots.square() + roots, solvable);
+			assert_eq!(solve_quadratic(x).is_some(), trace == P::zero());
+		}
+	}
+
+	#[test]
+	fn test_packed() {
+		check_packed::<PackedBinaryField4x2b>();
+		check_packed::<PackedBinaryField16x8b>();
+		check_packed::<PackedBinaryField2x64b>();
+		check_packed::<PackedAESBinaryField4x32b>();
+	}
+}
@@ -11,19 +11,21 @@
 
 #![cfg_attr(
 	all(target_arch = "x86_64", not(feature = "stable_only")),
-	feature(stdarch_x86_avx512)
+	feature(avx512_target_feature, stdarch_x86_avx512)
 )]
 
 pub mod aes_field;
 pub mod arch;
 pub mod arithmetic_traits;
 pub mod as_packed_field;
+pub mod batch_invert;
 pub mod binary_field;
 mod binary_field_arithmetic;
 pub mod byte_iteration;
 pub mod error;
 pub mod extension;
 pub mod field;
+pub mod frobenius;
 pub mod linear_transformation;
 mod macros;
 pub mod packed;
@@ -43,6 +45,7 @@ pub mod util;
 
 pub use aes_field::*;
 pub use arch::byte_sliced::*;
+pub use batch_invert::*;
 pub use binary_field::*;
 pub use error::*;
 pub use extension::*;
@@ -1,10 +1,10 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::marker::PhantomData;
+use std::{iter, marker::PhantomData};
 
 use rand::RngCore;
 
-use crate::{packed::PackedBinaryField, BinaryField, BinaryField1b, ExtensionField};
+use crate::{packed::PackedBinaryField, BinaryField, BinaryField1b, Error, ExtensionField, Field};
 
 /// Generic transformation trait that is used both for scalars and packed fields
 pub trait Transformation<Input, Output>: Sync {
@@ -82,3 +82,369 @@ where
 		transformation: FieldLinearTransformation<OP::Scalar, Data>,
 	) -> Self::PackedTransformation<Data>;
 }
+
+/// An isomorphism between two binary fields of the same degree, together with its inverse.
+///
+/// Any two binary fields of the same degree are isomorphic, but the isomorphism is not unique:
+/// composing one with a power of the Frobenius automorphism gives another one. This type computes
+/// one of them at runtime using only the arithmetic of the two fields, so that conversions between
+/// representations (e.g. canonical tower, AES tower or POLYVAL) don't have to be hard-coded. The
+/// resulting transformations can be passed to
+/// [`PackedTransformationFactory::make_packed_transformation`].
+///
+/// Both sides must be existing [`BinaryField`] types, since the field arithmetic is what defines a
+/// representation here. A representation given only by a reduction polynomial or a basis has to be
+/// implemented as a [`BinaryField`] type before an isomorphism to it can be computed.
+///
+/// The isomorphism maps the multiplicative generator of `F1` to a root of its minimal polynomial
+/// in `F2`. The root is found deterministically, so the same pair of fields always yields the same
+/// isomorphism.
+#[derive(Debug, Clone)]
+pub struct FieldIsomorphism<F1: BinaryField, F2: BinaryField> {
+	forward: FieldLinearTransformation<F2, Vec<F2>>,
+	inverse: FieldLinearTransformation<F1, Vec<F1>>,
+}
+
+impl<F1: BinaryField, F2: BinaryField> FieldIsomorphism<F1, F2> {
+	/// Computes an isomorphism from `F1` to `F2`.
+	///
+	/// Returns [`Error::ExtensionDegreeMismatch`] if the fields have different degrees.
+	pub fn new() -> Result<Self, Error> {
+		if F1::DEGREE != F2::DEGREE {
+			return Err(Error::ExtensionDegreeMismatch);
+		}
+
+		let generator = F1::MULTIPLICATIVE_GENERATOR;
+		let minimal_poly = minimal_polynomial(generator)
+			.into_iter()
+			.map(|bit| if bit { F2::ONE } else { F2::ZERO })
+			.collect();
+		let image = find_root(minimal_poly);
+
+		Ok(Self {
+			forward: map_generator(generator, image),
+			inverse: map_generator(image, generator),
+		})
+	}
+
+	/// The transformation from `F1` to `F2`.
+	pub const fn forward(&self) -> &FieldLinearTransformation<F2, Vec<F2>> {
+		&self.forward
+	}
+
+	/// The transformation from `F2` to `F1`.
+	pub const fn inverse(&self) -> &FieldLinearTransformation<F1, Vec<F1>> {
+		&self.inverse
+	}
+
+	/// Consumes the isomorphism, returning the forward and inverse transformations.
+	#[allow(clippy::type_complexity)]
+	pub fn into_transformations(
+		self,
+	) -> (FieldLinearTransformation<F2, Vec<F2>>, FieldLinearTransformation<F1, Vec<F1>>) {
+		(self.forward, self.inverse)
+	}
+}
+
+/// Returns the coordinates of a field element in the $\mathbb{F}_2$ basis of the field, packed into
+/// the low [`ExtensionField::DEGREE`] bits of the result.
+pub fn to_bits<F: BinaryField>(value: F) -> u128 {
+	ExtensionField::<BinaryField1b>::iter_bases(&value)
+		.enumerate()
+		.filter(|(_, bit)| *bit == BinaryField1b::ONE)
+		.fold(0, |acc, (i, _)| acc | (1 << i))
+}
+
+/// Inverts a square matrix over $\mathbb{F}_2$ given by its columns.
+///
+/// Returns the rows of the inverse matrix. Panics if the matrix is singular.
+fn invert_bit_matrix(columns: &[u128]) -> Vec<u128> {
+	let n = columns.len();
+	let mut rows = (0..n)
+		.map(|i| {
+			columns
+				.iter()
+				.enumerate()
+				.fold(0u128, |acc, (j, &column)| acc | (((column >> i) & 1) << j))
+		})
+		.collect::<Vec<_>>();
+	let mut inverse = (0..n).map(|i| 1u128 << i).collect::<Vec<_>>();
+
+	for col in 0..n {
+		let pivot = (col..n)
+			.find(|&row| (rows[row] >> col) & 1 == 1)
+			.expect("matrix must be invertible");
+		rows.swap(col, pivot);
+		inverse.swap(col, pivot);
+
+		for row in 0..n {
+			if row != col && (rows[row] >> col) & 1 == 1 {
+				rows[row] ^= rows[col];
+				inverse[row] ^= inverse[col];
+			}
+		}
+	}
+
+	inverse
+}
+
+/// Returns the powers $1, x, \ldots, x^{n-1}$ where $n$ is the field degree.
+fn powers<F: BinaryField>(value: F) -> Vec<F> {
+	iter::successors(Some(F::ONE), |&power| Some(power * value))
+		.take(F::DEGREE)
+		.collect()
+}
+
+/// Returns the coefficients of the minimal polynomial over $\mathbb{F}_2$ of an element that
+/// generates the field, from the constant term to the leading one.
+fn minimal_polynomial<F: BinaryField>(generator: F) -> Vec<bool> {
+	let columns = powers(generator)
+		.into_iter()
+		.map(to_bits)
+		.collect::<Vec<_>>();
+	let inverse_rows = invert_bit_matrix(&columns);
+
+	// The coordinates of generator^n in the power basis give the low coefficients.
+	let top_power = to_bits(generator.pow([F::DEGREE as u64]));
+	inverse_rows
+		.iter()
+		.map(|&row| (row & top_power).count_ones() % 2 == 1)
+		.chain(iter::once(true))
+		.collect()
+}
+
+/// Computes the linear map between fields that sends `generator` to `image`.
+///
+/// `image` must be a root of the minimal polynomial of `generator`.
+fn map_generator<IF: BinaryField, OF: BinaryField>(
+	generator: IF,
+	image: OF,
+) -> FieldLinearTransformation<OF, Vec<OF>> {
+	let columns = powers(generator)
+		.into_iter()
+		.map(to_bits)
+		.collect::<Vec<_>>();
+	let inverse_rows = invert_bit_matrix(&columns);
+	let image_powers = powers(image);
+
+	// The i-th basis element of IF is the sum of generator^j over the set bits j of column i of the
+	// inverse matrix.
+	let bases = (0..IF::DEGREE)
+		.map(|i| {
+			inverse_rows
+				.iter()
+				.zip(&image_powers)
+				.filter(|(&row, _)| (row >> i) & 1 == 1)
+				.map(|(_, &power)| power)
+				.sum()
+		})
+		.collect();
+
+	FieldLinearTransformation::new(bases)
+}
+
+/// Finds a root of a monic polynomial that splits into distinct linear factors over `F`.
+///
+/// Uses Berlekamp's trace algorithm. For any two distinct roots $r_1, r_2$, there is an element
+/// $\delta$ of the $\mathbb{F}_2$ basis with $\mathrm{Tr}(\delta r_1) \neq \mathrm{Tr}(\delta r_2)$,
+/// hence iterating over the basis always finds a non-trivial factor.
+fn find_root<F: BinaryField>(mut poly: Vec<F>) -> F {
+	while poly.len() > 2 {
+		poly = (0..F::DEGREE)
+			.find_map(|i| {
+				let delta = <F as ExtensionField<BinaryField1b>>::basis(i)
+					.expect("i is less than the field degree");
+				let factor = poly_gcd(poly.clone(), trace_polynomial(&poly, delta));
+				if factor.len() <= 1 || factor.len() == poly.len() {
+					return None;
+				}
+
+				let cofactor = poly_div(&poly, &factor);
+				Some(if factor.len() <= cofactor.len() {
+					factor
+				} else {
+					cofactor
+				})
+			})
+			.expect("polynomial must split into distinct linear factors");
+	}
+
+	// The polynomial is now x + root.
+	poly[0]
+}
+
+/// Computes $\sum_{k=0}^{n-1} (\delta x)^{2^k} \bmod p$ where $n$ is the field degree.
+fn trace_polynomial<F: BinaryField>(modulus: &[F], delta: F) -> Vec<F> {
+	let mut power = poly_rem(vec![F::ZERO, delta], modulus);
+	let mut result = power.clone();
+	for _ in 1..F::DEGREE {
+		let squared = power
+			.iter()
+			.flat_map(|&coeff| [coeff.square(), F::ZERO])
+			.collect();
+		power = poly_rem(squared, modulus);
+		poly_add_assign(&mut result, &power);
+	}
+	poly_trim(&mut result);
+	result
+}
+
+fn poly_trim<F: BinaryField>(poly: &mut Vec<F>) {
+	while poly.last() == Some(&F::ZERO) {
+		poly.pop();
+	}
+}
+
+fn poly_add_assign<F: BinaryField>(lhs: &mut Vec<F>, rhs: &[F]) {
+	if lhs.len() < rhs.len() {
+		lhs.resize(rhs.len(), F::ZERO);
+	}
+	for (lhs, &rhs) in iter::zip(lhs, rhs) {
+		*lhs += rhs;
+	}
+}
+
+/// Divides `lhs` by the non-zero `rhs`, returning the quotient and leaving the remainder in `lhs`.
+fn poly_div_rem<F: BinaryField>(lhs: &mut Vec<F>, rhs: &[F]) -> Vec<F> {
+	poly_trim(lhs);
+	let rhs_degree = rhs.len() - 1;
+	let lead_inv = rhs[rhs_degree].invert().expect("divisor must be non-zero");
+
+	let mut quotient = vec![F::ZERO; lhs.len().saturating_sub(rhs_degree)];
+	for shift in (0..quotient.len()).rev() {
+		let coeff = lhs[shift + rhs_degree] * lead_inv;
+		quotient[shift] = coeff;
+		for (lhs, &rhs) in iter::zip(&mut lhs[shift..], rhs) {
+			*lhs += coeff * rhs;
+		}
+	}
+
+	lhs.truncate(rhs_degree);
+	poly_trim(lhs);
+	quotient
+}
+
+fn poly_rem<F: BinaryField>(mut lhs: Vec<F>, rhs: &[F]) -> Vec<F> {
+	poly_div_rem(&mut lhs, rhs);
+	lhs
+}
+
+fn poly_div<F: BinaryField>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+	poly_div_rem(&mut lhs.to_vec(), rhs)
+}
+
+/// Returns the monic greatest common divisor of two polynomials, the first of which is non-zero.
+fn poly_gcd<F: BinaryField>(mut a: Vec<F>, mut b: Vec<F>) -> Vec<F> {
+	poly_trim(&mut b);
+	while !b.is_empty() {
+		let remainder = poly_rem(a, &b);
+		a = b;
+		b = remainder;
+	}
+
+	let lead_inv = a
+		.last()
+		.and_then(|lead| lead.invert())
+		.expect("gcd of a non-zero polynomial is non-zero");
+	a.iter_mut().for_each(|coeff| *coeff *= lead_inv);
+	a
+}
+
+#[cfg(test)]
+mod tests {
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		aes_field::AES_TO_BINARY_LINEAR_TRANSFORMATION,
+		polyval::{AES_TO_POLYVAL_TRANSFORMATION, BINARY_TO_POLYVAL_TRANSFORMATION},
+		AESTowerField128b, AESTowerField32b, AESTowerField8b, BinaryField128b,
+		BinaryField128bPolyval, BinaryField16b, BinaryField32b, BinaryField8b,
+		PackedBinaryField2x128b, PackedBinaryPolyval2x128b, PackedField,
+	};
+
+	fn check_isomorphism<F1: BinaryField, F2: BinaryField>() -> FieldIsomorphism<F1, F2> {
+		let isomorphism = FieldIsomorphism::<F1, F2>::new().unwrap();
+		let forward = isomorphism.forward();
+		let inverse = isomorphism.inverse();
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let a = F1::random(&mut rng);
+			let b = F1::random(&mut rng);
+			let a_image: F2 = forward.transform(&a);
+			let b_image: F2 = forward.transform(&b);
+
+			assert_eq!(forward.transform(&(a * b)), a_image * b_image);
+			assert_eq!(forward.transform(&(a + b)), a_image + b_image);
+			assert_eq!(inverse.transform(&a_image), a);
+		}
+		assert_eq!(forward.transform(&F1::ONE), F2::ONE);
+
+		isomorphism
+	}
+
+	/// Checks that a hard-coded isomorphism differs from the computed one by a Frobenius power.
+	fn check_conjugate<F1: BinaryField, F2: BinaryField>(
+		expected: &FieldLinearTransformation<F2, impl AsRef<[F2]> + Sync>,
+	) {
+		let isomorphism = FieldIsomorphism::<F1, F2>::new().unwrap();
+		let generator = F1::MULTIPLICATIVE_GENERATOR;
+		let expected_image: F2 = expected.transform(&generator);
+
+		let mut image: F2 = isomorphism.forward().transform(&generator);
+		let is_conjugate = (0..F2::DEGREE).any(|_| {
+			let found = image == expected_image;
+			image = image.square();
+			found
+		});
+		assert!(is_conjugate);
+	}
+
+	#[test]
+	fn test_isomorphisms() {
+		check_isomorphism::<BinaryField8b, AESTowerField8b>();
+		check_isomorphism::<AESTowerField8b, BinaryField8b>();
+		check_isomorphism::<BinaryField16b, BinaryField16b>();
+		check_isomorphism::<BinaryField32b, AESTowerField32b>();
+		check_isomorphism::<BinaryField128b, BinaryField128bPolyval>();
+		check_isomorphism::<AESTowerField128b, BinaryField128bPolyval>();
+		check_isomorphism::<BinaryField128bPolyval, AESTowerField128b>();
+	}
+
+	#[test]
+	fn test_degree_mismatch() {
+		assert!(matches!(
+			FieldIsomorphism::<BinaryField8b, BinaryField16b>::new(),
+			Err(Error::ExtensionDegreeMismatch)
+		));
+	}
+
+	#[test]
+	fn test_conjugate_to_hardcoded() {
+		check_conjugate::<AESTowerField8b, BinaryField8b>(&AES_TO_BINARY_LINEAR_TRANSFORMATION);
+		check_conjugate::<BinaryField128b, BinaryField128bPolyval>(
+			&BINARY_TO_POLYVAL_TRANSFORMATION,
+		);
+		check_conjugate::<AESTowerField128b, BinaryField128bPolyval>(
+			&AES_TO_POLYVAL_TRANSFORMATION,
+		);
+	}
+
+	#[test]
+	fn test_packed_transformation() {
+		let (forward, _) = FieldIsomorphism::<BinaryField128b, BinaryField128bPolyval>::new()
+			.unwrap()
+			.into_transformations();
+		let packed_forward = <PackedBinaryField2x128b as PackedTransformationFactory<
+			PackedBinaryPolyval2x128b,
+		>>::make_packed_transformation(forward.clone());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let input = PackedBinaryField2x128b::random(&mut rng);
+		let output = packed_forward.transform(&input);
+		for (input, output) in input.iter().zip(output.iter()) {
+			assert_eq!(forward.transform(&input), output);
+		}
+	}
+}
@@ -2,17 +2,35 @@
 
 use std::{
 	fmt::Debug,
+	mem::MaybeUninit,
 	ops::{Deref, DerefMut},
+	slice,
 };
 
+#[cfg(feature = "merkle")]
+use binius_field::TowerField;
+#[cfg(feature = "ntt")]
+use binius_field::{BinaryField, ExtensionField};
 use binius_field::{Field, PackedExtension, PackedField};
+#[cfg(feature = "merkle")]
+use binius_hash::PseudoCompressionFunction;
 use binius_math::{
-	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQuery,
-	MultilinearQueryRef,
+	fold_left_lerp, fold_right_lerp, CompositionPoly, EvaluationOrder, MultilinearExtension,
+	MultilinearPoly, MultilinearQuery, MultilinearQueryRef,
 };
 use binius_maybe_rayon::iter::FromParallelIterator;
+#[cfg(feature = "merkle")]
+use binius_maybe_rayon::iter::IndexedParallelIterator;
+#[cfg(feature = "ntt")]
+use binius_ntt::AdditiveNTT;
+#[cfg(feature = "merkle")]
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
 use tracing::instrument;
 
+#[cfg(feature = "merkle")]
+use crate::merkle;
+#[cfg(feature = "ntt")]
+use crate::{fri_fold, reed_solomon};
 use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
 
 /// HAL-managed memory containing the result of its operations.
@@ -37,6 +55,20 @@ pub trait ComputationBackend: Send + Sync + Debug {
 	/// Creates `Self::Vec<P>` from the given `Vec<P>`.
 	fn to_hal_slice<P: Debug + Send + Sync>(v: Vec<P>) -> Self::Vec<P>;
 
+	/// Creates `Self::Vec<P>` with `len` zeroed elements.
+	fn zeroed_hal_slice<P: PackedField>(&self, len: usize) -> Self::Vec<P> {
+		Self::to_hal_slice(bytemuck::zeroed_vec(len))
+	}
+
+	/// Creates `Self::Vec<T>` with `len` copies of `value`.
+	fn filled_hal_slice<T: Clone + Debug + Send + Sync + 'static>(
+		&self,
+		len: usize,
+		value: T,
+	) -> Self::Vec<T> {
+		Self::to_hal_slice(vec![value; len])
+	}
+
 	/// Computes tensor product expansion.
 	fn tensor_product_full_query<P: PackedField>(
 		&self,
@@ -44,12 +76,12 @@ pub trait ComputationBackend: Send + Sync + Debug {
 	) -> Result<Self::Vec<P>, Error>;
 
 	/// Calculate the accumulated evaluations for an arbitrary round of zerocheck.
-	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition, SumcheckBackend>(
 		&self,
 		evaluation_order: EvaluationOrder,
 		n_vars: usize,
 		tensor_query: Option<MultilinearQueryRef<P>>,
-		multilinears: &[SumcheckMultilinear<P, M>],
+		multilinears: &[SumcheckMultilinear<P, M, SumcheckBackend>],
 		evaluators: &[Evaluator],
 		nontrivial_evaluation_points: &[FDomain],
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
@@ -58,7 +90,8 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		P: PackedExtension<FDomain>,
 		M: MultilinearPoly<P> + Send + Sync,
 		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
-		Composition: CompositionPoly<P>;
+		Composition: CompositionPoly<P>,
+		SumcheckBackend: ComputationBackend<Vec<P> = Self::Vec<P>>;
 
 	/// Partially evaluate the polynomial with assignment to the high-indexed variables.
 	fn evaluate_partial_high<P: PackedField>(
@@ -66,6 +99,195 @@ pub trait ComputationBackend: Send + Sync + Debug {
 		multilinear: &impl MultilinearPoly<P>,
 		query_expansion: MultilinearQueryRef<P>,
 	) -> Result<MultilinearExtension<P>, Error>;
+
+	/// Folds a multilinear into the large field at its sumcheck switchover round.
+	///
+	/// Returns the evaluations of `multilinear` partially evaluated at `query_expansion`, on the
+	/// low-indexed variables for [`EvaluationOrder::LowToHigh`] and on the high-indexed variables
+	/// for [`EvaluationOrder::HighToLow`].
+	fn sumcheck_switchover<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		multilinear: &(impl MultilinearPoly<P> + Sync),
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<Self::Vec<P>, Error> {
+		let folded = match evaluation_order {
+			EvaluationOrder::LowToHigh => multilinear.evaluate_partial_low(query_expansion)?,
+			EvaluationOrder::HighToLow => multilinear.evaluate_partial_high(query_expansion)?,
+		};
+		Ok(Self::to_hal_slice(folded.into_evals()))
+	}
+
+	/// Folds the evaluations of a multilinear past its sumcheck switchover round with the round
+	/// challenge, halving their number.
+	///
+	/// The lowest-indexed variable is folded for [`EvaluationOrder::LowToHigh`] and the
+	/// highest-indexed one for [`EvaluationOrder::HighToLow`]. The default implementation writes
+	/// the folded evaluations to a new buffer from [`Self::zeroed_hal_slice`], since `Self::Vec`
+	/// can't be shrunk in place.
+	fn sumcheck_fold_evals<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		challenge: P::Scalar,
+		evals: &mut Self::Vec<P>,
+	) -> Result<(), Error> {
+		let mut folded = self.zeroed_hal_slice(1 << n_vars.saturating_sub(1 + P::LOG_WIDTH));
+		match evaluation_order {
+			EvaluationOrder::LowToHigh => fold_right_lerp(evals, n_vars, challenge, &mut folded)?,
+			EvaluationOrder::HighToLow => {
+				// SAFETY: `fold_left_lerp` only writes initialized values.
+				let out = unsafe { as_maybe_uninit_mut(&mut folded) };
+				fold_left_lerp(evals, n_vars, challenge, out)?
+			}
+		}
+		*evals = folded;
+		Ok(())
+	}
+
+	/// Reed–Solomon encodes a batch of interleaved messages in place.
+	///
+	/// The first `2^(log_dim + log_batch_size)` scalars of `code` hold the interleaved messages.
+	/// On return, `code` holds the `2^log_inv_rate` cosets of the interleaved codeword, each
+	/// computed with the forward additive NTT over the corresponding coset of the evaluation
+	/// domain.
+	///
+	/// The default implementation runs the NTT on the CPU, in parallel across cosets if
+	/// `multithreaded` is set and sequentially otherwise.
+	///
+	/// ## Preconditions
+	///
+	/// * `code` has exactly `2^(log_dim + log_inv_rate + log_batch_size)` scalars.
+	/// * `P::WIDTH` divides `2^log_dim`.
+	#[cfg(feature = "ntt")]
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		multithreaded: bool,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		Ok(reed_solomon::encode_batch(
+			ntt,
+			log_dim,
+			log_inv_rate,
+			log_batch_size,
+			multithreaded,
+			code,
+		)?)
+	}
+
+	/// Hashes each chunk of field elements into a Merkle tree leaf digest.
+	///
+	/// The elements are serialized in the canonical tower representation. `iterated_chunks` must
+	/// yield exactly one chunk per digest.
+	#[cfg(feature = "merkle")]
+	fn merkle_hash_leaves<F, H, ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		digests: &mut [Output<H>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		merkle::hash_leaves::<F, H, _>(iterated_chunks, digests);
+		Ok(())
+	}
+
+	/// Compresses adjacent pairs of digests of a Merkle tree layer into the next layer.
+	///
+	/// On success, every element of `next_layer` is overwritten.
+	///
+	/// ## Preconditions
+	///
+	/// * `prev_layer.len() == 2 * next_layer.len()`
+	#[cfg(feature = "merkle")]
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [D],
+	) -> Result<(), Error>
+	where
+		D: Clone + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		merkle::compress_layer(compression, prev_layer, next_layer);
+		Ok(())
+	}
+
+	/// Folds a Reed–Solomon codeword with a sequence of FRI folding challenges.
+	///
+	/// ## Arguments
+	///
+	/// * `ntt` - the additive NTT the codeword was encoded with.
+	/// * `codeword` - the codeword to fold.
+	/// * `start_round` - the number of folds the codeword has already been through.
+	/// * `folding_challenges` - the folding challenges, one per fold.
+	#[cfg(feature = "ntt")]
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let mut folded = self.zeroed_hal_slice(codeword.len() >> folding_challenges.len());
+		fri_fold::fold_codeword(ntt, codeword, start_round, folding_challenges, &mut folded);
+		Ok(folded)
+	}
+
+	/// Folds an interleaved codeword into a single codeword with FRI folding challenges.
+	///
+	/// The first `log_batch_size` challenges combine the interleaved codewords and the remaining
+	/// ones are FRI folds of the combined codeword.
+	///
+	/// ## Arguments
+	///
+	/// * `ntt` - the additive NTT the codeword was encoded with.
+	/// * `codeword` - the interleaved codeword.
+	/// * `log_batch_size` - the base-2 logarithm of the batch size of the interleaved code.
+	/// * `challenges` - the folding challenges. The length must be at least `log_batch_size`.
+	#[cfg(feature = "ntt")]
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		log_batch_size: usize,
+		challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		let (interleave_challenges, fold_challenges) = challenges.split_at(log_batch_size);
+		let tensor = self.tensor_product_full_query::<F>(interleave_challenges)?;
+		let mut folded = self.zeroed_hal_slice(codeword.len() >> challenges.len());
+		fri_fold::fold_interleaved(
+			ntt,
+			codeword,
+			&tensor,
+			fold_challenges,
+			log_batch_size,
+			&mut folded,
+		);
+		Ok(folded)
+	}
 }
 
 /// Makes it unnecessary to clone backends.
@@ -80,6 +302,18 @@ where
 		T::to_hal_slice(v)
 	}
 
+	fn zeroed_hal_slice<P: PackedField>(&self, len: usize) -> Self::Vec<P> {
+		T::zeroed_hal_slice(self, len)
+	}
+
+	fn filled_hal_slice<V: Clone + Debug + Send + Sync + 'static>(
+		&self,
+		len: usize,
+		value: V,
+	) -> Self::Vec<V> {
+		T::filled_hal_slice(self, len, value)
+	}
+
 	fn tensor_product_full_query<P: PackedField>(
 		&self,
 		query: &[P::Scalar],
@@ -87,12 +321,12 @@ where
 		T::tensor_product_full_query(self, query)
 	}
 
-	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition, SumcheckBackend>(
 		&self,
 		evaluation_order: EvaluationOrder,
 		n_vars: usize,
 		tensor_query: Option<MultilinearQueryRef<P>>,
-		multilinears: &[SumcheckMultilinear<P, M>],
+		multilinears: &[SumcheckMultilinear<P, M, SumcheckBackend>],
 		evaluators: &[Evaluator],
 		nontrivial_evaluation_points: &[FDomain],
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
@@ -102,6 +336,7 @@ where
 		M: MultilinearPoly<P> + Send + Sync,
 		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 		Composition: CompositionPoly<P>,
+		SumcheckBackend: ComputationBackend<Vec<P> = Self::Vec<P>>,
 	{
 		T::sumcheck_compute_round_evals(
 			self,
@@ -121,6 +356,111 @@ where
 	) -> Result<MultilinearExtension<P>, Error> {
 		T::evaluate_partial_high(self, multilinear, query_expansion)
 	}
+
+	fn sumcheck_switchover<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		multilinear: &(impl MultilinearPoly<P> + Sync),
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<Self::Vec<P>, Error> {
+		T::sumcheck_switchover(self, evaluation_order, multilinear, query_expansion)
+	}
+
+	fn sumcheck_fold_evals<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		challenge: P::Scalar,
+		evals: &mut Self::Vec<P>,
+	) -> Result<(), Error> {
+		T::sumcheck_fold_evals(self, evaluation_order, n_vars, challenge, evals)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		multithreaded: bool,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		T::reed_solomon_encode_batch(
+			self,
+			ntt,
+			log_dim,
+			log_inv_rate,
+			log_batch_size,
+			multithreaded,
+			code,
+		)
+	}
+
+	#[cfg(feature = "merkle")]
+	fn merkle_hash_leaves<F, H, ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		digests: &mut [Output<H>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		T::merkle_hash_leaves::<F, H, _>(self, iterated_chunks, digests)
+	}
+
+	#[cfg(feature = "merkle")]
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [D],
+	) -> Result<(), Error>
+	where
+		D: Clone + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		T::merkle_compress_layer(self, compression, prev_layer, next_layer)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_codeword(self, ntt, codeword, start_round, folding_challenges)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		log_batch_size: usize,
+		challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		T::fri_fold_interleaved(self, ntt, codeword, log_batch_size, challenges)
+	}
 }
 
 pub trait ComputationBackendExt: ComputationBackend {
@@ -136,3 +476,14 @@ pub trait ComputationBackendExt: ComputationBackend {
 }
 
 impl<Backend> ComputationBackendExt for Backend where Backend: ComputationBackend {}
+
+/// Views initialized elements as possibly uninitialized ones, for functions that only write them.
+///
+/// # Safety
+///
+/// The caller must only write initialized values through the returned slice.
+unsafe fn as_maybe_uninit_mut<T: Copy>(values: &mut [T]) -> &mut [MaybeUninit<T>] {
+	// `MaybeUninit<T>` has the same layout as `T`, and `T: Copy` means that overwriting the
+	// elements without dropping them is fine.
+	slice::from_raw_parts_mut(values.as_mut_ptr().cast(), values.len())
+}
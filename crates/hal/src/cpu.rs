@@ -4,9 +4,10 @@ use std::fmt::Debug;
 
 use binius_field::{Field, PackedExtension, PackedField};
 use binius_math::{
-	eq_ind_partial_eval, CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly,
-	MultilinearQueryRef,
+	eq_ind_partial_eval, fold_left_lerp_inplace, fold_right_lerp, CompositionPoly, EvaluationOrder,
+	MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
 };
+use bytemuck::zeroed_vec;
 use tracing::instrument;
 
 use crate::{
@@ -37,12 +38,12 @@ impl ComputationBackend for CpuBackend {
 		Ok(eq_ind_partial_eval(query))
 	}
 
-	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition>(
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition, SumcheckBackend>(
 		&self,
 		evaluation_order: EvaluationOrder,
 		n_vars: usize,
 		tensor_query: Option<MultilinearQueryRef<P>>,
-		multilinears: &[SumcheckMultilinear<P, M>],
+		multilinears: &[SumcheckMultilinear<P, M, SumcheckBackend>],
 		evaluators: &[Evaluator],
 		nontrivial_evaluation_points: &[FDomain],
 	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
@@ -52,6 +53,7 @@ impl ComputationBackend for CpuBackend {
 		M: MultilinearPoly<P> + Send + Sync,
 		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 		Composition: CompositionPoly<P>,
+		SumcheckBackend: ComputationBackend<Vec<P> = Self::Vec<P>>,
 	{
 		calculate_round_evals(
 			evaluation_order,
@@ -71,4 +73,29 @@ impl ComputationBackend for CpuBackend {
 	) -> Result<MultilinearExtension<P>, Error> {
 		Ok(multilinear.evaluate_partial_high(query_expansion)?)
 	}
+
+	fn sumcheck_fold_evals<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		challenge: P::Scalar,
+		evals: &mut Self::Vec<P>,
+	) -> Result<(), Error> {
+		match evaluation_order {
+			// Lerp folding in low-to-high evaluation order can be made inplace, but not
+			// easily so if multithreading is desired.
+			EvaluationOrder::LowToHigh => {
+				let mut folded = zeroed_vec(1 << n_vars.saturating_sub(1 + P::LOG_WIDTH));
+				fold_right_lerp(&*evals, n_vars, challenge, &mut folded)?;
+				*evals = folded;
+			}
+			// High-to-low evaluation order allows trivial inplace multithreaded folding.
+			EvaluationOrder::HighToLow => {
+				// REVIEW: note that this method is currently _not_ multithreaded, as
+				//         traces are usually sufficiently wide
+				fold_left_lerp_inplace(evals, n_vars, challenge)?;
+			}
+		}
+		Ok(())
+	}
 }
@@ -19,4 +19,7 @@ pub enum Error {
 	IncorrectDestSliceLengths,
 	#[error("{0}")]
 	FieldError(#[from] binius_field::Error),
+	#[cfg(feature = "ntt")]
+	#[error("NTT error: {0}")]
+	NttError(#[from] binius_ntt::Error),
 }
@@ -7,10 +7,25 @@
 //! the architecture of [Irreducible's](https://www.irreducible.com) custom FPGA platform. The
 //! crate exposes a default, portable CPU backend that can be created with
 //! [`crate::make_portable_backend`].
+//!
+//! The backend hooks for Reed–Solomon encoding and FRI folding are enabled by the `ntt` feature,
+//! and those for Merkle tree hashing by the `merkle` feature. The `mmap` feature enables the
+//! out-of-core [`MmapBackend`](crate::MmapBackend).
 
 mod backend;
 mod cpu;
 mod error;
+#[cfg(feature = "ntt")]
+pub mod fri_fold;
+#[cfg(feature = "merkle")]
+mod merkle;
+#[cfg(feature = "mmap")]
+mod mmap_backend;
+#[cfg(feature = "mmap")]
+pub mod mmap_vec;
+mod profiling;
+#[cfg(feature = "ntt")]
+mod reed_solomon;
 mod sumcheck_evaluator;
 mod sumcheck_multilinear;
 mod sumcheck_round_calculator;
@@ -18,5 +33,8 @@ mod sumcheck_round_calculator;
 pub use backend::*;
 pub use cpu::*;
 pub use error::*;
+#[cfg(feature = "mmap")]
+pub use mmap_backend::*;
+pub use profiling::*;
 pub use sumcheck_evaluator::*;
 pub use sumcheck_multilinear::*;
@@ -0,0 +1,46 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+//! Binary Merkle tree hashing.
+
+use std::array;
+
+use binius_field::TowerField;
+use binius_hash::{HashBuffer, PseudoCompressionFunction};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::{SerializationMode, SerializeBytes};
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+pub(crate) fn hash_leaves<F, H, ParIter>(iterated_chunks: ParIter, digests: &mut [Output<H>])
+where
+	F: TowerField,
+	H: Digest + BlockSizeUser + FixedOutputReset,
+	ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+{
+	digests
+		.par_iter_mut()
+		.zip(iterated_chunks)
+		.for_each_init(H::new, |hasher, (digest, elems)| {
+			{
+				let mut hash_buffer = HashBuffer::new(hasher);
+				for elem in elems {
+					let mode = SerializationMode::CanonicalTower;
+					SerializeBytes::serialize(&elem, &mut hash_buffer, mode)
+						.expect("HashBuffer has infinite capacity");
+				}
+			}
+			*digest = Digest::finalize_reset(hasher);
+		});
+}
+
+pub(crate) fn compress_layer<D, C>(compression: &C, prev_layer: &[D], next_layer: &mut [D])
+where
+	D: Clone + Send + Sync,
+	C: PseudoCompressionFunction<D, 2> + Sync,
+{
+	prev_layer
+		.par_chunks_exact(2)
+		.zip(next_layer.par_iter_mut())
+		.for_each(|(prev_pair, next_digest)| {
+			*next_digest = compression.compress(array::from_fn(|i| prev_pair[i].clone()));
+		})
+}
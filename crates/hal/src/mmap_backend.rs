@@ -0,0 +1,392 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::fmt::Debug;
+
+#[cfg(feature = "ntt")]
+use binius_field::BinaryField;
+use binius_field::{Field, PackedExtension, PackedField};
+use binius_math::{
+	tensor_prod_eq_ind, CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly,
+	MultilinearQueryRef,
+};
+use binius_maybe_rayon::prelude::*;
+#[cfg(feature = "ntt")]
+use binius_ntt::AdditiveNTT;
+use tracing::instrument;
+
+use crate::{
+	mmap_vec::{default_spill_threshold, MmapArena, MmapVec},
+	sumcheck_round_calculator::calculate_round_evals,
+	ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear,
+};
+
+/// Base-2 logarithm of the number of scalars computed by one task of the switchover fold.
+const LOG_SWITCHOVER_CHUNK_SIZE: usize = 12;
+
+/// Computation backend for provers whose witnesses and codewords may not fit in memory.
+///
+/// Buffers allocated by this backend are [`MmapVec`]s, which spill to memory-mapped temporary
+/// files once they exceed the backend's spill threshold. This covers the committed codewords,
+/// the FRI folded codewords, the Merkle tree nodes and the folded sumcheck multilinears.
+/// Committed multilinears and witness columns can be stored the same way by backing them with an
+/// [`MmapVec`] or allocating them from the backend's [`arena`](Self::arena).
+///
+/// The computations are the same as for [`CpuBackend`], except for the following, which trade
+/// speed for a smaller working set:
+///
+/// * Reed–Solomon encoding processes one coset of the codeword at a time, with the `ntt`
+///   feature.
+/// * The sumcheck switchover fold writes subcubes of the folded multilinear directly into its
+///   file-backed buffer, instead of materialising it on the heap first.
+///
+/// [`CpuBackend`]: crate::CpuBackend
+#[derive(Clone, Debug)]
+pub struct MmapBackend {
+	spill_threshold: usize,
+}
+
+impl MmapBackend {
+	/// Creates a backend with the default spill threshold.
+	pub fn new() -> Self {
+		Self::with_spill_threshold(default_spill_threshold())
+	}
+
+	/// Creates a backend whose buffers spill once they exceed `spill_threshold` bytes.
+	pub const fn with_spill_threshold(spill_threshold: usize) -> Self {
+		Self { spill_threshold }
+	}
+
+	/// Returns the size in bytes above which buffers allocated by this backend are stored in
+	/// files.
+	pub const fn spill_threshold(&self) -> usize {
+		self.spill_threshold
+	}
+
+	/// Creates an arena for witness columns that spill at this backend's threshold.
+	pub const fn arena<T>(&self) -> MmapArena<T> {
+		MmapArena::with_spill_threshold(self.spill_threshold)
+	}
+}
+
+impl Default for MmapBackend {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl ComputationBackend for MmapBackend {
+	type Vec<P: Send + Sync + Debug + 'static> = MmapVec<P>;
+
+	/// Converts with the default spill threshold, since there is no backend instance to read it
+	/// from.
+	///
+	/// The `'static` bound is the one that [`ComputationBackend::Vec`] puts on its elements. The
+	/// trait method leaves it implied, but naming the concrete `MmapVec<P>` requires it to be
+	/// stated, as in the `CpuBackend` implementation.
+	fn to_hal_slice<P: Debug + Send + Sync + 'static>(v: Vec<P>) -> Self::Vec<P> {
+		MmapVec::from(v)
+	}
+
+	fn zeroed_hal_slice<P: PackedField>(&self, len: usize) -> Self::Vec<P> {
+		MmapVec::zeroed_with_spill_threshold(len, self.spill_threshold)
+	}
+
+	fn filled_hal_slice<T: Clone + Debug + Send + Sync + 'static>(
+		&self,
+		len: usize,
+		value: T,
+	) -> Self::Vec<T> {
+		MmapVec::filled_with_spill_threshold(len, value, self.spill_threshold)
+	}
+
+	#[instrument(skip_all, level = "trace")]
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		let mut buffer = self.zeroed_hal_slice::<P>(1 << query.len().saturating_sub(P::LOG_WIDTH));
+		buffer[0].set(0, P::Scalar::ONE);
+		tensor_prod_eq_ind(0, &mut buffer, query)?;
+		Ok(buffer)
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition, SumcheckBackend>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M, SumcheckBackend>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+		SumcheckBackend: ComputationBackend<Vec<P> = Self::Vec<P>>,
+	{
+		calculate_round_evals(
+			evaluation_order,
+			n_vars,
+			tensor_query,
+			multilinears,
+			evaluators,
+			nontrivial_evaluation_points,
+		)
+	}
+
+	#[instrument(skip_all, name = "MmapBackend::evaluate_partial_high")]
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		Ok(multilinear.evaluate_partial_high(query_expansion)?)
+	}
+
+	#[instrument(skip_all, name = "MmapBackend::sumcheck_switchover", level = "debug")]
+	fn sumcheck_switchover<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		multilinear: &(impl MultilinearPoly<P> + Sync),
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<Self::Vec<P>, Error> {
+		let n_vars = multilinear
+			.n_vars()
+			.saturating_sub(query_expansion.n_vars());
+		let log_chunk_size = n_vars.min(LOG_SWITCHOVER_CHUNK_SIZE.max(P::LOG_WIDTH));
+
+		let mut folded = self.zeroed_hal_slice::<P>(1 << n_vars.saturating_sub(P::LOG_WIDTH));
+		folded
+			.par_chunks_mut(1 << log_chunk_size.saturating_sub(P::LOG_WIDTH))
+			.enumerate()
+			.try_for_each(|(chunk_index, chunk)| match evaluation_order {
+				EvaluationOrder::LowToHigh => multilinear.subcube_partial_low_evals(
+					query_expansion,
+					log_chunk_size,
+					chunk_index,
+					chunk,
+				),
+				EvaluationOrder::HighToLow => multilinear.subcube_partial_high_evals(
+					query_expansion,
+					log_chunk_size,
+					chunk_index,
+					chunk,
+				),
+			})?;
+		Ok(folded)
+	}
+
+	#[instrument(
+		skip_all,
+		name = "MmapBackend::reed_solomon_encode_batch",
+		level = "debug"
+	)]
+	#[cfg(feature = "ntt")]
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		_multithreaded: bool,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		let msgs_len = ((1 << log_dim) / P::WIDTH) << log_batch_size;
+		let (msgs, cosets) = code.split_at_mut(msgs_len);
+
+		// The message occupies the first coset, so it is encoded in place after the others.
+		let n_cosets = (1 << log_inv_rate) - 1;
+		for (i, coset) in cosets.chunks_exact_mut(msgs_len).take(n_cosets).enumerate() {
+			coset.copy_from_slice(msgs);
+			ntt.forward_transform(coset, i as u32 + 1, log_batch_size, log_dim)?;
+		}
+		ntt.forward_transform(msgs, 0, log_batch_size, log_dim)?;
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField32b, PackedBinaryField4x32b};
+	use binius_math::{MLEDirectAdapter, MultilinearQuery};
+	#[cfg(feature = "ntt")]
+	use binius_ntt::SingleThreadedNTT;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::make_portable_backend;
+
+	/// Runs `test` in a child process whose data segment is limited to `cap` bytes.
+	///
+	/// Heap allocations and thread stacks count towards `RLIMIT_DATA`, but shared file mappings,
+	/// which back spilled [`MmapVec`]s, do not. The child process is the test binary itself,
+	/// filtered to run only `test_name`.
+	#[cfg(all(target_os = "linux", feature = "ntt"))]
+	fn run_with_memory_cap(test_name: &str, cap: usize, test: impl FnOnce()) {
+		const CHILD_ENV: &str = "BINIUS_HAL_MEMORY_CAPPED_CHILD";
+
+		if std::env::var_os(CHILD_ENV).is_some() {
+			let limit = libc::rlimit {
+				rlim_cur: cap as libc::rlim_t,
+				rlim_max: cap as libc::rlim_t,
+			};
+			// SAFETY: `setrlimit` only reads the limit it is passed.
+			assert_eq!(unsafe { libc::setrlimit(libc::RLIMIT_DATA, &limit) }, 0);
+			test();
+			return;
+		}
+
+		let status = std::process::Command::new(std::env::current_exe().unwrap())
+			.args([test_name, "--exact", "--test-threads=1", "--nocapture"])
+			.env(CHILD_ENV, "1")
+			.env("RAYON_NUM_THREADS", "1")
+			.status()
+			.unwrap();
+		assert!(status.success(), "memory-capped child process failed: {status}");
+	}
+
+	#[cfg(all(target_os = "linux", feature = "ntt"))]
+	#[test]
+	fn test_encode_under_memory_cap() {
+		const MEMORY_CAP: usize = 16 << 20;
+
+		run_with_memory_cap(
+			"mmap_backend::tests::test_encode_under_memory_cap",
+			MEMORY_CAP,
+			|| {
+				// The codeword is twice the size of the cap, which rules out heap allocation.
+				let (log_dim, log_inv_rate, log_batch_size) = (4, 1, 18);
+				let codeword_len = 1 << (log_dim + log_inv_rate + log_batch_size);
+				assert!(Vec::<BinaryField32b>::new()
+					.try_reserve_exact(codeword_len)
+					.is_err());
+
+				let backend = MmapBackend::with_spill_threshold(1 << 20);
+				let ntt = SingleThreadedNTT::<BinaryField32b>::new(log_dim + log_inv_rate).unwrap();
+				let mut code = backend.zeroed_hal_slice::<PackedBinaryField4x32b>(
+					codeword_len / PackedBinaryField4x32b::WIDTH,
+				);
+				assert!(code.is_mapped());
+
+				let msgs_len = code.len() >> log_inv_rate;
+				for (i, packed) in code[..msgs_len].iter_mut().enumerate() {
+					*packed = PackedBinaryField4x32b::broadcast(BinaryField32b::new(i as u32));
+				}
+				backend
+					.reed_solomon_encode_batch(
+						&ntt,
+						log_dim,
+						log_inv_rate,
+						log_batch_size,
+						false,
+						&mut code,
+					)
+					.unwrap();
+
+				// Encoding is linear and the messages are the same in every lane, so every
+				// packed element of the codeword has equal lanes.
+				assert!(code.iter().all(|packed| {
+					let first = packed.get(0);
+					packed.iter().all(|scalar| scalar == first)
+				}));
+			},
+		);
+	}
+
+	#[test]
+	fn test_sumcheck_switchover_matches_cpu() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 16;
+		let evals = repeat_with(|| PackedBinaryField4x32b::random(&mut rng))
+			.take(1 << (n_vars - PackedBinaryField4x32b::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let multilinear = MLEDirectAdapter::from(MultilinearExtension::from_values(evals).unwrap());
+		let query = repeat_with(|| <BinaryField32b as Field>::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let query = MultilinearQuery::<PackedBinaryField4x32b>::expand(&query);
+
+		let backend = MmapBackend::with_spill_threshold(0);
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let folded = backend
+				.sumcheck_switchover(evaluation_order, &multilinear, query.to_ref())
+				.unwrap();
+			let expected = make_portable_backend()
+				.sumcheck_switchover(evaluation_order, &multilinear, query.to_ref())
+				.unwrap();
+			assert!(folded.is_mapped());
+			assert_eq!(&*folded, expected.as_slice());
+		}
+	}
+
+	#[test]
+	fn test_sumcheck_fold_evals_matches_cpu() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 10;
+		let evals = repeat_with(|| PackedBinaryField4x32b::random(&mut rng))
+			.take(1 << (n_vars - PackedBinaryField4x32b::LOG_WIDTH))
+			.collect::<Vec<_>>();
+		let challenge = <BinaryField32b as Field>::random(&mut rng);
+
+		let backend = MmapBackend::with_spill_threshold(0);
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let mut folded = MmapVec::from_vec_with_spill_threshold(evals.clone(), 0);
+			backend
+				.sumcheck_fold_evals(evaluation_order, n_vars, challenge, &mut folded)
+				.unwrap();
+			let mut expected = evals.clone();
+			make_portable_backend()
+				.sumcheck_fold_evals(evaluation_order, n_vars, challenge, &mut expected)
+				.unwrap();
+			assert!(folded.is_mapped());
+			assert_eq!(&*folded, expected.as_slice());
+		}
+	}
+
+	#[cfg(feature = "ntt")]
+	#[test]
+	fn test_reed_solomon_encode_matches_cpu() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let (log_dim, log_inv_rate, log_batch_size) = (6, 2, 1);
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(log_dim + log_inv_rate).unwrap();
+
+		let msgs_len = (1 << (log_dim + log_batch_size)) / PackedBinaryField4x32b::WIDTH;
+		let mut code = repeat_with(|| PackedBinaryField4x32b::random(&mut rng))
+			.take(msgs_len)
+			.collect::<Vec<_>>();
+		code.resize(msgs_len << log_inv_rate, PackedBinaryField4x32b::default());
+		let mut expected = code.clone();
+
+		MmapBackend::new()
+			.reed_solomon_encode_batch(
+				&ntt,
+				log_dim,
+				log_inv_rate,
+				log_batch_size,
+				false,
+				&mut code,
+			)
+			.unwrap();
+		make_portable_backend()
+			.reed_solomon_encode_batch(
+				&ntt,
+				log_dim,
+				log_inv_rate,
+				log_batch_size,
+				true,
+				&mut expected,
+			)
+			.unwrap();
+		assert_eq!(code, expected);
+	}
+}
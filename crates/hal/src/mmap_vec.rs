@@ -0,0 +1,529 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Vectors that spill to memory-mapped temporary files.
+//!
+//! [`MmapVec`] behaves like a [`Vec`] while it is small. Once its contents exceed the spill
+//! threshold, they are moved to an unlinked temporary file that is mapped into the address
+//! space. The operating system can then write the pages back to the file and evict them under
+//! memory pressure, so the resident memory of a prover is no longer bounded below by the size of
+//! its largest buffers.
+//!
+//! The temporary files are created in [`std::env::temp_dir`], which can be changed with the
+//! `TMPDIR` environment variable. Every vector carries its own spill threshold. The default
+//! threshold is 64 MiB and can be set with the `BINIUS_MMAP_SPILL_THRESHOLD` environment
+//! variable, in bytes. Owners of many buffers, like [`MmapBackend`] and [`MmapArena`], pass their
+//! own threshold to the vectors they create.
+//!
+//! [`MmapBackend`]: crate::MmapBackend
+
+use std::{
+	cell::RefCell,
+	fmt::{self, Debug},
+	fs::File,
+	iter,
+	marker::PhantomData,
+	mem,
+	ops::{Deref, DerefMut},
+	ptr, slice,
+	sync::OnceLock,
+};
+
+use binius_maybe_rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelIterator};
+use bytemuck::Zeroable;
+use memmap2::{MmapMut, MmapOptions};
+
+use crate::HalSlice;
+
+/// The environment variable that sets the initial spill threshold, in bytes.
+pub const SPILL_THRESHOLD_ENV: &str = "BINIUS_MMAP_SPILL_THRESHOLD";
+
+const DEFAULT_SPILL_THRESHOLD: usize = 1 << 26;
+
+/// The alignment guaranteed by `mmap`, which returns page-aligned addresses.
+const MIN_PAGE_SIZE: usize = 1 << 12;
+
+/// Returns the default size in bytes above which [`MmapVec`] contents are stored in a file.
+///
+/// This is read from the [`SPILL_THRESHOLD_ENV`] environment variable on first use.
+pub fn default_spill_threshold() -> usize {
+	static THRESHOLD: OnceLock<usize> = OnceLock::new();
+	*THRESHOLD.get_or_init(|| {
+		std::env::var(SPILL_THRESHOLD_ENV)
+			.ok()
+			.and_then(|val| val.parse().ok())
+			.unwrap_or(DEFAULT_SPILL_THRESHOLD)
+	})
+}
+
+const fn can_map<T>() -> bool {
+	mem::size_of::<T>() != 0 && mem::align_of::<T>() <= MIN_PAGE_SIZE
+}
+
+fn should_spill<T>(len: usize, spill_threshold: usize) -> bool {
+	can_map::<T>() && len.saturating_mul(mem::size_of::<T>()) > spill_threshold
+}
+
+/// A growable vector whose contents are stored either on the heap or in a memory-mapped
+/// temporary file.
+///
+/// The storage is chosen when the vector is created and moves to a file once the vector grows
+/// past the spill threshold. It never moves back to the heap. See the [module
+/// documentation](self) for how to configure spilling.
+///
+/// ## Panics
+///
+/// Like allocation failure for [`Vec`], failing to create, resize or map the temporary file is
+/// not recoverable and panics.
+pub struct MmapVec<T> {
+	storage: Storage<T>,
+	spill_threshold: usize,
+}
+
+enum Storage<T> {
+	Heap(Vec<T>),
+	Mapped(MappedBuffer<T>),
+}
+
+impl<T> MmapVec<T> {
+	/// Creates an empty vector with the default spill threshold.
+	pub fn new() -> Self {
+		Self::with_spill_threshold(default_spill_threshold())
+	}
+
+	/// Creates an empty vector that spills once its contents exceed `spill_threshold` bytes.
+	pub const fn with_spill_threshold(spill_threshold: usize) -> Self {
+		Self {
+			storage: Storage::Heap(Vec::new()),
+			spill_threshold,
+		}
+	}
+
+	/// Creates an empty vector with room for at least `capacity` elements.
+	pub fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity_and_spill_threshold(capacity, default_spill_threshold())
+	}
+
+	/// Creates an empty vector with room for at least `capacity` elements that spills once its
+	/// contents exceed `spill_threshold` bytes.
+	pub fn with_capacity_and_spill_threshold(capacity: usize, spill_threshold: usize) -> Self {
+		let storage = if should_spill::<T>(capacity, spill_threshold) {
+			Storage::Mapped(MappedBuffer::with_capacity(capacity))
+		} else {
+			Storage::Heap(Vec::with_capacity(capacity))
+		};
+		Self {
+			storage,
+			spill_threshold,
+		}
+	}
+
+	/// Creates a vector of `len` zeroed elements with the default spill threshold.
+	///
+	/// When the vector is file-backed, the zeroed pages are not touched until they are accessed,
+	/// so this does not increase resident memory.
+	pub fn zeroed(len: usize) -> Self
+	where
+		T: Zeroable,
+	{
+		Self::zeroed_with_spill_threshold(len, default_spill_threshold())
+	}
+
+	/// Creates a vector of `len` zeroed elements that spills once its contents exceed
+	/// `spill_threshold` bytes.
+	pub fn zeroed_with_spill_threshold(len: usize, spill_threshold: usize) -> Self
+	where
+		T: Zeroable,
+	{
+		let storage = if should_spill::<T>(len, spill_threshold) {
+			let mut buffer = MappedBuffer::with_capacity(len);
+			// A freshly resized file reads as zeros, which is a valid value of `T`.
+			buffer.len = len;
+			Storage::Mapped(buffer)
+		} else {
+			Storage::Heap(bytemuck::zeroed_vec(len))
+		};
+		Self {
+			storage,
+			spill_threshold,
+		}
+	}
+
+	/// Creates a vector of `len` copies of `value` that spills once its contents exceed
+	/// `spill_threshold` bytes.
+	pub fn filled_with_spill_threshold(len: usize, value: T, spill_threshold: usize) -> Self
+	where
+		T: Clone,
+	{
+		let mut result = Self::with_capacity_and_spill_threshold(len, spill_threshold);
+		result.extend(iter::repeat_n(value, len));
+		result
+	}
+
+	/// Moves the contents of `vec` into a vector that spills once its contents exceed
+	/// `spill_threshold` bytes.
+	pub fn from_vec_with_spill_threshold(vec: Vec<T>, spill_threshold: usize) -> Self {
+		let len = vec.len();
+		let mut result = Self {
+			storage: Storage::Heap(vec),
+			spill_threshold,
+		};
+		if should_spill::<T>(len, spill_threshold) {
+			result.spill(len);
+		}
+		result
+	}
+
+	/// Returns the size in bytes above which the contents of this vector are stored in a file.
+	pub const fn spill_threshold(&self) -> usize {
+		self.spill_threshold
+	}
+
+	/// Returns whether the contents are stored in a memory-mapped file.
+	pub const fn is_mapped(&self) -> bool {
+		matches!(self.storage, Storage::Mapped(_))
+	}
+
+	/// Appends an element to the back of the vector.
+	pub fn push(&mut self, value: T) {
+		if !self.is_mapped() && should_spill::<T>(self.len() + 1, self.spill_threshold) {
+			self.spill(self.len() + 1);
+		}
+		match &mut self.storage {
+			Storage::Heap(vec) => vec.push(value),
+			Storage::Mapped(buffer) => buffer.push(value),
+		}
+	}
+
+	/// Moves all the elements of `other` to the back of `self`, leaving `other` empty.
+	pub fn append(&mut self, other: &mut Self) {
+		let other_len = other.len();
+		if other_len == 0 {
+			return;
+		}
+		if self.is_empty() && (other.is_mapped() || !self.is_mapped()) {
+			mem::swap(&mut self.storage, &mut other.storage);
+			if !self.is_mapped() && should_spill::<T>(other_len, self.spill_threshold) {
+				self.spill(other_len);
+			}
+			return;
+		}
+
+		let new_len = self.len() + other_len;
+		if !self.is_mapped() && should_spill::<T>(new_len, self.spill_threshold) {
+			self.spill(new_len);
+		}
+		match &mut self.storage {
+			Storage::Heap(vec) => vec.reserve(other_len),
+			Storage::Mapped(buffer) => buffer.reserve(other_len),
+		}
+
+		let src = other.as_mut_ptr();
+		let dst = self.as_mut_ptr();
+		// SAFETY: the destination has capacity for `new_len` elements and the source holds
+		// `other_len` initialized elements. Ownership of the elements is transferred by setting
+		// the length of `other` to zero.
+		unsafe {
+			ptr::copy_nonoverlapping(src, dst.add(new_len - other_len), other_len);
+			other.set_len(0);
+			self.set_len(new_len);
+		}
+	}
+
+	/// Moves the heap contents to a file with room for at least `capacity` elements.
+	fn spill(&mut self, capacity: usize) {
+		let Storage::Heap(vec) = &mut self.storage else {
+			return;
+		};
+		let mut buffer = MappedBuffer::with_capacity(capacity.max(vec.len()));
+		// SAFETY: the buffer has capacity for `vec.len()` elements, and the elements are moved
+		// out of the heap vector by setting its length to zero.
+		unsafe {
+			ptr::copy_nonoverlapping(vec.as_ptr(), buffer.as_mut_ptr(), vec.len());
+			buffer.len = vec.len();
+			vec.set_len(0);
+		}
+		self.storage = Storage::Mapped(buffer);
+	}
+
+	fn as_mut_ptr(&mut self) -> *mut T {
+		match &mut self.storage {
+			Storage::Heap(vec) => vec.as_mut_ptr(),
+			Storage::Mapped(buffer) => buffer.as_mut_ptr(),
+		}
+	}
+
+	/// # Safety
+	///
+	/// The first `len` elements must be initialized and `len` must not exceed the capacity.
+	unsafe fn set_len(&mut self, len: usize) {
+		match &mut self.storage {
+			Storage::Heap(vec) => vec.set_len(len),
+			Storage::Mapped(buffer) => buffer.len = len,
+		}
+	}
+}
+
+impl<T> Default for MmapVec<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> Deref for MmapVec<T> {
+	type Target = [T];
+
+	fn deref(&self) -> &[T] {
+		match &self.storage {
+			Storage::Heap(vec) => vec,
+			Storage::Mapped(buffer) => buffer.as_slice(),
+		}
+	}
+}
+
+impl<T> DerefMut for MmapVec<T> {
+	fn deref_mut(&mut self) -> &mut [T] {
+		match &mut self.storage {
+			Storage::Heap(vec) => vec,
+			Storage::Mapped(buffer) => buffer.as_mut_slice(),
+		}
+	}
+}
+
+impl<T: Debug> Debug for MmapVec<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		Debug::fmt(&**self, f)
+	}
+}
+
+impl<T> From<Vec<T>> for MmapVec<T> {
+	fn from(vec: Vec<T>) -> Self {
+		Self::from_vec_with_spill_threshold(vec, default_spill_threshold())
+	}
+}
+
+impl<T> FromIterator<T> for MmapVec<T> {
+	fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+		let mut result = Self::new();
+		result.extend(iter);
+		result
+	}
+}
+
+impl<T> Extend<T> for MmapVec<T> {
+	fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+		for value in iter {
+			self.push(value);
+		}
+	}
+}
+
+/// Collects with the default spill threshold.
+impl<T: Send> FromParallelIterator<T> for MmapVec<T> {
+	fn from_par_iter<I>(par_iter: I) -> Self
+	where
+		I: IntoParallelIterator<Item = T>,
+	{
+		par_iter
+			.into_par_iter()
+			.fold(Self::new, |mut vec, value| {
+				vec.push(value);
+				vec
+			})
+			.reduce(Self::new, |mut left, mut right| {
+				left.append(&mut right);
+				left
+			})
+	}
+}
+
+impl<T: Send + Sync + Debug + 'static> HalSlice<T> for MmapVec<T> {}
+
+/// An arena that allocates slices backed by [`MmapVec`]s.
+///
+/// This plays the role of a `bumpalo::Bump` for large buffers, such as witness columns. The
+/// allocated slices live as long as the arena, and their memory is released when the arena is
+/// dropped. Allocations larger than the spill threshold are stored in memory-mapped files.
+pub struct MmapArena<T> {
+	spill_threshold: usize,
+	buffers: RefCell<Vec<MmapVec<T>>>,
+}
+
+impl<T> MmapArena<T> {
+	/// Creates an arena with the default spill threshold.
+	pub fn new() -> Self {
+		Self::with_spill_threshold(default_spill_threshold())
+	}
+
+	/// Creates an arena whose allocations spill once they exceed `spill_threshold` bytes.
+	pub const fn with_spill_threshold(spill_threshold: usize) -> Self {
+		Self {
+			spill_threshold,
+			buffers: RefCell::new(Vec::new()),
+		}
+	}
+
+	/// Allocates a slice of `len` copies of `value`.
+	#[allow(clippy::mut_from_ref)]
+	pub fn alloc_filled(&self, len: usize, value: T) -> &mut [T]
+	where
+		T: Clone,
+	{
+		let mut buffer = MmapVec::filled_with_spill_threshold(len, value, self.spill_threshold);
+		let ptr = buffer.as_mut_ptr();
+		self.buffers.borrow_mut().push(buffer);
+		// SAFETY: the elements of an `MmapVec` live in a heap allocation or a memory mapping,
+		// which does not move when the vector is moved into `buffers`. The vector is neither
+		// accessed nor dropped before the arena is dropped, which the returned lifetime prevents.
+		unsafe { slice::from_raw_parts_mut(ptr, len) }
+	}
+}
+
+impl<T> Default for MmapArena<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T> Debug for MmapArena<T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("MmapArena")
+			.field("spill_threshold", &self.spill_threshold)
+			.field("n_buffers", &self.buffers.borrow().len())
+			.finish()
+	}
+}
+
+/// Elements stored in a shared, writable mapping of an unlinked temporary file.
+struct MappedBuffer<T> {
+	file: File,
+	map: MmapMut,
+	len: usize,
+	capacity: usize,
+	_marker: PhantomData<T>,
+}
+
+impl<T> MappedBuffer<T> {
+	fn with_capacity(capacity: usize) -> Self {
+		assert!(can_map::<T>(), "element type cannot be stored in a memory-mapped file");
+
+		let capacity = capacity.max(1);
+		let file = tempfile::tempfile().expect("failed to create a temporary file");
+		let map = Self::map_file(&file, capacity);
+		Self {
+			file,
+			map,
+			len: 0,
+			capacity,
+			_marker: PhantomData,
+		}
+	}
+
+	fn map_file(file: &File, capacity: usize) -> MmapMut {
+		let n_bytes = capacity
+			.checked_mul(mem::size_of::<T>())
+			.expect("capacity overflow");
+		file.set_len(n_bytes as u64)
+			.expect("failed to resize the temporary file");
+		// SAFETY: the file is unlinked and owned by this buffer, so no other process or mapping
+		// can modify it.
+		unsafe { MmapOptions::new().len(n_bytes).map_mut(file) }
+			.expect("failed to map the temporary file")
+	}
+
+	fn reserve(&mut self, additional: usize) {
+		let required = self.len.checked_add(additional).expect("capacity overflow");
+		if required <= self.capacity {
+			return;
+		}
+
+		let capacity = required.max(2 * self.capacity);
+		// The mapping is shared, so the contents persist in the file when it is remapped.
+		self.map
+			.flush()
+			.expect("failed to flush the temporary file");
+		self.map = Self::map_file(&self.file, capacity);
+		self.capacity = capacity;
+	}
+
+	fn push(&mut self, value: T) {
+		self.reserve(1);
+		// SAFETY: `reserve` ensures there is room for one more element.
+		unsafe { self.as_mut_ptr().add(self.len).write(value) };
+		self.len += 1;
+	}
+
+	fn as_ptr(&self) -> *const T {
+		self.map.as_ptr().cast()
+	}
+
+	fn as_mut_ptr(&mut self) -> *mut T {
+		self.map.as_mut_ptr().cast()
+	}
+
+	fn as_slice(&self) -> &[T] {
+		// SAFETY: the mapping is page-aligned, which satisfies the alignment of `T`, and the
+		// first `len` elements are initialized.
+		unsafe { slice::from_raw_parts(self.as_ptr(), self.len) }
+	}
+
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		// SAFETY: see `as_slice`.
+		unsafe { slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
+	}
+}
+
+impl<T> Drop for MappedBuffer<T> {
+	fn drop(&mut self) {
+		if mem::needs_drop::<T>() {
+			// SAFETY: the first `len` elements are initialized and are not used again.
+			unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_maybe_rayon::prelude::*;
+
+	use super::*;
+
+	#[test]
+	fn test_collect_spills_and_preserves_order() {
+		let values = (0..10_000u64).collect::<Vec<_>>();
+
+		// Every non-empty vector spills with a zero threshold.
+		let mut sequential = MmapVec::with_spill_threshold(0);
+		sequential.extend(values.iter().copied());
+		assert!(sequential.is_mapped());
+		assert_eq!(&*sequential, values.as_slice());
+
+		let mut parallel = MmapVec::with_spill_threshold(0);
+		let mut collected = values.par_iter().copied().collect::<MmapVec<_>>();
+		parallel.append(&mut collected);
+		assert!(parallel.is_mapped());
+		assert_eq!(&*parallel, values.as_slice());
+	}
+
+	#[test]
+	fn test_zeroed_and_drop() {
+		let zeros = MmapVec::<u128>::zeroed(1 << 12);
+		assert!(zeros.iter().all(|&x| x == 0));
+
+		let mut strings = MmapVec::from_vec_with_spill_threshold(vec![String::from("a"); 3], 0);
+		assert!(strings.is_mapped());
+		strings.push(String::from("b"));
+		assert_eq!(strings.concat(), "aaab");
+	}
+
+	#[test]
+	fn test_arena_allocations_are_independent() {
+		let arena = MmapArena::with_spill_threshold(1 << 10);
+		let small = arena.alloc_filled(4, 1u64);
+		let large = arena.alloc_filled(1 << 12, 2u64);
+		small[0] = 3;
+		large[0] = 4;
+		assert_eq!(small, &[3, 1, 1, 1]);
+		assert_eq!(large[..2], [4, 2]);
+		assert!(arena.buffers.borrow()[1].is_mapped());
+	}
+}
@@ -0,0 +1,453 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{
+	collections::BTreeMap,
+	fmt::{self, Debug, Display},
+	sync::{Arc, Mutex},
+	time::{Duration, Instant},
+};
+
+#[cfg(feature = "merkle")]
+use binius_field::TowerField;
+#[cfg(feature = "ntt")]
+use binius_field::{BinaryField, ExtensionField};
+use binius_field::{Field, PackedExtension, PackedField};
+#[cfg(feature = "merkle")]
+use binius_hash::PseudoCompressionFunction;
+use binius_math::{
+	CompositionPoly, EvaluationOrder, MultilinearExtension, MultilinearPoly, MultilinearQueryRef,
+};
+#[cfg(feature = "merkle")]
+use binius_maybe_rayon::iter::IndexedParallelIterator;
+#[cfg(feature = "ntt")]
+use binius_ntt::AdditiveNTT;
+#[cfg(feature = "merkle")]
+use digest::{core_api::BlockSizeUser, Digest, FixedOutputReset, Output};
+
+use crate::{ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+
+/// A backend operation that is recorded by [`ProfilingBackend`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BackendOperation {
+	TensorProductFullQuery,
+	SumcheckComputeRoundEvals,
+	EvaluatePartialHigh,
+}
+
+impl Display for BackendOperation {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		let name = match self {
+			Self::TensorProductFullQuery => "tensor_product_full_query",
+			Self::SumcheckComputeRoundEvals => "sumcheck_compute_round_evals",
+			Self::EvaluatePartialHigh => "evaluate_partial_high",
+		};
+		f.write_str(name)
+	}
+}
+
+/// A single recorded call to a backend operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRecord {
+	pub operation: BackendOperation,
+	/// Number of variables of the multilinears, or the length of the query for tensor product
+	/// expansions.
+	pub n_vars: usize,
+	/// Number of multilinears the operation was called with.
+	pub n_multilinears: usize,
+	/// Number of sumcheck evaluators the operation was called with.
+	pub n_evaluators: usize,
+	/// Wall time spent in the inner backend.
+	pub elapsed: Duration,
+}
+
+/// Aggregated statistics of the calls to one operation with the same number of variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OperationStats {
+	pub n_calls: usize,
+	pub total_multilinears: usize,
+	pub total_evaluators: usize,
+	pub total_time: Duration,
+	pub max_time: Duration,
+}
+
+/// Summary of the calls recorded by a [`ProfilingBackend`], grouped by operation and number of
+/// variables.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileSummary {
+	pub stats: BTreeMap<(BackendOperation, usize), OperationStats>,
+}
+
+impl ProfileSummary {
+	pub fn from_records<'a>(records: impl IntoIterator<Item = &'a CallRecord>) -> Self {
+		let mut stats = BTreeMap::<_, OperationStats>::new();
+		for record in records {
+			let entry = stats.entry((record.operation, record.n_vars)).or_default();
+			entry.n_calls += 1;
+			entry.total_multilinears += record.n_multilinears;
+			entry.total_evaluators += record.n_evaluators;
+			entry.total_time += record.elapsed;
+			entry.max_time = entry.max_time.max(record.elapsed);
+		}
+		Self { stats }
+	}
+
+	/// Total wall time spent in the recorded operations.
+	pub fn total_time(&self) -> Duration {
+		self.stats.values().map(|stats| stats.total_time).sum()
+	}
+}
+
+impl Display for ProfileSummary {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		writeln!(
+			f,
+			"{:<30} {:>6} {:>8} {:>12} {:>12} {:>14} {:>14}",
+			"operation", "n_vars", "calls", "multilinears", "evaluators", "total", "max"
+		)?;
+		for ((operation, n_vars), stats) in &self.stats {
+			writeln!(
+				f,
+				"{:<30} {:>6} {:>8} {:>12} {:>12} {:>14?} {:>14?}",
+				operation.to_string(),
+				n_vars,
+				stats.n_calls,
+				stats.total_multilinears,
+				stats.total_evaluators,
+				stats.total_time,
+				stats.max_time,
+			)?;
+		}
+		write!(f, "total time: {:?}", self.total_time())
+	}
+}
+
+/// A [`ComputationBackend`] that forwards every call to an inner backend and records the call
+/// counts, sizes and wall time of its main operations.
+///
+/// The recorded calls are shared between clones of the backend, so a summary can be read after
+/// passing a reference to the backend into a prover, such as `constraint_system::prove`:
+///
+/// ```ignore
+/// let backend = ProfilingBackend::new(make_portable_backend());
+/// let proof = constraint_system::prove::<...>(..., &backend)?;
+/// println!("{}", backend.summary());
+/// ```
+///
+/// Only [`ComputationBackend::tensor_product_full_query`],
+/// [`ComputationBackend::sumcheck_compute_round_evals`] and
+/// [`ComputationBackend::evaluate_partial_high`] are recorded; the remaining operations are
+/// forwarded without being recorded.
+#[derive(Debug, Clone, Default)]
+pub struct ProfilingBackend<Backend> {
+	inner: Backend,
+	records: Arc<Mutex<Vec<CallRecord>>>,
+}
+
+impl<Backend: ComputationBackend> ProfilingBackend<Backend> {
+	pub fn new(inner: Backend) -> Self {
+		Self {
+			inner,
+			records: Default::default(),
+		}
+	}
+
+	pub const fn inner(&self) -> &Backend {
+		&self.inner
+	}
+
+	/// Returns the calls recorded so far, in the order they completed.
+	pub fn records(&self) -> Vec<CallRecord> {
+		self.lock_records().clone()
+	}
+
+	/// Returns the summary of the calls recorded so far.
+	pub fn summary(&self) -> ProfileSummary {
+		ProfileSummary::from_records(self.lock_records().iter())
+	}
+
+	/// Clears the recorded calls.
+	pub fn reset(&self) {
+		self.lock_records().clear();
+	}
+
+	fn lock_records(&self) -> std::sync::MutexGuard<'_, Vec<CallRecord>> {
+		// A panic while holding the lock can't leave the records in an inconsistent state.
+		self.records
+			.lock()
+			.unwrap_or_else(|poisoned| poisoned.into_inner())
+	}
+
+	fn record<R>(
+		&self,
+		operation: BackendOperation,
+		n_vars: usize,
+		n_multilinears: usize,
+		n_evaluators: usize,
+		f: impl FnOnce() -> R,
+	) -> R {
+		let start = Instant::now();
+		let result = f();
+		let elapsed = start.elapsed();
+		self.lock_records().push(CallRecord {
+			operation,
+			n_vars,
+			n_multilinears,
+			n_evaluators,
+			elapsed,
+		});
+		result
+	}
+}
+
+impl<Backend: ComputationBackend> ComputationBackend for ProfilingBackend<Backend> {
+	type Vec<P: Send + Sync + Debug + 'static> = Backend::Vec<P>;
+
+	fn to_hal_slice<P: Debug + Send + Sync>(v: Vec<P>) -> Self::Vec<P> {
+		Backend::to_hal_slice(v)
+	}
+
+	fn zeroed_hal_slice<P: PackedField>(&self, len: usize) -> Self::Vec<P> {
+		self.inner.zeroed_hal_slice(len)
+	}
+
+	fn filled_hal_slice<T: Clone + Debug + Send + Sync + 'static>(
+		&self,
+		len: usize,
+		value: T,
+	) -> Self::Vec<T> {
+		self.inner.filled_hal_slice(len, value)
+	}
+
+	fn tensor_product_full_query<P: PackedField>(
+		&self,
+		query: &[P::Scalar],
+	) -> Result<Self::Vec<P>, Error> {
+		self.record(BackendOperation::TensorProductFullQuery, query.len(), 0, 0, || {
+			self.inner.tensor_product_full_query(query)
+		})
+	}
+
+	fn sumcheck_compute_round_evals<FDomain, P, M, Evaluator, Composition, SumcheckBackend>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		tensor_query: Option<MultilinearQueryRef<P>>,
+		multilinears: &[SumcheckMultilinear<P, M, SumcheckBackend>],
+		evaluators: &[Evaluator],
+		nontrivial_evaluation_points: &[FDomain],
+	) -> Result<Vec<RoundEvals<P::Scalar>>, Error>
+	where
+		FDomain: Field,
+		P: PackedExtension<FDomain>,
+		M: MultilinearPoly<P> + Send + Sync,
+		Evaluator: SumcheckEvaluator<P, Composition> + Sync,
+		Composition: CompositionPoly<P>,
+		SumcheckBackend: ComputationBackend<Vec<P> = Self::Vec<P>>,
+	{
+		self.record(
+			BackendOperation::SumcheckComputeRoundEvals,
+			n_vars,
+			multilinears.len(),
+			evaluators.len(),
+			|| {
+				self.inner.sumcheck_compute_round_evals(
+					evaluation_order,
+					n_vars,
+					tensor_query,
+					multilinears,
+					evaluators,
+					nontrivial_evaluation_points,
+				)
+			},
+		)
+	}
+
+	fn evaluate_partial_high<P: PackedField>(
+		&self,
+		multilinear: &impl MultilinearPoly<P>,
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		self.record(BackendOperation::EvaluatePartialHigh, multilinear.n_vars(), 1, 0, || {
+			self.inner
+				.evaluate_partial_high(multilinear, query_expansion)
+		})
+	}
+
+	fn sumcheck_switchover<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		multilinear: &(impl MultilinearPoly<P> + Sync),
+		query_expansion: MultilinearQueryRef<P>,
+	) -> Result<Self::Vec<P>, Error> {
+		self.inner
+			.sumcheck_switchover(evaluation_order, multilinear, query_expansion)
+	}
+
+	fn sumcheck_fold_evals<P: PackedField>(
+		&self,
+		evaluation_order: EvaluationOrder,
+		n_vars: usize,
+		challenge: P::Scalar,
+		evals: &mut Self::Vec<P>,
+	) -> Result<(), Error> {
+		self.inner
+			.sumcheck_fold_evals(evaluation_order, n_vars, challenge, evals)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn reed_solomon_encode_batch<F, P, NTT>(
+		&self,
+		ntt: &NTT,
+		log_dim: usize,
+		log_inv_rate: usize,
+		log_batch_size: usize,
+		multithreaded: bool,
+		code: &mut [P],
+	) -> Result<(), Error>
+	where
+		F: BinaryField,
+		P: PackedField<Scalar = F>,
+		NTT: AdditiveNTT<F> + Sync,
+	{
+		self.inner.reed_solomon_encode_batch(
+			ntt,
+			log_dim,
+			log_inv_rate,
+			log_batch_size,
+			multithreaded,
+			code,
+		)
+	}
+
+	#[cfg(feature = "merkle")]
+	fn merkle_hash_leaves<F, H, ParIter>(
+		&self,
+		iterated_chunks: ParIter,
+		digests: &mut [Output<H>],
+	) -> Result<(), Error>
+	where
+		F: TowerField,
+		H: Digest + BlockSizeUser + FixedOutputReset,
+		ParIter: IndexedParallelIterator<Item: IntoIterator<Item = F>>,
+	{
+		self.inner
+			.merkle_hash_leaves::<F, H, _>(iterated_chunks, digests)
+	}
+
+	#[cfg(feature = "merkle")]
+	fn merkle_compress_layer<D, C>(
+		&self,
+		compression: &C,
+		prev_layer: &[D],
+		next_layer: &mut [D],
+	) -> Result<(), Error>
+	where
+		D: Clone + Send + Sync,
+		C: PseudoCompressionFunction<D, 2> + Sync,
+	{
+		self.inner
+			.merkle_compress_layer(compression, prev_layer, next_layer)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn fri_fold_codeword<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		start_round: usize,
+		folding_challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		self.inner
+			.fri_fold_codeword(ntt, codeword, start_round, folding_challenges)
+	}
+
+	#[cfg(feature = "ntt")]
+	fn fri_fold_interleaved<F, FS, NTT>(
+		&self,
+		ntt: &NTT,
+		codeword: &[F],
+		log_batch_size: usize,
+		challenges: &[F],
+	) -> Result<Self::Vec<F>, Error>
+	where
+		F: BinaryField + ExtensionField<FS>,
+		FS: BinaryField,
+		NTT: AdditiveNTT<FS> + Sync,
+	{
+		self.inner
+			.fri_fold_interleaved(ntt, codeword, log_batch_size, challenges)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField128b, PackedBinaryField2x128b};
+	use binius_math::MLEDirectAdapter;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{make_portable_backend, ComputationBackendExt};
+
+	#[test]
+	fn test_records_calls_and_forwards_results() {
+		type P = PackedBinaryField2x128b;
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let backend = ProfilingBackend::new(make_portable_backend());
+
+		let query = repeat_with(|| <BinaryField128b as Field>::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let expansion = backend.tensor_product_full_query::<P>(&query).unwrap();
+		assert_eq!(
+			expansion,
+			make_portable_backend()
+				.tensor_product_full_query::<P>(&query)
+				.unwrap()
+		);
+
+		let multilinear = MultilinearExtension::new(
+			5,
+			repeat_with(|| P::random(&mut rng))
+				.take(16)
+				.collect::<Vec<_>>(),
+		)
+		.unwrap();
+		let multilinear = MLEDirectAdapter::from(multilinear);
+		let query = backend.multilinear_query::<P>(&query[..2]).unwrap();
+		backend
+			.evaluate_partial_high(&multilinear, MultilinearQueryRef::new(&query))
+			.unwrap();
+
+		let records = backend.records();
+		assert_eq!(
+			records
+				.iter()
+				.map(|record| (record.operation, record.n_vars))
+				.collect::<Vec<_>>(),
+			[
+				(BackendOperation::TensorProductFullQuery, 3),
+				(BackendOperation::TensorProductFullQuery, 2),
+				(BackendOperation::EvaluatePartialHigh, 5),
+			]
+		);
+
+		let summary = backend.summary();
+		assert_eq!(summary.stats.len(), 3);
+		assert_eq!(
+			summary.stats[&(BackendOperation::EvaluatePartialHigh, 5)].total_multilinears,
+			1
+		);
+		assert!(summary.to_string().contains("evaluate_partial_high"));
+
+		backend.reset();
+		assert!(backend.records().is_empty());
+	}
+}
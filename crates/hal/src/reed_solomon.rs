@@ -0,0 +1,37 @@
+// Copyright 2023-2025 Irreducible Inc.
+
+//! Reed–Solomon encoding with the additive NTT.
+
+use binius_field::{BinaryField, PackedField};
+use binius_maybe_rayon::prelude::*;
+use binius_ntt::{AdditiveNTT, Error};
+
+pub(crate) fn encode_batch<F, P, NTT>(
+	ntt: &NTT,
+	log_dim: usize,
+	log_inv_rate: usize,
+	log_batch_size: usize,
+	multithreaded: bool,
+	code: &mut [P],
+) -> Result<(), Error>
+where
+	F: BinaryField,
+	P: PackedField<Scalar = F>,
+	NTT: AdditiveNTT<F> + Sync,
+{
+	let msgs_len = ((1 << log_dim) / P::WIDTH) << log_batch_size;
+	for i in 1..(1 << log_inv_rate) {
+		code.copy_within(0..msgs_len, i * msgs_len);
+	}
+
+	if multithreaded {
+		(0..(1 << log_inv_rate))
+			.into_par_iter()
+			.zip(code.par_chunks_exact_mut(msgs_len))
+			.try_for_each(|(i, data)| ntt.forward_transform(data, i, log_batch_size, log_dim))
+	} else {
+		(0..(1 << log_inv_rate))
+			.zip(code.chunks_exact_mut(msgs_len))
+			.try_for_each(|(i, data)| ntt.forward_transform(data, i, log_batch_size, log_dim))
+	}
+}
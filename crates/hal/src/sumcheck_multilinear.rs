@@ -1,14 +1,20 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::PackedField;
-use binius_math::MultilinearPoly;
+use binius_math::{EvaluationOrder, MultilinearPoly};
+use binius_maybe_rayon::prelude::*;
+
+use crate::{ComputationBackend, CpuBackend};
 
 /// An individual multilinear polynomial in a multivariate composite.
+///
+/// The evaluations of a folded multilinear are stored in memory allocated by `Backend`.
 #[derive(Debug, Clone)]
-pub enum SumcheckMultilinear<P, M>
+pub enum SumcheckMultilinear<P, M, Backend = CpuBackend>
 where
 	P: PackedField,
 	M: MultilinearPoly<P>,
+	Backend: ComputationBackend,
 {
 	/// Small field multilinear - to be folded into large field at `switchover` round
 	Transparent {
@@ -16,5 +22,184 @@ where
 		switchover_round: usize,
 	},
 	/// Large field multilinear - halved in size each round
-	Folded { large_field_folded_evals: Vec<P> },
+	Folded {
+		large_field_folded_evals: Backend::Vec<P>,
+		/// The blocks of `large_field_folded_evals` that are known to be zero, carried over from
+		/// the sparsity of the transparent multilinear.
+		zero_subcubes: ZeroSubcubeMask,
+	},
+}
+
+/// Tracks which aligned blocks of a folded multilinear's hypercube evaluations are zero.
+///
+/// The mask is derived from [`MultilinearPoly::is_zero_on_subcube`] at the switchover round and
+/// folded along with the evaluations afterwards: a block of the folded evaluations is zero when
+/// both blocks it is interpolated from are. This lets the round evaluations skip the zero
+/// subcubes of sparse multilinears in every round, not only before switchover.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZeroSubcubeMask {
+	n_vars: usize,
+	// One flag per block of 2^block_vars() hypercube evaluations.
+	is_zero: Vec<bool>,
+}
+
+impl ZeroSubcubeMask {
+	/// Log2 of the number of hypercube evaluations tracked by a single mask entry.
+	pub const LOG_BLOCK_SIZE: usize = 6;
+
+	/// The mask of `multilinear` partially evaluated at a tensor query of `query_n_vars`
+	/// variables, at its sumcheck switchover round.
+	///
+	/// The query substitutes the low-indexed variables for [`EvaluationOrder::LowToHigh`] and the
+	/// high-indexed ones for [`EvaluationOrder::HighToLow`], as in
+	/// [`ComputationBackend::sumcheck_switchover`].
+	pub fn from_switchover<P: PackedField>(
+		evaluation_order: EvaluationOrder,
+		multilinear: &(impl MultilinearPoly<P> + Sync),
+		query_n_vars: usize,
+	) -> Self {
+		let n_vars = multilinear.n_vars() - query_n_vars;
+		let block_vars = Self::LOG_BLOCK_SIZE.min(n_vars);
+		let is_zero = (0..1 << (n_vars - block_vars))
+			.into_par_iter()
+			.map(|block_index| match evaluation_order {
+				// The block is a partial evaluation of a contiguous subcube of the original.
+				EvaluationOrder::LowToHigh => {
+					multilinear.is_zero_on_subcube(block_vars + query_n_vars, block_index)
+				}
+				// The block combines one subcube of the original per assignment of the
+				// substituted high variables.
+				EvaluationOrder::HighToLow => (0..1 << query_n_vars).all(|query_index| {
+					multilinear.is_zero_on_subcube(
+						block_vars,
+						block_index | query_index << (n_vars - block_vars),
+					)
+				}),
+			})
+			.collect();
+		Self { n_vars, is_zero }
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// Whether the evaluations on a subcube of the hypercube are known to be zero.
+	///
+	/// Subcubes smaller than a block are zero when their enclosing block is.
+	pub fn is_zero_on_subcube(&self, subcube_vars: usize, subcube_index: usize) -> bool {
+		let block_vars = self.block_vars();
+		if subcube_vars > self.n_vars {
+			return false;
+		}
+		if subcube_vars >= block_vars {
+			let shift = subcube_vars - block_vars;
+			self.is_zero[subcube_index << shift..(subcube_index + 1) << shift]
+				.iter()
+				.all(|&is_zero| is_zero)
+		} else {
+			self.is_zero[subcube_index >> (block_vars - subcube_vars)]
+		}
+	}
+
+	/// Updates the mask to that of the evaluations folded by one variable, as done by
+	/// [`ComputationBackend::sumcheck_fold_evals`].
+	pub fn fold(&mut self, evaluation_order: EvaluationOrder) {
+		assert!(self.n_vars > 0, "cannot fold a mask over zero variables");
+		if self.is_zero.len() > 1 {
+			self.is_zero = match evaluation_order {
+				EvaluationOrder::LowToHigh => self
+					.is_zero
+					.chunks_exact(2)
+					.map(|pair| pair[0] && pair[1])
+					.collect(),
+				EvaluationOrder::HighToLow => {
+					let (lo, hi) = self.is_zero.split_at(self.is_zero.len() / 2);
+					lo.iter().zip(hi).map(|(&lo, &hi)| lo && hi).collect()
+				}
+			};
+		}
+		self.n_vars -= 1;
+	}
+
+	fn block_vars(&self) -> usize {
+		Self::LOG_BLOCK_SIZE.min(self.n_vars)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::BinaryField128b;
+	use binius_math::{MultilinearQuery, SparseMultilinearExtension};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+
+	#[test]
+	fn test_folded_mask_matches_switchover_mask() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 12;
+		let entries = (0..8)
+			.map(|_| (rng.gen_range(0..1 << n_vars), BinaryField128b::new(rng.gen())))
+			.collect::<std::collections::BTreeMap<_, _>>();
+		let multilinear = SparseMultilinearExtension::new(n_vars, entries).unwrap();
+
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let mut mask = ZeroSubcubeMask::from_switchover::<BinaryField128b>(
+				evaluation_order,
+				&multilinear,
+				0,
+			);
+			assert!(mask.is_zero.iter().any(|&is_zero| is_zero));
+
+			for query_n_vars in 1..=n_vars {
+				mask.fold(evaluation_order);
+				let expected = ZeroSubcubeMask::from_switchover::<BinaryField128b>(
+					evaluation_order,
+					&multilinear,
+					query_n_vars,
+				);
+				assert_eq!(mask, expected);
+			}
+		}
+	}
+
+	#[test]
+	fn test_mask_zero_subcubes_are_zero() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let n_vars = 10;
+		let query_n_vars = 2;
+		let entries = (0..4)
+			.map(|_| (rng.gen_range(0..1 << n_vars), BinaryField128b::new(rng.gen())))
+			.collect::<std::collections::BTreeMap<_, _>>();
+		let multilinear = SparseMultilinearExtension::new(n_vars, entries).unwrap();
+		let query = MultilinearQuery::<BinaryField128b>::expand(
+			&(0..query_n_vars)
+				.map(|_| BinaryField128b::new(rng.gen()))
+				.collect::<Vec<_>>(),
+		);
+
+		for evaluation_order in [EvaluationOrder::LowToHigh, EvaluationOrder::HighToLow] {
+			let mask = ZeroSubcubeMask::from_switchover::<BinaryField128b>(
+				evaluation_order,
+				&multilinear,
+				query_n_vars,
+			);
+			let folded = match evaluation_order {
+				EvaluationOrder::LowToHigh => multilinear.evaluate_partial_low(query.to_ref()),
+				EvaluationOrder::HighToLow => multilinear.evaluate_partial_high(query.to_ref()),
+			}
+			.unwrap();
+
+			for subcube_vars in 0..=mask.n_vars() {
+				for subcube_index in 0..1 << (mask.n_vars() - subcube_vars) {
+					if mask.is_zero_on_subcube(subcube_vars, subcube_index) {
+						let evals = &folded.evals()
+							[subcube_index << subcube_vars..(subcube_index + 1) << subcube_vars];
+						assert!(evals.iter().all(|&eval| eval == BinaryField128b::default()));
+					}
+				}
+			}
+		}
+	}
 }
@@ -17,7 +17,7 @@ use bytemuck::zeroed_vec;
 use itertools::{izip, Either, Itertools};
 use stackalloc::stackalloc_with_iter;
 
-use crate::{Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
+use crate::{ComputationBackend, Error, RoundEvals, SumcheckEvaluator, SumcheckMultilinear};
 
 trait SumcheckMultilinearAccess<P: PackedField> {
 	/// The size of `Vec<P>` scratchspace used by [`subcube_evaluations`], if any.
@@ -56,9 +56,9 @@ trait SumcheckMultilinearAccess<P: PackedField> {
 	/// * `evals_0`       - `subcube_vars`-variate hypercube with current variables substituted for 0
 	/// * `evals_1`       - `subcube_vars`-variate hypercube with current variables substituted for 1
 	#[allow(clippy::too_many_arguments)]
-	fn subcube_evaluations<M: MultilinearPoly<P>>(
+	fn subcube_evaluations<M: MultilinearPoly<P>, Backend: ComputationBackend>(
 		&self,
-		multilinear: &SumcheckMultilinear<P, M>,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
 		subcube_vars: usize,
 		subcube_index: usize,
 		index_vars: usize,
@@ -66,17 +66,29 @@ trait SumcheckMultilinearAccess<P: PackedField> {
 		evals_0: &mut [P],
 		evals_1: &mut [P],
 	) -> Result<(), Error>;
+
+	/// Returns whether both `evals_0` and `evals_1` written by [`subcube_evaluations`] are known to
+	/// be all zero, without computing them.
+	///
+	/// Returning `false` is always correct; this is used to skip subcubes of sparse multilinears.
+	fn subcube_is_zero<M: MultilinearPoly<P>, Backend: ComputationBackend>(
+		&self,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		index_vars: usize,
+	) -> bool;
 }
 
 /// Calculate the accumulated evaluations for an arbitrary sumcheck round.
 ///
 /// See [`calculate_first_round_evals`] for an optimized version of this method
 /// that works over small fields in the first round.
-pub(crate) fn calculate_round_evals<FDomain, F, P, M, Evaluator, Composition>(
+pub(crate) fn calculate_round_evals<FDomain, F, P, M, Backend, Evaluator, Composition>(
 	evaluation_order: EvaluationOrder,
 	n_vars: usize,
 	tensor_query: Option<MultilinearQueryRef<P>>,
-	multilinears: &[SumcheckMultilinear<P, M>],
+	multilinears: &[SumcheckMultilinear<P, M, Backend>],
 	evaluators: &[Evaluator],
 	finite_evaluation_points: &[FDomain],
 ) -> Result<Vec<RoundEvals<F>>, Error>
@@ -85,6 +97,7 @@ where
 	F: Field,
 	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
 	M: MultilinearPoly<P> + Sync,
+	Backend: ComputationBackend,
 	Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 	Composition: CompositionPoly<P>,
 {
@@ -109,10 +122,10 @@ where
 	}
 }
 
-fn calculate_round_evals_with_access<FDomain, F, P, M, Evaluator, Access, Composition>(
+fn calculate_round_evals_with_access<FDomain, F, P, M, Backend, Evaluator, Access, Composition>(
 	n_vars: usize,
 	access: &Access,
-	multilinears: &[SumcheckMultilinear<P, M>],
+	multilinears: &[SumcheckMultilinear<P, M, Backend>],
 	evaluators: &[Evaluator],
 	nontrivial_evaluation_points: &[FDomain],
 ) -> Result<Vec<RoundEvals<F>>, Error>
@@ -121,6 +134,7 @@ where
 	F: Field,
 	P: PackedField<Scalar = F> + PackedExtension<FDomain>,
 	M: MultilinearPoly<P> + Sync,
+	Backend: ComputationBackend,
 	Evaluator: SumcheckEvaluator<P, Composition> + Sync,
 	Access: SumcheckMultilinearAccess<P> + Sync,
 	Composition: CompositionPoly<P>,
@@ -148,6 +162,16 @@ where
 		bail!(Error::IncorrectNontrivialEvalPointsLength);
 	}
 
+	// A subcube where every multilinear is zero contributes nothing to the round evaluations if
+	// every composition vanishes at zero, because so does the leading term used at the infinity
+	// point. This lets sparse multilinears skip most of the hypercube.
+	let skip_zero_subcubes = evaluators.iter().all(|evaluator| {
+		let composition = evaluator.composition();
+		composition
+			.evaluate(&vec![P::zero(); composition.n_vars()])
+			.is_ok_and(|eval| eval == P::zero())
+	});
+
 	let index_vars = n_vars - 1 - subcube_vars;
 	let packed_accumulators = (0..1 << index_vars)
 		.into_par_iter()
@@ -160,6 +184,13 @@ where
 					round_evals,
 				} = &mut par_fold_states;
 
+				if skip_zero_subcubes
+					&& multilinears.iter().all(|multilinear| {
+						access.subcube_is_zero(multilinear, subcube_vars, subcube_index, index_vars)
+					}) {
+					return Ok(par_fold_states);
+				}
+
 				for (multilinear, evals) in izip!(multilinears, multilinear_evals.iter_mut()) {
 					access.subcube_evaluations(
 						multilinear,
@@ -353,9 +384,9 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for LowToHighAccess<'_, P> {
 		Some(1 << (subcube_vars + 1).saturating_sub(P::LOG_WIDTH))
 	}
 
-	fn subcube_evaluations<M: MultilinearPoly<P>>(
+	fn subcube_evaluations<M: MultilinearPoly<P>, Backend: ComputationBackend>(
 		&self,
-		multilinear: &SumcheckMultilinear<P, M>,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
 		subcube_vars: usize,
 		subcube_index: usize,
 		_index_vars: usize,
@@ -390,9 +421,10 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for LowToHighAccess<'_, P> {
 
 			SumcheckMultilinear::Folded {
 				large_field_folded_evals,
+				..
 			} => {
 				let multilinear =
-					MultilinearExtension::from_values_generic(large_field_folded_evals.as_slice())?;
+					MultilinearExtension::from_values_generic(&**large_field_folded_evals)?;
 
 				MLEDirectAdapter::from(multilinear).subcube_evals(
 					subcube_vars + 1,
@@ -427,6 +459,24 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for LowToHighAccess<'_, P> {
 
 		Ok(())
 	}
+
+	fn subcube_is_zero<M: MultilinearPoly<P>, Backend: ComputationBackend>(
+		&self,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		_index_vars: usize,
+	) -> bool {
+		match multilinear {
+			// The evaluations at 0 & 1 are interleaved in a subcube of the partially evaluated
+			// multilinear, which is derived from a contiguous subcube of the original.
+			SumcheckMultilinear::Transparent { multilinear, .. } => multilinear
+				.is_zero_on_subcube(subcube_vars + 1 + self.tensor_query.n_vars(), subcube_index),
+			SumcheckMultilinear::Folded { zero_subcubes, .. } => {
+				zero_subcubes.is_zero_on_subcube(subcube_vars + 1, subcube_index)
+			}
+		}
+	}
 }
 
 #[derive(Debug)]
@@ -439,9 +489,9 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for HighToLowAccess<'_, P> {
 		None
 	}
 
-	fn subcube_evaluations<M: MultilinearPoly<P>>(
+	fn subcube_evaluations<M: MultilinearPoly<P>, Backend: ComputationBackend>(
 		&self,
-		multilinear: &SumcheckMultilinear<P, M>,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
 		subcube_vars: usize,
 		subcube_index: usize,
 		index_vars: usize,
@@ -483,9 +533,10 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for HighToLowAccess<'_, P> {
 
 			SumcheckMultilinear::Folded {
 				large_field_folded_evals,
+				..
 			} => {
 				let multilinear =
-					MultilinearExtension::from_values_generic(large_field_folded_evals.as_slice())?;
+					MultilinearExtension::from_values_generic(&**large_field_folded_evals)?;
 				let adapter = MLEDirectAdapter::from(multilinear);
 				adapter.subcube_evals(subcube_vars, subcube_index, 0, evals_0)?;
 				adapter.subcube_evals(subcube_vars, subcube_index | 1 << index_vars, 0, evals_1)?;
@@ -494,4 +545,34 @@ impl<P: PackedField> SumcheckMultilinearAccess<P> for HighToLowAccess<'_, P> {
 
 		Ok(())
 	}
+
+	fn subcube_is_zero<M: MultilinearPoly<P>, Backend: ComputationBackend>(
+		&self,
+		multilinear: &SumcheckMultilinear<P, M, Backend>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		index_vars: usize,
+	) -> bool {
+		let subcube_indices = [subcube_index, subcube_index | 1 << index_vars];
+		let multilinear = match multilinear {
+			SumcheckMultilinear::Transparent { multilinear, .. } => multilinear,
+			SumcheckMultilinear::Folded { zero_subcubes, .. } => {
+				return subcube_indices.into_iter().all(|subcube_index| {
+					zero_subcubes.is_zero_on_subcube(subcube_vars, subcube_index)
+				});
+			}
+		};
+
+		// The subcubes of the partially evaluated multilinear combine one subcube of the
+		// original for every assignment of the high variables substituted by the tensor query.
+		let query_n_vars = self.tensor_query.n_vars();
+		subcube_indices.into_iter().all(|subcube_index| {
+			(0..1 << query_n_vars).all(|query_index| {
+				multilinear.is_zero_on_subcube(
+					subcube_vars,
+					subcube_index | query_index << (index_vars + 1),
+				)
+			})
+		})
+	}
 }
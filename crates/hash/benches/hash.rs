@@ -7,7 +7,8 @@ use binius_field::{
 };
 use binius_hash::{
 	FixedLenHasherDigest, Groestl256, GroestlDigest, GroestlDigestCompression, HashDigest,
-	HasherDigest, PseudoCompressionFunction, Vision32b, VisionHasher,
+	HasherDigest, PseudoCompressionFunction, Vision32Compression, Vision32b, VisionHasher,
+	VisionHasherDigest,
 };
 use criterion::{criterion_group, criterion_main, Criterion, Throughput};
 use groestl_crypto::{Digest, Groestl256 as GenericGroestl256};
@@ -111,11 +112,44 @@ fn bench_vision32(c: &mut Criterion) {
 	group.finish()
 }
 
+fn bench_vision32_digest(c: &mut Criterion) {
+	let mut group = c.benchmark_group("vision");
+
+	let mut rng = thread_rng();
+
+	const N: usize = 1 << 16;
+	let mut data = vec![0u8; N];
+	rng.fill_bytes(&mut data);
+
+	group.throughput(Throughput::Bytes(N as u64));
+	group.bench_function("VisionHasherDigest", |bench| {
+		bench.iter(|| VisionHasherDigest::digest(&data))
+	});
+
+	const N_DIGESTS: usize = 1 << 10;
+	let digests = (0..N_DIGESTS)
+		.map(|i| VisionHasherDigest::digest(i.to_le_bytes()))
+		.collect::<Vec<_>>();
+
+	group.throughput(Throughput::Bytes((N_DIGESTS * 32) as u64));
+	group.bench_function("Vision32Compression", |bench| {
+		bench.iter(|| {
+			digests
+				.chunks_exact(2)
+				.map(|pair| Vision32Compression.compress([pair[0], pair[1]]))
+				.collect::<Vec<_>>()
+		})
+	});
+
+	group.finish()
+}
+
 criterion_group!(
 	hash,
 	bench_groestl_compression,
 	bench_groestl,
 	bench_groestl_rustcrypto,
-	bench_vision32
+	bench_vision32,
+	bench_vision32_digest
 );
 criterion_main!(hash);
@@ -15,3 +15,16 @@ cfg_if! {
 		pub use portable::Groestl256Core;
 	}
 }
+
+use binius_field::PackedAESBinaryField64x8b;
+
+use crate::permutation::{CryptographicPermutation, Permutation};
+
+/// The Grøstl-256 P permutation over the 64-byte state.
+impl Permutation<PackedAESBinaryField64x8b> for Groestl256Core {
+	fn permute_mut(&self, input: &mut PackedAESBinaryField64x8b) {
+		*input = self.permutation_p(*input);
+	}
+}
+
+impl CryptographicPermutation<PackedAESBinaryField64x8b> for Groestl256Core {}
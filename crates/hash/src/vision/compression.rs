@@ -0,0 +1,57 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{AESTowerField32b, BinaryField32b, PackedAESBinaryField8x32b, PackedField};
+use digest::Output;
+
+use super::{digest::VisionHasherDigest, permutation::PERMUTATION};
+use crate::{permutation::Permutation, PseudoCompressionFunction};
+
+/// One-way compression function that compresses two 32-byte Vision-32b digests into one.
+///
+/// The two digests are read as 16 little-endian `BinaryField32b` elements and fill the rate of a
+/// fresh sponge state, with the capacity set to zero. The output is the first 8 elements of the
+/// state after a single Vision-32b permutation, truncating the rest.
+#[derive(Debug, Default, Clone)]
+pub struct Vision32Compression;
+
+impl PseudoCompressionFunction<Output<VisionHasherDigest>, 2> for Vision32Compression {
+	fn compress(&self, input: [Output<VisionHasherDigest>; 2]) -> Output<VisionHasherDigest> {
+		let mut state = [PackedAESBinaryField8x32b::zero(); 3];
+		for (packed, digest) in state.iter_mut().zip(&input) {
+			*packed = PackedAESBinaryField8x32b::from_fn(|i| {
+				let bytes = digest[4 * i..4 * (i + 1)]
+					.try_into()
+					.expect("slice has 4 bytes");
+				AESTowerField32b::from(BinaryField32b::new(u32::from_le_bytes(bytes)))
+			});
+		}
+
+		PERMUTATION.permute_mut(&mut state);
+
+		let mut out = Output::<VisionHasherDigest>::default();
+		for (dst, val) in out.chunks_exact_mut(4).zip(state[0].iter()) {
+			dst.copy_from_slice(&BinaryField32b::from(val).val().to_le_bytes());
+		}
+		out
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use digest::Digest;
+
+	use super::*;
+
+	#[test]
+	fn test_compression_is_not_symmetric() {
+		let a = VisionHasherDigest::digest(b"left");
+		let b = VisionHasherDigest::digest(b"right");
+
+		let compression = Vision32Compression;
+		let ab = compression.compress([a, b]);
+		assert_eq!(ab, compression.compress([a, b]));
+		assert_ne!(ab, compression.compress([b, a]));
+		assert_ne!(ab, a);
+		assert_ne!(ab, b);
+	}
+}
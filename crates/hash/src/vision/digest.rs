@@ -12,6 +12,11 @@ use binius_field::{
 	PackedBinaryField8x32b, PackedExtension, PackedExtensionIndexable, PackedField,
 	PackedFieldIndexable,
 };
+use digest::{
+	consts::{U32, U64},
+	core_api::BlockSizeUser,
+	FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update,
+};
 use lazy_static::lazy_static;
 
 use super::permutation::PERMUTATION;
@@ -162,6 +167,104 @@ where
 	}
 }
 
+/// The Vision-32b sponge over byte strings, exposed through the RustCrypto [`digest`] traits.
+///
+/// Unlike [`VisionHasher`], which requires the message length up front, this hasher accepts
+/// byte strings of arbitrary length and pads them with the `10*1` padding rule. Every 4 bytes of
+/// input are read as a little-endian `BinaryField32b` element, and the rate of the sponge is 16
+/// such elements. The 32-byte digest is the little-endian encoding of the first 8 elements of the
+/// state after the last permutation.
+///
+/// This makes Vision-32b usable wherever a [`digest::Digest`] is expected, such as for hashing
+/// Merkle tree leaves and in byte-oriented Fiat–Shamir challengers.
+#[derive(Clone)]
+pub struct VisionHasherDigest {
+	// The hashed state
+	state: [PackedAESBinaryField8x32b; 3],
+	// Bytes of the current block that are not absorbed yet
+	buffer: [u8; RATE_AS_U8],
+	// Number of bytes in `buffer`. Invariant: always strictly less than `RATE_AS_U8`.
+	filled_bytes: usize,
+}
+
+impl Default for VisionHasherDigest {
+	fn default() -> Self {
+		Self {
+			state: [PackedAESBinaryField8x32b::zero(); 3],
+			buffer: [0; RATE_AS_U8],
+			filled_bytes: 0,
+		}
+	}
+}
+
+impl VisionHasherDigest {
+	fn permute_block(state: &mut [PackedAESBinaryField8x32b; 3], block: &[u8; RATE_AS_U8]) {
+		let rate = PackedAESBinaryField8x32b::unpack_scalars_mut(&mut state[..2]);
+		for (dst, src) in rate.iter_mut().zip(block.chunks_exact(4)) {
+			let val = u32::from_le_bytes(src.try_into().expect("chunk has 4 bytes"));
+			*dst = AESTowerField32b::from(BinaryField32b::new(val));
+		}
+		PERMUTATION.permute_mut(state);
+	}
+
+	fn finalize_into_state(&mut self, out: &mut Output<Self>) {
+		self.buffer[self.filled_bytes..].fill(0);
+		self.buffer[self.filled_bytes] |= PADDING_START;
+		self.buffer[RATE_AS_U8 - 1] |= PADDING_END;
+		Self::permute_block(&mut self.state, &self.buffer);
+
+		for (dst, val) in out.chunks_exact_mut(4).zip(self.state[0].iter()) {
+			dst.copy_from_slice(&BinaryField32b::from(val).val().to_le_bytes());
+		}
+	}
+}
+
+impl HashMarker for VisionHasherDigest {}
+
+impl OutputSizeUser for VisionHasherDigest {
+	type OutputSize = U32;
+}
+
+impl BlockSizeUser for VisionHasherDigest {
+	type BlockSize = U64;
+}
+
+impl Update for VisionHasherDigest {
+	fn update(&mut self, mut data: &[u8]) {
+		while !data.is_empty() {
+			let to_copy = (RATE_AS_U8 - self.filled_bytes).min(data.len());
+			let (head, tail) = data.split_at(to_copy);
+			self.buffer[self.filled_bytes..self.filled_bytes + to_copy].copy_from_slice(head);
+			self.filled_bytes += to_copy;
+			data = tail;
+
+			if self.filled_bytes == RATE_AS_U8 {
+				Self::permute_block(&mut self.state, &self.buffer);
+				self.filled_bytes = 0;
+			}
+		}
+	}
+}
+
+impl Reset for VisionHasherDigest {
+	fn reset(&mut self) {
+		*self = Self::default();
+	}
+}
+
+impl FixedOutput for VisionHasherDigest {
+	fn finalize_into(mut self, out: &mut Output<Self>) {
+		self.finalize_into_state(out);
+	}
+}
+
+impl FixedOutputReset for VisionHasherDigest {
+	fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+		self.finalize_into_state(out);
+		Reset::reset(self);
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use std::array;
@@ -171,8 +274,9 @@ mod tests {
 		make_binary_to_aes_packed_transformer, BinaryField64b, PackedAESBinaryField4x64b,
 		PackedBinaryField4x64b, PackedBinaryField8x32b,
 	};
+	use digest::Digest;
 	use hex_literal::hex;
-	use rand::thread_rng;
+	use rand::{thread_rng, RngCore};
 
 	use super::*;
 	use crate::{FixedLenHasherDigest, HashDigest};
@@ -340,4 +444,45 @@ mod tests {
 
 		assert_eq!(digest_as_bin, aes_transformer_2.transform(&digest_as_aes));
 	}
+
+	#[test]
+	fn test_digest_incremental_updates() {
+		let mut rng = thread_rng();
+		let mut data = [0u8; 300];
+		rng.fill_bytes(&mut data);
+
+		let expected = VisionHasherDigest::digest(data);
+
+		let mut hasher = VisionHasherDigest::new();
+		Digest::update(&mut hasher, &data[..3]);
+		Digest::update(&mut hasher, &data[3..64]);
+		Digest::update(&mut hasher, &data[64..200]);
+		Digest::update(&mut hasher, &data[200..]);
+		assert_eq!(hasher.finalize(), expected);
+	}
+
+	#[test]
+	fn test_digest_padding_distinguishes_lengths() {
+		// Messages that only differ by trailing zeros, including across the block boundary, must
+		// hash to different digests.
+		let digests = [0, 1, 63, 64, 65, 128]
+			.map(|len| VisionHasherDigest::digest(vec![0u8; len]))
+			.to_vec();
+		for (i, digest_i) in digests.iter().enumerate() {
+			for digest_j in &digests[i + 1..] {
+				assert_ne!(digest_i, digest_j);
+			}
+		}
+	}
+
+	#[test]
+	fn test_digest_finalize_reset() {
+		let mut hasher = VisionHasherDigest::new();
+		Digest::update(&mut hasher, b"first message");
+		let first = hasher.finalize_reset();
+		assert_eq!(first, VisionHasherDigest::digest(b"first message"));
+
+		Digest::update(&mut hasher, b"second message");
+		assert_eq!(hasher.finalize_reset(), VisionHasherDigest::digest(b"second message"));
+	}
 }
@@ -1,9 +1,11 @@
 // Copyright 2024-2025 Irreducible Inc.
 
+pub mod compression;
 pub mod constants;
 pub mod digest;
 pub mod permutation;
 
+pub use compression::*;
 pub use constants::*;
 pub use digest::*;
 pub use permutation::{Vision32MDSTransform, Vision32bPermutation, INV_PACKED_TRANS_AES};
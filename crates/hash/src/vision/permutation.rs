@@ -20,7 +20,7 @@ use super::constants::{
 	AFFINE_FWD_AES, AFFINE_FWD_CONST_AES, AFFINE_INV_AES, AFFINE_INV_CONST_AES, NUM_ROUNDS,
 	ROUND_KEYS,
 };
-use crate::permutation::Permutation;
+use crate::permutation::{CryptographicPermutation, Permutation};
 
 type PackedTransformationType8x32bAES = <PackedAESBinaryField8x32b as PackedTransformationFactory<
 	PackedAESBinaryField8x32b,
@@ -111,6 +111,8 @@ impl Permutation<[PackedAESBinaryField8x32b; 3]> for Vision32bPermutation {
 	}
 }
 
+impl CryptographicPermutation<[PackedAESBinaryField8x32b; 3]> for Vision32bPermutation {}
+
 impl Permutation<[ByteSlicedAES32x32b; 24]> for Vision32bPermutation {
 	fn permute_mut(&self, input: &mut [ByteSlicedAES32x32b; 24]) {
 		add_packed_768(input, &ROUND_KEYS_PACKED_AES_BYTE_SLICED[0]);
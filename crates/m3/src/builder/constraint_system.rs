@@ -9,6 +9,7 @@ use binius_core::{
 		Constraint, ConstraintPredicate, ConstraintSet, MultilinearOracleSet, OracleId,
 		ProjectionVariant,
 	},
+	polynomial::CircuitEvaluator,
 	transparent::step_down::StepDown,
 };
 use binius_field::{underlier::UnderlierType, TowerField};
@@ -243,6 +244,7 @@ impl<F: TowerField> ConstraintSystem<F> {
 							name: zero_constraint.name.clone(),
 							composition: zero_constraint.expr.clone(),
 							predicate: ConstraintPredicate::Zero,
+							evaluator: CircuitEvaluator::default(),
 						})
 						.collect::<Vec<_>>();
 
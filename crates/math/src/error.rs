@@ -49,6 +49,8 @@ pub enum Error {
 	IncorrectNumberOfVariables { expected: usize, actual: usize },
 	#[error("indexed point on hypercube is out of range: index={index}")]
 	HypercubeIndexOutOfRange { index: usize },
+	#[error("sparse multilinear has more than one evaluation at hypercube index {index}")]
+	DuplicateSparseIndex { index: usize },
 	#[error("the output polynomial must have size {expected}")]
 	IncorrectOutputPolynomialSize { expected: usize },
 	#[error("the total number of coefficients, {total_length}, in the piecewise multilinear is too large: {total_length} > 2^{total_n_vars}")]
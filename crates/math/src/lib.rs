@@ -4,7 +4,7 @@
 //!
 //! This crate provides a variety of mathematical primitives used in Binius, including:
 //!
-//! * Multilinear polynomials
+//! * Multilinear polynomials, in dense and sparse representations
 //! * Univariate polynomials
 //! * Matrix operations
 //! * Arithmetic expressions and evaluators
@@ -26,6 +26,7 @@ mod multilinear_extension;
 mod multilinear_query;
 mod packing_deref;
 mod piecewise_multilinear;
+mod sparse_multilinear;
 mod tensor_prod_eq_ind;
 mod univariate;
 
@@ -42,5 +43,6 @@ pub use multilinear_extension::*;
 pub use multilinear_query::*;
 pub use packing_deref::*;
 pub use piecewise_multilinear::*;
+pub use sparse_multilinear::*;
 pub use tensor_prod_eq_ind::*;
 pub use univariate::*;
@@ -101,6 +101,17 @@ pub trait MultilinearPoly<P: PackedField>: Debug {
 		evals: &mut [P],
 	) -> Result<(), Error>;
 
+	/// Returns whether the evaluations over a subcube of the boolean hypercube are known to all be
+	/// zero.
+	///
+	/// The subcube is the same as the one written by [`Self::subcube_evals`]. Implementations
+	/// backed by sparse data return `true` when they can cheaply tell that the subcube holds no
+	/// non-zero evaluations, which lets callers skip work on it. Returning `false` is always
+	/// correct, and is the default.
+	fn is_zero_on_subcube(&self, _subcube_vars: usize, _subcube_index: usize) -> bool {
+		false
+	}
+
 	/// Returns the hypercube evaluations, embedded into packed extension field elements, if the
 	/// data is already available.
 	///
@@ -211,6 +222,10 @@ where
 		)
 	}
 
+	fn is_zero_on_subcube(&self, subcube_vars: usize, subcube_index: usize) -> bool {
+		either::for_both!(self, inner => inner.is_zero_on_subcube(subcube_vars, subcube_index))
+	}
+
 	fn packed_evals(&self) -> Option<&[P]> {
 		either::for_both!(self, inner => inner.packed_evals())
 	}
@@ -0,0 +1,561 @@
+// Copyright 2025 Irreducible Inc.
+
+use std::{fmt::Debug, ops::Range, sync::Arc};
+
+use binius_field::{
+	packed::{get_packed_slice, set_packed_slice},
+	ExtensionField, Field, PackedField,
+};
+use binius_utils::bail;
+use bytemuck::zeroed_vec;
+
+use crate::{
+	mle_adapters::validate_subcube_partial_evals_params, Error, MultilinearExtension,
+	MultilinearPoly, MultilinearQueryRef,
+};
+
+/// A multilinear polynomial represented by its non-zero evaluations over the boolean hypercube.
+///
+/// Columns such as selectors and flags are often zero almost everywhere on the hypercube. This
+/// representation stores only the non-zero evaluations, sorted by hypercube index, so that
+/// evaluations, partial evaluations and subcube queries take time proportional to the number of
+/// non-zero evaluations they touch rather than to the size of the hypercube.
+///
+/// The evaluations are elements of `F`, and the polynomial implements [`MultilinearPoly`] over
+/// any packed extension field of `F`, like [`MLEEmbeddingAdapter`](crate::MLEEmbeddingAdapter)
+/// does for dense multilinears.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseMultilinearExtension<F: Field> {
+	n_vars: usize,
+	// Hypercube indices of the non-zero evaluations, in ascending order
+	indices: Vec<usize>,
+	// The non-zero evaluations, in the same order as `indices`
+	values: Vec<F>,
+}
+
+impl<F: Field> SparseMultilinearExtension<F> {
+	/// Constructs the multilinear from `(index, value)` pairs of hypercube evaluations.
+	///
+	/// Entries may be given in any order and entries with zero values are dropped. All vertices of
+	/// the hypercube that are not listed evaluate to zero.
+	pub fn new(
+		n_vars: usize,
+		entries: impl IntoIterator<Item = (usize, F)>,
+	) -> Result<Self, Error> {
+		let mut entries = entries
+			.into_iter()
+			.filter(|&(_, value)| value != F::ZERO)
+			.collect::<Vec<_>>();
+		entries.sort_unstable_by_key(|&(index, _)| index);
+
+		if let Some(&(index, _)) = entries.last() {
+			if index >= 1 << n_vars {
+				bail!(Error::HypercubeIndexOutOfRange { index });
+			}
+		}
+		if let Some(pair) = entries.windows(2).find(|pair| pair[0].0 == pair[1].0) {
+			bail!(Error::DuplicateSparseIndex { index: pair[0].0 });
+		}
+
+		let (indices, values) = entries.into_iter().unzip();
+		Ok(Self {
+			n_vars,
+			indices,
+			values,
+		})
+	}
+
+	/// The multilinear that evaluates to zero everywhere on the hypercube.
+	pub const fn zeros(n_vars: usize) -> Self {
+		Self {
+			n_vars,
+			indices: Vec::new(),
+			values: Vec::new(),
+		}
+	}
+
+	/// Constructs the sparse representation of a dense multilinear.
+	pub fn from_dense<P, Data>(multilinear: &MultilinearExtension<P, Data>) -> Self
+	where
+		P: PackedField<Scalar = F>,
+		Data: std::ops::Deref<Target = [P]>,
+	{
+		let (indices, values) = PackedField::iter_slice(multilinear.evals())
+			.take(multilinear.size())
+			.enumerate()
+			.filter(|&(_, value)| value != F::ZERO)
+			.unzip();
+		Self {
+			n_vars: multilinear.n_vars(),
+			indices,
+			values,
+		}
+	}
+
+	/// Materializes the evaluations over the whole hypercube.
+	pub fn to_dense<P: PackedField<Scalar = F>>(&self) -> Result<MultilinearExtension<P>, Error> {
+		let mut evals = zeroed_vec(1 << self.n_vars.saturating_sub(P::LOG_WIDTH));
+		for (index, value) in self.iter() {
+			set_packed_slice(&mut evals, index, value);
+		}
+		MultilinearExtension::new(self.n_vars, evals)
+	}
+
+	pub const fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	/// The number of non-zero hypercube evaluations.
+	pub fn n_nonzero(&self) -> usize {
+		self.indices.len()
+	}
+
+	/// Iterates over the `(index, value)` pairs of the non-zero hypercube evaluations, in
+	/// ascending order by index.
+	pub fn iter(&self) -> impl Iterator<Item = (usize, F)> + '_ {
+		self.indices
+			.iter()
+			.copied()
+			.zip(self.values.iter().copied())
+	}
+
+	/// The `(index, value)` pairs of the non-zero evaluations with indices in
+	/// `start..start + len`.
+	fn entries_in(&self, start: usize, len: usize) -> impl Iterator<Item = (usize, F)> + '_ {
+		let range = self.positions_in(start..start + len);
+		self.indices[range.clone()]
+			.iter()
+			.copied()
+			.zip(self.values[range].iter().copied())
+	}
+
+	fn positions_in(&self, range: Range<usize>) -> Range<usize> {
+		let begin = self.indices.partition_point(|&index| index < range.start);
+		let end = begin + self.indices[begin..].partition_point(|&index| index < range.end);
+		begin..end
+	}
+
+	pub fn upcast_arc_dyn<'a, P>(self) -> Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+		F: 'a,
+	{
+		Arc::new(self)
+	}
+}
+
+impl<F, P> MultilinearPoly<P> for SparseMultilinearExtension<F>
+where
+	F: Field,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn log_extension_degree(&self) -> usize {
+		<P::Scalar as ExtensionField<F>>::LOG_DEGREE
+	}
+
+	fn evaluate_on_hypercube(&self, index: usize) -> Result<P::Scalar, Error> {
+		if index >= 1 << self.n_vars {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		Ok(self
+			.indices
+			.binary_search(&index)
+			.map_or(P::Scalar::ZERO, |position| self.values[position].into()))
+	}
+
+	fn evaluate_on_hypercube_and_scale(
+		&self,
+		index: usize,
+		scalar: P::Scalar,
+	) -> Result<P::Scalar, Error> {
+		if index >= 1 << self.n_vars {
+			bail!(Error::HypercubeIndexOutOfRange { index });
+		}
+		Ok(self
+			.indices
+			.binary_search(&index)
+			.map_or(P::Scalar::ZERO, |position| scalar * self.values[position]))
+	}
+
+	fn evaluate(&self, query: MultilinearQueryRef<P>) -> Result<P::Scalar, Error> {
+		if query.n_vars() != self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars
+			});
+		}
+
+		Ok(self
+			.iter()
+			.map(|(index, value)| get_packed_slice(query.expansion(), index) * value)
+			.sum())
+	}
+
+	fn evaluate_partial_low(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let query_n_vars = query.n_vars();
+		if query_n_vars > self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars
+			});
+		}
+
+		let new_n_vars = self.n_vars - query_n_vars;
+		let query_mask = (1 << query_n_vars) - 1;
+		let mut result = zeroed_vec(1 << new_n_vars.saturating_sub(P::LOG_WIDTH));
+		for (index, value) in self.iter() {
+			let query_factor = get_packed_slice(query.expansion(), index & query_mask);
+			add_to_packed_slice(&mut result, index >> query_n_vars, query_factor * value);
+		}
+
+		MultilinearExtension::new(new_n_vars, result)
+	}
+
+	fn evaluate_partial_high(
+		&self,
+		query: MultilinearQueryRef<P>,
+	) -> Result<MultilinearExtension<P>, Error> {
+		let query_n_vars = query.n_vars();
+		if query_n_vars > self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars
+			});
+		}
+
+		let new_n_vars = self.n_vars - query_n_vars;
+		let result_mask = (1 << new_n_vars) - 1;
+		let mut result = zeroed_vec(1 << new_n_vars.saturating_sub(P::LOG_WIDTH));
+		for (index, value) in self.iter() {
+			let query_factor = get_packed_slice(query.expansion(), index >> new_n_vars);
+			add_to_packed_slice(&mut result, index & result_mask, query_factor * value);
+		}
+
+		MultilinearExtension::new(new_n_vars, result)
+	}
+
+	fn subcube_partial_low_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_low_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_low_evals,
+		)?;
+
+		let query_n_vars = query.n_vars();
+		let query_mask = (1 << query_n_vars) - 1;
+		let subcube_start = subcube_index << (query_n_vars + subcube_vars);
+
+		partial_low_evals.fill(P::zero());
+		for (index, value) in self.entries_in(subcube_start, 1 << (query_n_vars + subcube_vars)) {
+			let offset = index - subcube_start;
+			let query_factor = get_packed_slice(query.expansion(), offset & query_mask);
+			add_to_packed_slice(partial_low_evals, offset >> query_n_vars, query_factor * value);
+		}
+
+		Ok(())
+	}
+
+	fn subcube_partial_high_evals(
+		&self,
+		query: MultilinearQueryRef<P>,
+		subcube_vars: usize,
+		subcube_index: usize,
+		partial_high_evals: &mut [P],
+	) -> Result<(), Error> {
+		validate_subcube_partial_evals_params(
+			self.n_vars,
+			query,
+			subcube_vars,
+			subcube_index,
+			partial_high_evals,
+		)?;
+
+		let query_n_vars = query.n_vars();
+
+		partial_high_evals.fill(P::zero());
+		for query_index in 0..1 << query_n_vars {
+			let query_factor = get_packed_slice(query.expansion(), query_index);
+			let subcube_start =
+				subcube_index << subcube_vars | query_index << (self.n_vars - query_n_vars);
+			for (index, value) in self.entries_in(subcube_start, 1 << subcube_vars) {
+				add_to_packed_slice(
+					partial_high_evals,
+					index - subcube_start,
+					query_factor * value,
+				);
+			}
+		}
+
+		Ok(())
+	}
+
+	fn subcube_evals(
+		&self,
+		subcube_vars: usize,
+		subcube_index: usize,
+		log_embedding_degree: usize,
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let log_extension_degree = <P::Scalar as ExtensionField<F>>::LOG_DEGREE;
+
+		if subcube_vars > self.n_vars {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_vars".to_string(),
+				range: 0..self.n_vars + 1,
+			});
+		}
+
+		if log_embedding_degree > log_extension_degree {
+			bail!(Error::LogEmbeddingDegreeTooLarge {
+				log_embedding_degree
+			});
+		}
+
+		let correct_len = 1 << subcube_vars.saturating_sub(log_embedding_degree + P::LOG_WIDTH);
+		if evals.len() != correct_len {
+			bail!(Error::ArgumentRangeError {
+				arg: "evals.len()".to_string(),
+				range: correct_len..correct_len + 1,
+			});
+		}
+
+		let max_index = 1 << (self.n_vars - subcube_vars);
+		if subcube_index >= max_index {
+			bail!(Error::ArgumentRangeError {
+				arg: "subcube_index".to_string(),
+				range: 0..max_index,
+			});
+		}
+
+		let subcube_start = subcube_index << subcube_vars;
+		let basis_mask = (1 << log_embedding_degree) - 1;
+
+		evals.fill(P::zero());
+		for (index, value) in self.entries_in(subcube_start, 1 << subcube_vars) {
+			let offset = index - subcube_start;
+			// Every group of 2^log_embedding_degree consecutive evaluations is packed into one
+			// extension field element, with the evaluations as coefficients of a strided basis, as
+			// in `ExtensionField::from_bases_sparse`.
+			let basis = <P::Scalar as ExtensionField<F>>::basis(
+				(offset & basis_mask) << (log_extension_degree - log_embedding_degree),
+			)?;
+			add_to_packed_slice(evals, offset >> log_embedding_degree, basis * value);
+		}
+
+		Ok(())
+	}
+
+	fn is_zero_on_subcube(&self, subcube_vars: usize, subcube_index: usize) -> bool {
+		subcube_vars <= self.n_vars
+			&& self
+				.positions_in(subcube_index << subcube_vars..(subcube_index + 1) << subcube_vars)
+				.is_empty()
+	}
+
+	fn packed_evals(&self) -> Option<&[P]> {
+		None
+	}
+}
+
+fn add_to_packed_slice<P: PackedField>(packed: &mut [P], index: usize, value: P::Scalar) {
+	let current = get_packed_slice(packed, index);
+	set_packed_slice(packed, index, current + value);
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{
+		BinaryField128b, BinaryField1b, BinaryField8b, PackedBinaryField256x1b,
+		PackedBinaryField2x128b, PackedBinaryField32x8b,
+	};
+	use rand::{rngs::StdRng, Rng, SeedableRng};
+
+	use super::*;
+	use crate::{MLEEmbeddingAdapter, MultilinearQuery};
+
+	type FE = BinaryField128b;
+	type PE = PackedBinaryField2x128b;
+
+	fn random_sparse<F: Field>(
+		rng: &mut StdRng,
+		n_vars: usize,
+		n_nonzero: usize,
+	) -> SparseMultilinearExtension<F> {
+		let entries =
+			repeat_with(|| (rng.gen_range(0..1 << n_vars), <F as Field>::random(&mut *rng)))
+				.take(n_nonzero)
+				.collect::<std::collections::BTreeMap<_, _>>();
+		SparseMultilinearExtension::new(n_vars, entries).unwrap()
+	}
+
+	fn random_query(rng: &mut StdRng, n_vars: usize) -> MultilinearQuery<PE> {
+		let point = repeat_with(|| <FE as Field>::random(&mut *rng))
+			.take(n_vars)
+			.collect::<Vec<_>>();
+		MultilinearQuery::expand(&point)
+	}
+
+	/// Checks every `MultilinearPoly` method of the sparse multilinear against the dense one.
+	fn check_against_dense<F, P>(n_vars: usize, n_nonzero: usize)
+	where
+		F: Field,
+		P: PackedField<Scalar = F>,
+		FE: ExtensionField<F>,
+		PE: binius_field::RepackedExtension<P>,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		let sparse = random_sparse::<F>(&mut rng, n_vars, n_nonzero);
+		let dense = MLEEmbeddingAdapter::<P, PE>::from(sparse.to_dense::<P>().unwrap());
+		assert_eq!(SparseMultilinearExtension::from_dense(dense.as_ref()), sparse);
+
+		for index in 0..1 << n_vars {
+			assert_eq!(
+				MultilinearPoly::<PE>::evaluate_on_hypercube(&sparse, index).unwrap(),
+				dense.evaluate_on_hypercube(index).unwrap()
+			);
+		}
+
+		let query = random_query(&mut rng, n_vars);
+		assert_eq!(
+			sparse.evaluate(query.to_ref()).unwrap(),
+			dense.evaluate(query.to_ref()).unwrap()
+		);
+
+		for query_n_vars in 0..=n_vars {
+			let query = random_query(&mut rng, query_n_vars);
+			assert_eq!(
+				sparse.evaluate_partial_low(query.to_ref()).unwrap(),
+				dense.evaluate_partial_low(query.to_ref()).unwrap()
+			);
+			assert_eq!(
+				sparse.evaluate_partial_high(query.to_ref()).unwrap(),
+				dense.evaluate_partial_high(query.to_ref()).unwrap()
+			);
+
+			for subcube_vars in 0..=n_vars - query_n_vars {
+				let len = 1 << subcube_vars.saturating_sub(PE::LOG_WIDTH);
+				for subcube_index in 0..1 << (n_vars - query_n_vars - subcube_vars) {
+					let mut sparse_evals = vec![PE::default(); len];
+					let mut dense_evals = vec![PE::default(); len];
+					sparse
+						.subcube_partial_low_evals(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut sparse_evals,
+						)
+						.unwrap();
+					dense
+						.subcube_partial_low_evals(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut dense_evals,
+						)
+						.unwrap();
+					assert_eq!(sparse_evals, dense_evals);
+
+					sparse
+						.subcube_partial_high_evals(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut sparse_evals,
+						)
+						.unwrap();
+					dense
+						.subcube_partial_high_evals(
+							query.to_ref(),
+							subcube_vars,
+							subcube_index,
+							&mut dense_evals,
+						)
+						.unwrap();
+					assert_eq!(sparse_evals, dense_evals);
+				}
+			}
+		}
+
+		let log_extension_degree = <FE as ExtensionField<F>>::LOG_DEGREE;
+		for log_embedding_degree in [0, log_extension_degree.min(n_vars)] {
+			for subcube_vars in log_embedding_degree..=n_vars {
+				let len = 1 << subcube_vars.saturating_sub(log_embedding_degree + PE::LOG_WIDTH);
+				for subcube_index in 0..1 << (n_vars - subcube_vars) {
+					let mut sparse_evals = vec![PE::default(); len];
+					let mut dense_evals = vec![PE::default(); len];
+					sparse
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut sparse_evals,
+						)
+						.unwrap();
+					dense
+						.subcube_evals(
+							subcube_vars,
+							subcube_index,
+							log_embedding_degree,
+							&mut dense_evals,
+						)
+						.unwrap();
+					assert_eq!(sparse_evals, dense_evals);
+					assert_eq!(
+						MultilinearPoly::<PE>::is_zero_on_subcube(
+							&sparse,
+							subcube_vars,
+							subcube_index
+						),
+						dense_evals.iter().all(|&eval| eval == PE::zero())
+					);
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_sparse_matches_dense_b128() {
+		check_against_dense::<FE, PE>(6, 5);
+	}
+
+	#[test]
+	fn test_sparse_matches_dense_b8() {
+		check_against_dense::<BinaryField8b, PackedBinaryField32x8b>(7, 9);
+	}
+
+	#[test]
+	fn test_sparse_matches_dense_b1() {
+		check_against_dense::<BinaryField1b, PackedBinaryField256x1b>(9, 20);
+	}
+
+	#[test]
+	fn test_new_rejects_invalid_entries() {
+		let one = BinaryField8b::ONE;
+		assert!(matches!(
+			SparseMultilinearExtension::new(3, [(8, one)]),
+			Err(Error::HypercubeIndexOutOfRange { index: 8 })
+		));
+		assert!(matches!(
+			SparseMultilinearExtension::new(3, [(2, one), (5, one), (2, one)]),
+			Err(Error::DuplicateSparseIndex { index: 2 })
+		));
+
+		let sparse =
+			SparseMultilinearExtension::new(3, [(5, one), (2, BinaryField8b::ZERO)]).unwrap();
+		assert_eq!(sparse.n_nonzero(), 1);
+	}
+}
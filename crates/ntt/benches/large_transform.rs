@@ -9,6 +9,7 @@ use binius_field::{
 };
 use binius_maybe_rayon::prelude::*;
 use binius_ntt::{AdditiveNTT, SingleThreadedNTT};
+use binius_utils::rayon::get_log_max_threads;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
 use rand::thread_rng;
 
@@ -42,6 +43,8 @@ fn bench_large_transform<F: TowerField, P: PackedField<Scalar = F>>(
 	let mut group = c.benchmark_group("slow/transform");
 	const LOG_BATCH_SIZE: usize = 6;
 	const LOG_DIM: usize = 16;
+	// 2^14 field elements of 32 bits occupy 64 KiB, which fits into the L2 cache.
+	const LOG_FOUR_STEP_BLOCK_SIZE: usize = 14;
 	for log_inv_rate in [1, 2] {
 		let data_len = 1 << (LOG_DIM + LOG_BATCH_SIZE + log_inv_rate - P::LOG_WIDTH);
 		let mut rng = thread_rng();
@@ -67,6 +70,22 @@ fn bench_large_transform<F: TowerField, P: PackedField<Scalar = F>>(
 		group.bench_function(BenchmarkId::new("multithread/precompute", &params), |b| {
 			b.iter(|| transform(&mut data, LOG_DIM, LOG_BATCH_SIZE, log_inv_rate, &ntt, true));
 		});
+
+		let ntt = SingleThreadedNTT::<F>::new(LOG_DIM + log_inv_rate)
+			.unwrap()
+			.precompute_twiddles()
+			.four_step(LOG_FOUR_STEP_BLOCK_SIZE);
+		group.bench_function(BenchmarkId::new("single-thread/four-step", &params), |b| {
+			b.iter(|| transform(&mut data, LOG_DIM, LOG_BATCH_SIZE, log_inv_rate, &ntt, false));
+		});
+
+		let ntt = SingleThreadedNTT::<F>::new(LOG_DIM + log_inv_rate)
+			.unwrap()
+			.precompute_twiddles()
+			.four_step_with_max_threads(LOG_FOUR_STEP_BLOCK_SIZE, get_log_max_threads());
+		group.bench_function(BenchmarkId::new("multithread/four-step", &params), |b| {
+			b.iter(|| transform(&mut data, LOG_DIM, LOG_BATCH_SIZE, log_inv_rate, &ntt, true));
+		});
 	}
 }
 
@@ -5,8 +5,9 @@ use binius_math::BinarySubspace;
 use binius_utils::rayon::get_log_max_threads;
 
 use super::{
-	additive_ntt::AdditiveNTT, error::Error, multithreaded::MultithreadedNTT,
-	single_threaded::SingleThreadedNTT, twiddle::PrecomputedTwiddleAccess,
+	additive_ntt::AdditiveNTT, error::Error, four_step::FourStepNTT,
+	multithreaded::MultithreadedNTT, single_threaded::SingleThreadedNTT,
+	twiddle::PrecomputedTwiddleAccess,
 };
 
 /// How many threads to use (threads number is a power of 2).
@@ -41,10 +42,23 @@ impl ThreadingSettings {
 	}
 }
 
+/// In which order the butterfly layers of a transform are evaluated.
+#[derive(Default, Debug, Clone, Copy)]
+pub enum TransformStrategy {
+	/// Evaluate the layers one after another over the whole data.
+	#[default]
+	LayerByLayer,
+	/// Recursively split the transform into row and column sub-transforms of at most
+	/// `1 << log_block_size` field elements, see [`FourStepNTT`]. This keeps the working set in
+	/// cache for transforms that are larger than the cache.
+	FourStep { log_block_size: usize },
+}
+
 #[derive(Default)]
 pub struct NTTOptions {
 	pub precompute_twiddles: bool,
 	pub thread_settings: ThreadingSettings,
+	pub strategy: TransformStrategy,
 }
 
 /// An enum that can be used to switch between different NTT implementations without passing AdditiveNTT as a type parameter.
@@ -54,12 +68,27 @@ pub enum DynamicDispatchNTT<F: BinaryField> {
 	SingleThreadedPrecompute(SingleThreadedNTT<F, PrecomputedTwiddleAccess<F>>),
 	MultiThreaded(MultithreadedNTT<F>),
 	MultiThreadedPrecompute(MultithreadedNTT<F, PrecomputedTwiddleAccess<F>>),
+	FourStep(FourStepNTT<F>),
+	FourStepPrecompute(FourStepNTT<F, PrecomputedTwiddleAccess<F>>),
 }
 
 impl<F: BinaryField> DynamicDispatchNTT<F> {
 	/// Create a new AdditiveNTT based on the given settings.
 	pub fn new(log_domain_size: usize, options: &NTTOptions) -> Result<Self, Error> {
 		let log_threads = options.thread_settings.log_threads_count();
+		if let TransformStrategy::FourStep { log_block_size } = options.strategy {
+			let ntt = SingleThreadedNTT::new(log_domain_size)?;
+			let result = if options.precompute_twiddles {
+				Self::FourStepPrecompute(
+					ntt.precompute_twiddles()
+						.four_step_with_max_threads(log_block_size, log_threads),
+				)
+			} else {
+				Self::FourStep(ntt.four_step_with_max_threads(log_block_size, log_threads))
+			};
+			return Ok(result);
+		}
+
 		let result = match (options.precompute_twiddles, log_threads) {
 			(false, 0) => Self::SingleThreaded(SingleThreadedNTT::new(log_domain_size)?),
 			(true, 0) => Self::SingleThreadedPrecompute(
@@ -87,6 +116,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.log_domain_size(),
 			Self::MultiThreaded(ntt) => ntt.log_domain_size(),
 			Self::MultiThreadedPrecompute(ntt) => ntt.log_domain_size(),
+			Self::FourStep(ntt) => ntt.log_domain_size(),
+			Self::FourStepPrecompute(ntt) => ntt.log_domain_size(),
 		}
 	}
 
@@ -96,6 +127,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.subspace(i),
 			Self::MultiThreaded(ntt) => ntt.subspace(i),
 			Self::MultiThreadedPrecompute(ntt) => ntt.subspace(i),
+			Self::FourStep(ntt) => ntt.subspace(i),
+			Self::FourStepPrecompute(ntt) => ntt.subspace(i),
 		}
 	}
 
@@ -105,6 +138,8 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::SingleThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreaded(ntt) => ntt.get_subspace_eval(i, j),
 			Self::MultiThreadedPrecompute(ntt) => ntt.get_subspace_eval(i, j),
+			Self::FourStep(ntt) => ntt.get_subspace_eval(i, j),
+			Self::FourStepPrecompute(ntt) => ntt.get_subspace_eval(i, j),
 		}
 	}
 
@@ -124,6 +159,10 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.forward_transform(data, coset, log_batch_size, log_n)
 			}
+			Self::FourStep(ntt) => ntt.forward_transform(data, coset, log_batch_size, log_n),
+			Self::FourStepPrecompute(ntt) => {
+				ntt.forward_transform(data, coset, log_batch_size, log_n)
+			}
 		}
 	}
 
@@ -143,6 +182,10 @@ impl<F: BinaryField> AdditiveNTT<F> for DynamicDispatchNTT<F> {
 			Self::MultiThreadedPrecompute(ntt) => {
 				ntt.inverse_transform(data, coset, log_batch_size, log_n)
 			}
+			Self::FourStep(ntt) => ntt.inverse_transform(data, coset, log_batch_size, log_n),
+			Self::FourStepPrecompute(ntt) => {
+				ntt.inverse_transform(data, coset, log_batch_size, log_n)
+			}
 		}
 	}
 }
@@ -162,12 +205,14 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::SingleThreaded,
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
@@ -175,6 +220,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
@@ -185,6 +231,7 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::MultithreadedDefault,
+			..Default::default()
 		});
 		if multithreaded {
 			assert!(matches!(ntt, DynamicDispatchNTT::MultiThreadedPrecompute(_)));
@@ -195,19 +242,36 @@ mod tests {
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 2 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::MultiThreaded(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: true,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreadedPrecompute(_)));
 
 		let ntt = make_ntt(&NTTOptions {
 			precompute_twiddles: false,
 			thread_settings: ThreadingSettings::ExplicitThreadsCount { log_threads: 0 },
+			..Default::default()
 		});
 		assert!(matches!(ntt, DynamicDispatchNTT::SingleThreaded(_)));
+
+		let ntt = make_ntt(&NTTOptions {
+			precompute_twiddles: false,
+			thread_settings: ThreadingSettings::SingleThreaded,
+			strategy: TransformStrategy::FourStep { log_block_size: 4 },
+		});
+		assert!(matches!(ntt, DynamicDispatchNTT::FourStep(_)));
+
+		let ntt = make_ntt(&NTTOptions {
+			precompute_twiddles: true,
+			thread_settings: ThreadingSettings::MultithreadedDefault,
+			strategy: TransformStrategy::FourStep { log_block_size: 4 },
+		});
+		assert!(matches!(ntt, DynamicDispatchNTT::FourStepPrecompute(_)));
 	}
 }
@@ -20,6 +20,12 @@ pub enum Error {
 	StrideGreaterThanPackedWidth,
 	#[error("the batch size is greater than the number of elements")]
 	BatchTooLarge,
+	#[error("the polynomial must have at most 2**{log_n} coefficients")]
+	PolynomialTooLarge { log_n: usize },
+	#[error("the number of values must be exactly 2**{log_n}")]
+	IncorrectValuesLength { log_n: usize },
+	#[error("the subspace basis must be linearly independent")]
+	LinearlyDependentBasis,
 	#[error("odd interpolation length mismatch, expected to be exactly {expected_len}")]
 	OddInterpolateIncorrectLength { expected_len: usize },
 	#[error("math error: {0}")]
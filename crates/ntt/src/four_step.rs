@@ -0,0 +1,341 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{BinaryField, PackedField};
+use binius_math::BinarySubspace;
+use binius_maybe_rayon::prelude::*;
+
+use super::{
+	error::Error,
+	single_threaded::{self, check_batch_transform_inputs_and_params},
+	strided_array::StridedArray2DViewMut,
+	twiddle::{OnTheFlyTwiddleAccess, TwiddleAccess},
+	AdditiveNTT, SingleThreadedNTT,
+};
+
+/// Implementation of `AdditiveNTT` that splits large transforms into cache-sized sub-transforms.
+///
+/// Viewing the data as a matrix whose rows hold the evaluations sharing the high index bits, the
+/// top NTT layers act independently on every column and the bottom layers act on every row. The
+/// row transforms are smaller NTTs over cosets of the lower subspace, and the column transforms
+/// are smaller NTTs over the upper twiddle layers. Columns are processed in panels that are
+/// transposed into contiguous scratch buffers. Both kinds of sub-transforms are split
+/// recursively until they fit into `1 << log_block_size` field elements, at which point the
+/// layer-by-layer single-threaded implementation takes over.
+#[derive(Debug)]
+pub struct FourStepNTT<F: BinaryField, TA: TwiddleAccess<F> = OnTheFlyTwiddleAccess<F, Vec<F>>> {
+	single_threaded: SingleThreadedNTT<F, TA>,
+	log_block_size: usize,
+	log_max_threads: usize,
+}
+
+impl<F: BinaryField, TA: TwiddleAccess<F> + Sync> SingleThreadedNTT<F, TA> {
+	/// Returns four-step NTT implementation with sub-transforms of at most
+	/// `1 << log_block_size` field elements, which runs on a single thread.
+	pub const fn four_step(self, log_block_size: usize) -> FourStepNTT<F, TA> {
+		self.four_step_with_max_threads(log_block_size, 0)
+	}
+
+	/// Returns four-step NTT implementation with sub-transforms of at most
+	/// `1 << log_block_size` field elements, which processes the rows and column panels of the
+	/// outermost split on `1 << log_max_threads` threads.
+	pub const fn four_step_with_max_threads(
+		self,
+		log_block_size: usize,
+		log_max_threads: usize,
+	) -> FourStepNTT<F, TA> {
+		FourStepNTT {
+			single_threaded: self,
+			log_block_size,
+			log_max_threads,
+		}
+	}
+}
+
+impl<F, TA> AdditiveNTT<F> for FourStepNTT<F, TA>
+where
+	F: BinaryField,
+	TA: TwiddleAccess<F> + Sync,
+{
+	fn log_domain_size(&self) -> usize {
+		self.single_threaded.log_domain_size()
+	}
+
+	fn subspace(&self, i: usize) -> BinarySubspace<F> {
+		self.single_threaded.subspace(i)
+	}
+
+	fn get_subspace_eval(&self, i: usize, j: usize) -> F {
+		self.single_threaded.get_subspace_eval(i, j)
+	}
+
+	fn forward_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		forward_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.log_block_size,
+			self.log_max_threads,
+		)
+	}
+
+	fn inverse_transform<P: PackedField<Scalar = F>>(
+		&self,
+		data: &mut [P],
+		coset: u32,
+		log_batch_size: usize,
+		log_n: usize,
+	) -> Result<(), Error> {
+		inverse_transform(
+			self.log_domain_size(),
+			self.single_threaded.twiddles(),
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+			self.log_block_size,
+			self.log_max_threads,
+		)
+	}
+}
+
+/// Shape of one level of the four-step decomposition.
+///
+/// The data is viewed as a `1 << log_height` by `1 << log_width` matrix of packed elements. Each
+/// row holds `1 << log_row_n` consecutive NTT evaluations (times the batch), and each column panel
+/// is `1 << log_panel_width` packed elements wide.
+#[derive(Debug, Clone, Copy)]
+struct Split {
+	log_row_n: usize,
+	log_height: usize,
+	log_width: usize,
+	log_panel_width: usize,
+}
+
+impl Split {
+	/// Returns the split for the given transform, or `None` if the transform is small enough to be
+	/// performed layer by layer.
+	fn new<P: PackedField>(
+		data_len: usize,
+		log_batch_size: usize,
+		log_n: usize,
+		log_block_size: usize,
+	) -> Option<Self> {
+		let log_w = P::LOG_WIDTH;
+
+		// Every row and every column panel must consist of whole packed elements, which requires
+		// at least two NTT layers and two packed elements.
+		if data_len <= 2 || log_n < 2 || log_n + log_batch_size <= log_block_size.max(log_w + 1) {
+			return None;
+		}
+
+		let log_row_n = log_n.div_ceil(2).max(log_w.saturating_sub(log_batch_size));
+		let log_height = log_n - log_row_n;
+		let log_width = log_row_n + log_batch_size - log_w;
+		let log_panel_width = log_block_size
+			.saturating_sub(log_height)
+			.clamp(log_w, log_width + log_w)
+			- log_w;
+
+		Some(Self {
+			log_row_n,
+			log_height,
+			log_width,
+			log_panel_width,
+		})
+	}
+}
+
+#[allow(clippy::too_many_arguments)]
+fn forward_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F> + Sync],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_size: usize,
+	log_max_threads: usize,
+) -> Result<(), Error> {
+	let Some(split) = Split::new::<P>(data.len(), log_batch_size, log_n, log_block_size) else {
+		return single_threaded::forward_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	check_batch_transform_inputs_and_params(log_domain_size, data, coset, log_batch_size, log_n)?;
+
+	let Split {
+		log_row_n,
+		log_height,
+		log_width,
+		..
+	} = split;
+
+	// The top layers are NTTs over the columns which use the twiddles of the upper layers.
+	transform_columns(data, split, log_max_threads, |panel, log_panel_batch_size| {
+		forward_transform(
+			log_domain_size - log_row_n,
+			&s_evals[log_row_n..],
+			panel,
+			coset,
+			log_panel_batch_size,
+			log_height,
+			log_block_size,
+			0,
+		)
+	})?;
+
+	// The bottom layers are NTTs over the rows, each on its own coset of the lower subspace.
+	transform_rows(data, log_width, log_max_threads, |row, chunk| {
+		forward_transform(
+			log_domain_size,
+			s_evals,
+			chunk,
+			coset << log_height | row as u32,
+			log_batch_size,
+			log_row_n,
+			log_block_size,
+			0,
+		)
+	})
+}
+
+#[allow(clippy::too_many_arguments)]
+fn inverse_transform<F: BinaryField, P: PackedField<Scalar = F>>(
+	log_domain_size: usize,
+	s_evals: &[impl TwiddleAccess<F> + Sync],
+	data: &mut [P],
+	coset: u32,
+	log_batch_size: usize,
+	log_n: usize,
+	log_block_size: usize,
+	log_max_threads: usize,
+) -> Result<(), Error> {
+	let Some(split) = Split::new::<P>(data.len(), log_batch_size, log_n, log_block_size) else {
+		return single_threaded::inverse_transform(
+			log_domain_size,
+			s_evals,
+			data,
+			coset,
+			log_batch_size,
+			log_n,
+		);
+	};
+
+	check_batch_transform_inputs_and_params(log_domain_size, data, coset, log_batch_size, log_n)?;
+
+	let Split {
+		log_row_n,
+		log_height,
+		log_width,
+		..
+	} = split;
+
+	transform_rows(data, log_width, log_max_threads, |row, chunk| {
+		inverse_transform(
+			log_domain_size,
+			s_evals,
+			chunk,
+			coset << log_height | row as u32,
+			log_batch_size,
+			log_row_n,
+			log_block_size,
+			0,
+		)
+	})?;
+
+	transform_columns(data, split, log_max_threads, |panel, log_panel_batch_size| {
+		inverse_transform(
+			log_domain_size - log_row_n,
+			&s_evals[log_row_n..],
+			panel,
+			coset,
+			log_panel_batch_size,
+			log_height,
+			log_block_size,
+			0,
+		)
+	})
+}
+
+/// Applies `transform` to every row of `1 << log_width` packed elements, passing the row index.
+fn transform_rows<P: PackedField>(
+	data: &mut [P],
+	log_width: usize,
+	log_max_threads: usize,
+	transform: impl Fn(usize, &mut [P]) -> Result<(), Error> + Sync,
+) -> Result<(), Error> {
+	if log_max_threads == 0 {
+		data.chunks_mut(1 << log_width)
+			.enumerate()
+			.try_for_each(|(row, chunk)| transform(row, chunk))
+	} else {
+		data.par_chunks_mut(1 << log_width)
+			.enumerate()
+			.try_for_each(|(row, chunk)| transform(row, chunk))
+	}
+}
+
+/// Applies `transform` to every column panel, passing the panel transposed into a contiguous
+/// buffer together with the log number of scalars in each of its rows.
+fn transform_columns<P: PackedField>(
+	data: &mut [P],
+	split: Split,
+	log_max_threads: usize,
+	transform: impl Fn(&mut [P], usize) -> Result<(), Error> + Sync,
+) -> Result<(), Error> {
+	let Split {
+		log_height,
+		log_width,
+		log_panel_width,
+		..
+	} = split;
+	let log_panel_batch_size = log_panel_width + P::LOG_WIDTH;
+
+	// A single panel spans the whole matrix, so there is nothing to transpose.
+	if log_panel_width == log_width {
+		return transform(data, log_panel_batch_size);
+	}
+
+	let transform_panel = |mut panel: StridedArray2DViewMut<P>| {
+		let mut buffer = Vec::with_capacity(1 << (log_height + log_panel_width));
+		for i in 0..panel.height() {
+			buffer.extend((0..panel.width()).map(|j| panel[(i, j)]));
+		}
+
+		transform(&mut buffer, log_panel_batch_size)?;
+
+		for (i, row) in buffer.chunks_exact(1 << log_panel_width).enumerate() {
+			for (j, &value) in row.iter().enumerate() {
+				panel[(i, j)] = value;
+			}
+		}
+		Ok(())
+	};
+
+	let matrix = StridedArray2DViewMut::without_stride(data, 1 << log_height, 1 << log_width)
+		.expect("dimensions are correct");
+	if log_max_threads == 0 {
+		matrix
+			.into_strides(1 << log_panel_width)
+			.try_for_each(transform_panel)
+	} else {
+		matrix
+			.into_par_strides(1 << log_panel_width)
+			.try_for_each(transform_panel)
+	}
+}
@@ -10,8 +10,10 @@
 mod additive_ntt;
 mod dynamic_dispatch;
 mod error;
+mod four_step;
 mod multithreaded;
 mod odd_interpolate;
+mod polynomial;
 mod single_threaded;
 mod strided_array;
 #[cfg(test)]
@@ -19,8 +21,10 @@ mod tests;
 pub mod twiddle;
 
 pub use additive_ntt::AdditiveNTT;
-pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings};
+pub use dynamic_dispatch::{DynamicDispatchNTT, NTTOptions, ThreadingSettings, TransformStrategy};
 pub use error::Error;
+pub use four_step::FourStepNTT;
 pub use multithreaded::MultithreadedNTT;
 pub use odd_interpolate::OddInterpolate;
+pub use polynomial::{AffineSubspaceEvaluator, PolynomialNTT};
 pub use single_threaded::SingleThreadedNTT;
@@ -0,0 +1,554 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{linear_transformation::to_bits, BinaryField, Field};
+use binius_math::BinarySubspace;
+use binius_utils::{bail, checked_arithmetics::log2_ceil_usize};
+
+use super::{additive_ntt::AdditiveNTT, error::Error, single_threaded::SingleThreadedNTT};
+
+/// Univariate polynomial arithmetic on top of an additive NTT.
+///
+/// Polynomials are given either in the monomial basis or in the novel polynomial basis of
+/// [LCH14], where the $j$-th basis polynomial is
+/// $X_j(X) = \prod_{i \in \mathrm{bits}(j)} \hat{W}_i(X)$ for the normalized subspace
+/// polynomials $\hat{W}_i$ of the NTT. Converting between the two bases is done by recursively
+/// dividing by (resp. multiplying with) the subspace polynomials, which are linearized and thus
+/// sparse in the monomial basis. For a polynomial with $n$ coefficients, the conversions take
+/// $\mathcal{O}(n \log^2 n)$ field operations, which dominates the cost of multiplication,
+/// evaluation and interpolation.
+///
+/// [LCH14]: <https://arxiv.org/abs/1404.3458>
+#[derive(Debug)]
+pub struct PolynomialNTT<F: BinaryField, NTT = SingleThreadedNTT<F>> {
+	ntt: NTT,
+	/// `subspace_polys[i][t]` is the coefficient of $X^{2^t}$ in $\hat{W}_i(X)$.
+	subspace_polys: Vec<Vec<F>>,
+}
+
+impl<F: BinaryField, NTT: AdditiveNTT<F>> PolynomialNTT<F, NTT> {
+	pub fn new(ntt: NTT) -> Self {
+		let log_domain_size = ntt.log_domain_size();
+		let mut subspace_polys = Vec::<Vec<F>>::with_capacity(log_domain_size);
+		for i in 0..log_domain_size {
+			let subspace_poly = match subspace_polys.last() {
+				// $\hat{W}_0(X) = X$
+				None => vec![F::ONE],
+				// $\hat{W}_i(X) = \hat{W}_{i-1}(X) (\hat{W}_{i-1}(X) + 1) / c$, where the
+				// normalization constant is $c = \hat{W}_{i-1}(\beta_i) (\hat{W}_{i-1}(\beta_i) + 1)$.
+				// Squaring is $\mathbb{F}_2$-linear, so the square of a linearized polynomial is
+				// obtained by squaring its coefficients and shifting them up by one position.
+				Some(prev) => {
+					let eval = ntt.get_subspace_eval(i - 1, 1);
+					let norm_inv = (eval.square() + eval)
+						.invert()
+						.expect("normalization constants are nonzero");
+					(0..=i)
+						.map(|t| {
+							let square_term = if t > 0 { prev[t - 1].square() } else { F::ZERO };
+							let linear_term = prev.get(t).copied().unwrap_or(F::ZERO);
+							(square_term + linear_term) * norm_inv
+						})
+						.collect()
+				}
+			};
+			subspace_polys.push(subspace_poly);
+		}
+
+		Self {
+			ntt,
+			subspace_polys,
+		}
+	}
+
+	pub const fn ntt(&self) -> &NTT {
+		&self.ntt
+	}
+
+	/// Converts the coefficients of a polynomial from the monomial basis to the novel polynomial
+	/// basis in place.
+	///
+	/// The number of coefficients must be a power of two that is at most the NTT domain size.
+	pub fn monomial_to_novel(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_coeffs_len(coeffs.len())?;
+
+		// Splitting $P = P_0 + \hat{W}_{k-1} P_1$ with $\deg P_0, \deg P_1 < 2^{k-1}$ gives
+		// the novel basis coefficients of $P$ as those of $P_0$ followed by those of $P_1$.
+		for log_block_len in (1..=log_n).rev() {
+			let subspace_poly = &self.subspace_polys[log_block_len - 1];
+			for block in coeffs.chunks_exact_mut(1 << log_block_len) {
+				div_rem_subspace_poly(block, subspace_poly);
+			}
+		}
+		Ok(())
+	}
+
+	/// Converts the coefficients of a polynomial from the novel polynomial basis to the monomial
+	/// basis in place.
+	///
+	/// The number of coefficients must be a power of two that is at most the NTT domain size.
+	pub fn novel_to_monomial(&self, coeffs: &mut [F]) -> Result<(), Error> {
+		let log_n = self.check_coeffs_len(coeffs.len())?;
+
+		for log_block_len in 1..=log_n {
+			let subspace_poly = &self.subspace_polys[log_block_len - 1];
+			for block in coeffs.chunks_exact_mut(1 << log_block_len) {
+				mul_add_subspace_poly(block, subspace_poly);
+			}
+		}
+		Ok(())
+	}
+
+	/// Evaluates a polynomial given in the monomial basis over the coset `coset` of the NTT
+	/// domain of size `2^log_n`.
+	///
+	/// The polynomial must have at most `2^log_n` coefficients.
+	pub fn evaluate(&self, coeffs: &[F], coset: u32, log_n: usize) -> Result<Vec<F>, Error> {
+		if coeffs.len() > 1 << log_n {
+			bail!(Error::PolynomialTooLarge { log_n });
+		}
+
+		let mut evals = coeffs.to_vec();
+		evals.resize(1 << log_n, F::ZERO);
+		self.monomial_to_novel(&mut evals)?;
+		self.ntt.forward_transform(&mut evals, coset, 0, log_n)?;
+		Ok(evals)
+	}
+
+	/// Interpolates the monomial basis coefficients of the unique polynomial of degree less than
+	/// `evals.len()` with the given values over the coset `coset` of the NTT domain.
+	///
+	/// The number of values must be a power of two.
+	pub fn interpolate(&self, evals: &[F], coset: u32) -> Result<Vec<F>, Error> {
+		if !evals.len().is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
+
+		let mut coeffs = evals.to_vec();
+		self.ntt
+			.inverse_transform(&mut coeffs, coset, 0, evals.len().ilog2() as usize)?;
+		self.novel_to_monomial(&mut coeffs)?;
+		Ok(coeffs)
+	}
+
+	/// Multiplies two polynomials given in the monomial basis.
+	pub fn mul(&self, lhs: &[F], rhs: &[F]) -> Result<Vec<F>, Error> {
+		self.mul_in_basis(lhs, rhs, true)
+	}
+
+	/// Multiplies two polynomials given in the novel polynomial basis.
+	pub fn mul_novel(&self, lhs: &[F], rhs: &[F]) -> Result<Vec<F>, Error> {
+		self.mul_in_basis(lhs, rhs, false)
+	}
+
+	fn mul_in_basis(&self, lhs: &[F], rhs: &[F], monomial: bool) -> Result<Vec<F>, Error> {
+		if lhs.is_empty() || rhs.is_empty() {
+			return Ok(Vec::new());
+		}
+
+		// The novel basis polynomial $X_j$ has degree $j$, so both the product and its novel basis
+		// representation have `len` coefficients.
+		let len = lhs.len() + rhs.len() - 1;
+		let log_n = log2_ceil_usize(len);
+
+		let to_evals = |coeffs: &[F]| -> Result<Vec<F>, Error> {
+			let mut evals = coeffs.to_vec();
+			evals.resize(1 << log_n, F::ZERO);
+			if monomial {
+				self.monomial_to_novel(&mut evals)?;
+			}
+			self.ntt.forward_transform(&mut evals, 0, 0, log_n)?;
+			Ok(evals)
+		};
+
+		let mut product = to_evals(lhs)?;
+		for (product_eval, rhs_eval) in product.iter_mut().zip(to_evals(rhs)?) {
+			*product_eval *= rhs_eval;
+		}
+
+		self.ntt.inverse_transform(&mut product, 0, 0, log_n)?;
+		if monomial {
+			self.novel_to_monomial(&mut product)?;
+		}
+		product.truncate(len);
+		Ok(product)
+	}
+
+	fn check_coeffs_len(&self, len: usize) -> Result<usize, Error> {
+		if !len.is_power_of_two() {
+			bail!(Error::PowerOfTwoLengthRequired);
+		}
+
+		let log_n = len.ilog2() as usize;
+		if log_n > self.subspace_polys.len() {
+			bail!(Error::DomainTooSmall {
+				log_required_domain_size: log_n
+			});
+		}
+		Ok(log_n)
+	}
+}
+
+/// Divides the polynomial in `block` by the linearized polynomial `subspace_poly` of degree
+/// `block.len() / 2`, storing the remainder in the lower half and the quotient in the upper half.
+fn div_rem_subspace_poly<F: Field>(block: &mut [F], subspace_poly: &[F]) {
+	let half = block.len() / 2;
+	let (&lead, tail) = subspace_poly
+		.split_last()
+		.expect("subspace polynomials are non-empty");
+	let lead_inv = lead.invert().expect("leading coefficient is nonzero");
+
+	for d in (half..block.len()).rev() {
+		let quotient = block[d] * lead_inv;
+		block[d] = quotient;
+		for (t, &coeff) in tail.iter().enumerate() {
+			block[d - half + (1 << t)] += quotient * coeff;
+		}
+	}
+}
+
+/// Inverse of [`div_rem_subspace_poly`]: replaces the remainder in the lower half and the quotient
+/// in the upper half of `block` with `remainder + subspace_poly * quotient`.
+fn mul_add_subspace_poly<F: Field>(block: &mut [F], subspace_poly: &[F]) {
+	let half = block.len() / 2;
+	let (&lead, tail) = subspace_poly
+		.split_last()
+		.expect("subspace polynomials are non-empty");
+
+	// Undo the division steps in reverse order.
+	for d in half..block.len() {
+		let quotient = block[d];
+		block[d] = quotient * lead;
+		for (t, &coeff) in tail.iter().enumerate() {
+			block[d - half + (1 << t)] += quotient * coeff;
+		}
+	}
+}
+
+/// Fast multipoint evaluation and interpolation over an arbitrary affine subspace.
+///
+/// The domain is $s + S$ for a shift $s$ and a subspace $S$ with basis
+/// $\gamma_0, \ldots, \gamma_{k-1}$, ordered by the little-endian coordinates with respect to
+/// that basis. The evaluation reduces to an additive NTT over the subspace spanned by
+/// $\gamma_i / \gamma_0$, extended by $s / \gamma_0$ when the shift is not in the subspace, applied
+/// to the polynomial $P(\gamma_0 X)$.
+#[derive(Debug)]
+pub struct AffineSubspaceEvaluator<F: BinaryField> {
+	poly_ntt: PolynomialNTT<F>,
+	log_n: usize,
+	scale: F,
+	coset: u32,
+	/// Index of the normalized shift in the subspace, when the shift lies in the subspace.
+	shift_index: usize,
+}
+
+impl<F: BinaryField> AffineSubspaceEvaluator<F> {
+	pub fn new(subspace: &BinarySubspace<F>, shift: F) -> Result<Self, Error> {
+		let log_n = subspace.dim();
+		let scale = match subspace.basis().first() {
+			Some(&first) => first,
+			None if shift != F::ZERO => shift,
+			None => F::ONE,
+		};
+		let scale_inv = scale.invert().ok_or(Error::LinearlyDependentBasis)?;
+
+		let mut basis = subspace
+			.basis()
+			.iter()
+			.map(|&gamma| gamma * scale_inv)
+			.collect::<Vec<_>>();
+		let shift = shift * scale_inv;
+
+		let echelon = BitEchelonForm::new(&basis)?;
+		let (coset, shift_index) = match echelon.coordinates(shift) {
+			Some(shift_index) => (0, shift_index),
+			None => {
+				basis.push(shift);
+				(1, 0)
+			}
+		};
+		if basis.is_empty() {
+			basis.push(F::ONE);
+		}
+
+		let ntt = SingleThreadedNTT::with_subspace(&BinarySubspace::new_unchecked(basis))?;
+		Ok(Self {
+			poly_ntt: PolynomialNTT::new(ntt),
+			log_n,
+			scale,
+			coset,
+			shift_index,
+		})
+	}
+
+	/// Base-2 logarithm of the number of points in the domain.
+	pub const fn log_size(&self) -> usize {
+		self.log_n
+	}
+
+	/// Evaluates a polynomial given in the monomial basis over the domain.
+	///
+	/// The polynomial must have at most as many coefficients as there are points in the domain.
+	pub fn evaluate(&self, coeffs: &[F]) -> Result<Vec<F>, Error> {
+		let scaled_coeffs = coeffs
+			.iter()
+			.zip(powers(self.scale))
+			.map(|(&coeff, power)| coeff * power)
+			.collect::<Vec<_>>();
+
+		let evals = self
+			.poly_ntt
+			.evaluate(&scaled_coeffs, self.coset, self.log_n)?;
+		Ok(self.permute(evals))
+	}
+
+	/// Interpolates the monomial basis coefficients of the unique polynomial of degree less than
+	/// the domain size with the given values over the domain.
+	pub fn interpolate(&self, evals: &[F]) -> Result<Vec<F>, Error> {
+		if evals.len() != 1 << self.log_n {
+			bail!(Error::IncorrectValuesLength { log_n: self.log_n });
+		}
+
+		let scale_inv = self.scale.invert().expect("scale is nonzero");
+		let coeffs = self
+			.poly_ntt
+			.interpolate(&self.permute(evals.to_vec()), self.coset)?
+			.into_iter()
+			.zip(powers(scale_inv))
+			.map(|(coeff, power)| coeff * power)
+			.collect();
+		Ok(coeffs)
+	}
+
+	/// Reorders values between the NTT domain order and the domain order. The domain point with
+	/// index $i$ is the NTT domain point with index $i \oplus j$, where $j$ is the index of the
+	/// shift in the subspace, so the permutation is an involution.
+	fn permute(&self, values: Vec<F>) -> Vec<F> {
+		if self.shift_index == 0 {
+			return values;
+		}
+		(0..values.len())
+			.map(|i| values[i ^ self.shift_index])
+			.collect()
+	}
+}
+
+fn powers<F: Field>(base: F) -> impl Iterator<Item = F> {
+	std::iter::successors(Some(F::ONE), move |&power| Some(power * base))
+}
+
+/// Row echelon form of a list of field elements viewed as vectors over $\mathbb{F}_2$.
+struct BitEchelonForm {
+	/// Reduced vectors together with the set of input vectors they are the sum of.
+	rows: Vec<(u128, usize)>,
+}
+
+impl BitEchelonForm {
+	fn new<F: BinaryField>(basis: &[F]) -> Result<Self, Error> {
+		let mut echelon = Self { rows: Vec::new() };
+		for (i, &element) in basis.iter().enumerate() {
+			let (reduced, combination) = echelon.reduce(to_bits(element));
+			if reduced == 0 {
+				bail!(Error::LinearlyDependentBasis);
+			}
+			echelon.rows.push((reduced, combination | 1 << i));
+		}
+		Ok(echelon)
+	}
+
+	/// Reduces `bits` by the rows, returning the remainder and the rows combination subtracted.
+	fn reduce(&self, mut bits: u128) -> (u128, usize) {
+		let mut combination = 0;
+		for &(row, row_combination) in &self.rows {
+			let pivot = 1 << row.ilog2();
+			if bits & pivot != 0 {
+				bits ^= row;
+				combination ^= row_combination;
+			}
+		}
+		(bits, combination)
+	}
+
+	/// Returns the coordinates of `element` with respect to the basis as a little-endian bit
+	/// index, or `None` if it is not in the span.
+	fn coordinates<F: BinaryField>(&self, element: F) -> Option<usize> {
+		let (reduced, combination) = self.reduce(to_bits(element));
+		(reduced == 0).then_some(combination)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use assert_matches::assert_matches;
+	use binius_field::{BinaryField32b, BinaryField8b};
+	use binius_math::evaluate_univariate;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+
+	fn random_poly<F: Field>(rng: &mut StdRng, len: usize) -> Vec<F> {
+		repeat_with(|| F::random(&mut *rng)).take(len).collect()
+	}
+
+	fn naive_mul<F: Field>(lhs: &[F], rhs: &[F]) -> Vec<F> {
+		let mut product = vec![F::ZERO; lhs.len() + rhs.len() - 1];
+		for (i, &a) in lhs.iter().enumerate() {
+			for (j, &b) in rhs.iter().enumerate() {
+				product[i + j] += a * b;
+			}
+		}
+		product
+	}
+
+	#[test]
+	fn test_novel_basis_matches_ntt_evaluation() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let ntt = SingleThreadedNTT::<BinaryField32b>::new(10).unwrap();
+		let subspace = ntt.subspace(0);
+		let poly_ntt = PolynomialNTT::new(ntt);
+
+		for log_n in 0..=6 {
+			let coeffs = random_poly(&mut rng, 1 << log_n);
+
+			let mut novel = coeffs.clone();
+			poly_ntt.monomial_to_novel(&mut novel).unwrap();
+
+			let mut roundtrip = novel.clone();
+			poly_ntt.novel_to_monomial(&mut roundtrip).unwrap();
+			assert_eq!(roundtrip, coeffs);
+
+			for coset in 0..4 {
+				let mut evals = novel.clone();
+				poly_ntt
+					.ntt()
+					.forward_transform(&mut evals, coset, 0, log_n)
+					.unwrap();
+				for (i, &eval) in evals.iter().enumerate() {
+					let point = subspace.get((coset as usize) << log_n | i);
+					assert_eq!(eval, evaluate_univariate(&coeffs, point));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_mul() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::new(SingleThreadedNTT::<BinaryField32b>::new(10).unwrap());
+
+		for (lhs_len, rhs_len) in [(1, 1), (1, 7), (5, 3), (16, 17), (100, 30)] {
+			let lhs = random_poly(&mut rng, lhs_len);
+			let rhs = random_poly(&mut rng, rhs_len);
+			assert_eq!(poly_ntt.mul(&lhs, &rhs).unwrap(), naive_mul(&lhs, &rhs));
+		}
+
+		assert!(poly_ntt
+			.mul(&[], &[BinaryField32b::ONE])
+			.unwrap()
+			.is_empty());
+		assert_matches!(
+			poly_ntt.mul(&random_poly(&mut rng, 600), &random_poly(&mut rng, 600)),
+			Err(Error::DomainTooSmall { .. })
+		);
+	}
+
+	#[test]
+	fn test_mul_novel() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::new(SingleThreadedNTT::<BinaryField32b>::new(10).unwrap());
+
+		let lhs = random_poly(&mut rng, 8);
+		let rhs = random_poly(&mut rng, 8);
+
+		let to_novel = |coeffs: &[BinaryField32b], len: usize| {
+			let mut novel = coeffs.to_vec();
+			novel.resize(len, BinaryField32b::ZERO);
+			poly_ntt.monomial_to_novel(&mut novel).unwrap();
+			novel
+		};
+
+		let mut expected = to_novel(&naive_mul(&lhs, &rhs), 16);
+		expected.truncate(15);
+		assert_eq!(
+			poly_ntt
+				.mul_novel(&to_novel(&lhs, 8), &to_novel(&rhs, 8))
+				.unwrap(),
+			expected
+		);
+	}
+
+	#[test]
+	fn test_evaluate_interpolate() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let poly_ntt = PolynomialNTT::new(SingleThreadedNTT::<BinaryField8b>::new(8).unwrap());
+
+		let coeffs = random_poly(&mut rng, 20);
+		let evals = poly_ntt.evaluate(&coeffs, 3, 5).unwrap();
+		let mut interpolated = poly_ntt.interpolate(&evals, 3).unwrap();
+		assert!(interpolated[20..]
+			.iter()
+			.all(|&coeff| coeff == BinaryField8b::ZERO));
+		interpolated.truncate(20);
+		assert_eq!(interpolated, coeffs);
+
+		assert_matches!(
+			poly_ntt.evaluate(&coeffs, 0, 4),
+			Err(Error::PolynomialTooLarge { log_n: 4 })
+		);
+		assert_matches!(poly_ntt.interpolate(&evals[..6], 0), Err(Error::PowerOfTwoLengthRequired));
+	}
+
+	#[test]
+	fn test_affine_subspace_evaluator() {
+		let mut rng = StdRng::seed_from_u64(0);
+
+		let basis = random_poly::<BinaryField32b>(&mut rng, 5);
+		let subspace = BinarySubspace::new_unchecked(basis.clone());
+		let shifts = [
+			BinaryField32b::ZERO,
+			BinaryField32b::random(&mut rng),
+			basis[1] + basis[3],
+		];
+
+		for shift in shifts {
+			let evaluator = AffineSubspaceEvaluator::new(&subspace, shift).unwrap();
+			assert_eq!(evaluator.log_size(), 5);
+
+			let coeffs = random_poly(&mut rng, 32);
+			let evals = evaluator.evaluate(&coeffs).unwrap();
+			for (i, &eval) in evals.iter().enumerate() {
+				let point = shift + subspace.get(i);
+				assert_eq!(eval, evaluate_univariate(&coeffs, point));
+			}
+
+			assert_eq!(evaluator.interpolate(&evals).unwrap(), coeffs);
+			assert_matches!(
+				evaluator.interpolate(&evals[..16]),
+				Err(Error::IncorrectValuesLength { log_n: 5 })
+			);
+		}
+
+		let point = BinaryField32b::random(&mut rng);
+		let evaluator =
+			AffineSubspaceEvaluator::new(&BinarySubspace::new_unchecked(vec![]), point).unwrap();
+		let coeffs = random_poly(&mut rng, 1);
+		assert_eq!(evaluator.evaluate(&coeffs).unwrap(), coeffs);
+	}
+
+	#[test]
+	fn test_affine_subspace_linearly_dependent() {
+		let basis = vec![
+			BinaryField32b::new(3),
+			BinaryField32b::new(5),
+			BinaryField32b::new(6),
+		];
+		assert_matches!(
+			AffineSubspaceEvaluator::new(
+				&BinarySubspace::new_unchecked(basis),
+				BinaryField32b::ONE
+			),
+			Err(Error::LinearlyDependentBasis)
+		);
+	}
+}
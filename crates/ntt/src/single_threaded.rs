@@ -21,7 +21,14 @@ impl<F: BinaryField> SingleThreadedNTT<F> {
 	/// on-the-fly computed twiddle factors.
 	pub fn new(log_domain_size: usize) -> Result<Self, Error> {
 		let subspace = BinarySubspace::with_dim(log_domain_size)?;
-		let twiddle_access = OnTheFlyTwiddleAccess::generate(&subspace)?;
+		Self::with_subspace(&subspace)
+	}
+
+	/// Constructs an NTT over the given subspace using on-the-fly computed twiddle factors.
+	///
+	/// The first basis element of the subspace must be 1.
+	pub fn with_subspace(subspace: &BinarySubspace<F>) -> Result<Self, Error> {
+		let twiddle_access = OnTheFlyTwiddleAccess::generate(subspace)?;
 		Ok(Self::with_twiddle_access(twiddle_access))
 	}
 
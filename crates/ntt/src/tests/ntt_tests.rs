@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::ops::Range;
+use std::{iter::repeat_with, ops::Range};
 
 use binius_field::{
 	arch::{
@@ -10,12 +10,16 @@ use binius_field::{
 		packed_8::PackedBinaryField1x8b,
 	},
 	underlier::{NumCast, WithUnderlier},
-	AESTowerField8b, BinaryField, BinaryField8b, PackedBinaryField16x32b, PackedBinaryField32x16b,
-	PackedBinaryField8x32b, PackedExtension, PackedField, RepackedExtension,
+	AESTowerField8b, BinaryField, BinaryField32b, BinaryField8b, PackedBinaryField16x32b,
+	PackedBinaryField32x16b, PackedBinaryField8x32b, PackedExtension, PackedField,
+	RepackedExtension,
 };
 use rand::{rngs::StdRng, SeedableRng};
 
-use crate::{dynamic_dispatch::DynamicDispatchNTT, AdditiveNTT, SingleThreadedNTT};
+use crate::{
+	dynamic_dispatch::DynamicDispatchNTT, AdditiveNTT, NTTOptions, SingleThreadedNTT,
+	ThreadingSettings, TransformStrategy,
+};
 
 /// Check that forward and inverse transformation of `ntt` on `data` is the same as forward and inverse transformation of `reference_ntt` on `data`
 /// and that the result of the roundtrip is the same as the original data.
@@ -87,6 +91,14 @@ fn check_roundtrip_all_ntts<P>(
 	let dynamic_dispatch_ntt = DynamicDispatchNTT::SingleThreaded(
 		SingleThreadedNTT::<P::Scalar>::new(log_domain_size).unwrap(),
 	);
+	// Use the smallest block size so that even the small test transforms are split.
+	let four_step_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.four_step(0);
+	let four_step_precompute_ntt = SingleThreadedNTT::<P::Scalar>::new(log_domain_size)
+		.unwrap()
+		.precompute_twiddles()
+		.four_step_with_max_threads(0, 2);
 
 	let mut rng = StdRng::seed_from_u64(0);
 	let data = (0..1u128 << log_data_size)
@@ -143,6 +155,22 @@ fn check_roundtrip_all_ntts<P>(
 				log_batch_size,
 				log_n,
 			);
+			check_roundtrip_with_reference(
+				&simple_ntt,
+				&four_step_ntt,
+				&data,
+				cosets.clone(),
+				log_batch_size,
+				log_n,
+			);
+			check_roundtrip_with_reference(
+				&simple_ntt,
+				&four_step_precompute_ntt,
+				&data,
+				cosets.clone(),
+				log_batch_size,
+				log_n,
+			);
 		}
 	}
 }
@@ -177,6 +205,36 @@ fn test_sub_packing_width() {
 	check_roundtrip_all_ntts::<PackedBinaryField32x16b>(12, 0, 2, 1);
 }
 
+#[test]
+fn test_four_step_block_sizes() {
+	type P = PackedBinaryField8x32b;
+
+	let log_domain_size = 14;
+	let simple_ntt = SingleThreadedNTT::<BinaryField32b>::new(log_domain_size)
+		.unwrap()
+		.into_simple_ntt();
+
+	let mut rng = StdRng::seed_from_u64(0);
+	for (log_n, log_batch_size) in [(11, 0), (10, 3), (9, 1), (3, 6)] {
+		let data = repeat_with(|| P::random(&mut rng))
+			.take(1 << (log_n + log_batch_size - P::LOG_WIDTH))
+			.collect::<Vec<_>>();
+
+		for log_block_size in [0, 4, 7, log_n + log_batch_size] {
+			let ntt = DynamicDispatchNTT::new(
+				log_domain_size,
+				&NTTOptions {
+					precompute_twiddles: log_block_size % 2 == 0,
+					thread_settings: ThreadingSettings::SingleThreaded,
+					strategy: TransformStrategy::FourStep { log_block_size },
+				},
+			)
+			.unwrap();
+			check_roundtrip_with_reference(&simple_ntt, &ntt, &data, 0..4, log_batch_size, log_n);
+		}
+	}
+}
+
 fn check_packed_extension_roundtrip_with_reference<F, PE>(
 	reference_ntt: &impl AdditiveNTT<F>,
 	ntt: &impl AdditiveNTT<F>,
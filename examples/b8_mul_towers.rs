@@ -0,0 +1,163 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Proves a batch of 8-bit field multiplications in a choice of tower family, which allows
+//! comparing the prover performance of the canonical and the AES towers on the same circuit.
+
+use anyhow::Result;
+use binius_circuits::builder::{types::U, ConstraintSystemBuilder};
+use binius_core::{
+	constraint_system::{self, ConstraintSystem},
+	fiat_shamir::HasherChallenger,
+	tower::{AESTowerFamily, CanonicalTowerFamily},
+	witness::MultilinearExtensionIndex,
+};
+use binius_field::{
+	as_packed_field::PackScalar, AESTowerField128b, AESTowerField8b, BinaryField128b,
+	BinaryField8b, ExtensionField, TowerField,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_macros::arith_expr;
+use binius_math::DefaultEvaluationDomainFactory;
+use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
+use bytemuck::Pod;
+use bytesize::ByteSize;
+use clap::{value_parser, Parser, ValueEnum};
+use groestl_crypto::Groestl256;
+use itertools::izip;
+use rand::thread_rng;
+use tracing_profile::init_tracing;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Tower {
+	/// The canonical Fan-Paar tower.
+	Canonical,
+	/// The Fan-Paar tower built on top of the Rijndael field.
+	Aes,
+}
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The number of operations to do.
+	#[arg(short, long, default_value_t = 512, value_parser = value_parser!(u32).range(512..))]
+	n_ops: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+	/// The tower family to prove in.
+	#[arg(long, value_enum, default_value_t = Tower::Canonical)]
+	tower: Tower,
+}
+
+const SECURITY_BITS: usize = 100;
+
+/// Builds the constraint system and the witness for `out = in_a * in_b` over `F8`.
+fn build_b8_mul<F, F8>(
+	allocator: &bumpalo::Bump,
+	log_n_muls: usize,
+) -> Result<(ConstraintSystem<F>, MultilinearExtensionIndex<'_, U, F>)>
+where
+	F: TowerField + ExtensionField<F8>,
+	F8: TowerField + Pod,
+	U: PackScalar<F> + PackScalar<F8>,
+{
+	let mut builder = ConstraintSystemBuilder::<F>::new_in_field_with_witness(allocator);
+
+	let trace_gen_scope = tracing::info_span!("generating trace").entered();
+
+	let in_a = builder.add_committed("in_a", log_n_muls, F8::TOWER_LEVEL);
+	let in_b = builder.add_committed("in_b", log_n_muls, F8::TOWER_LEVEL);
+	let out = builder.add_committed("out", log_n_muls, F8::TOWER_LEVEL);
+
+	if let Some(witness) = builder.witness() {
+		let mut rng = thread_rng();
+		let mut in_a_witness = witness.new_column::<F8>(in_a);
+		let mut in_b_witness = witness.new_column::<F8>(in_b);
+		let mut out_witness = witness.new_column::<F8>(out);
+
+		for (a, b, out) in izip!(
+			in_a_witness.as_mut_slice::<F8>(),
+			in_b_witness.as_mut_slice::<F8>(),
+			out_witness.as_mut_slice::<F8>()
+		) {
+			*a = F8::random(&mut rng);
+			*b = F8::random(&mut rng);
+			*out = *a * *b;
+		}
+	}
+
+	builder.assert_zero(
+		"b8_mul",
+		[in_a, in_b, out],
+		arith_expr!([in_a, in_b, out] = in_a * in_b - out).convert_field(),
+	);
+
+	drop(trace_gen_scope);
+
+	let witness = builder
+		.take_witness()
+		.expect("builder created with witness");
+	let constraint_system = builder.build()?;
+	Ok((constraint_system, witness))
+}
+
+macro_rules! prove_and_verify {
+	($tower:ty, $field:ty, $field_8b:ty, $args:expr) => {{
+		let allocator = bumpalo::Bump::new();
+		let log_n_muls = log2_ceil_usize($args.n_ops as usize);
+		let (constraint_system, witness) =
+			build_b8_mul::<$field, $field_8b>(&allocator, log_n_muls)?;
+
+		let domain_factory = DefaultEvaluationDomainFactory::default();
+		let backend = make_portable_backend();
+
+		let proof = constraint_system::prove::<
+			U,
+			$tower,
+			_,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+			_,
+		>(
+			&constraint_system,
+			$args.log_inv_rate as usize,
+			SECURITY_BITS,
+			&[],
+			witness,
+			&domain_factory,
+			&backend,
+		)?;
+
+		println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
+
+		constraint_system::verify::<
+			U,
+			$tower,
+			Groestl256,
+			Groestl256ByteCompression,
+			HasherChallenger<Groestl256>,
+		>(&constraint_system, $args.log_inv_rate as usize, SECURITY_BITS, &[], proof)?;
+	}};
+}
+
+fn main() -> Result<()> {
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+
+	let _guard = init_tracing().expect("failed to initialize tracing");
+
+	println!("Verifying {} 8-bit multiplications in the {:?} tower", args.n_ops, args.tower);
+
+	match args.tower {
+		Tower::Canonical => {
+			prove_and_verify!(CanonicalTowerFamily, BinaryField128b, BinaryField8b, args)
+		}
+		Tower::Aes => prove_and_verify!(AESTowerFamily, AESTowerField128b, AESTowerField8b, args),
+	}
+
+	Ok(())
+}